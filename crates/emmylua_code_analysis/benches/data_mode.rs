@@ -0,0 +1,105 @@
+//! 对比大型配置数据文件在完整分析与数据模式下的分析与诊断耗时
+//!
+//! 运行: `cargo bench -p emmylua_code_analysis --bench data_mode`
+
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use emmylua_code_analysis::{Emmyrc, VirtualWorkspace};
+use tokio_util::sync::CancellationToken;
+
+const ROWS: usize = 20000;
+const ITERATIONS: u32 = 3;
+
+const SCHEMA: &str = r#"
+---@class Reward: Bean
+---@field itemId int
+---@field count int
+
+---@class Item: Bean
+---@field id int
+---@field name string
+---@field quality int
+---@field price float
+---@field pos vector2
+---@field rewards list<Reward>
+
+---@class TbItem: ConfigTable
+---@field [int] Item
+"#;
+
+fn generate_data(rows: usize) -> String {
+    let mut data = String::from("---@type TbItem\nlocal items = {\n");
+    for id in 1..=rows {
+        let _ = writeln!(
+            data,
+            "    [{id}] = {{ id = {id}, name = \"item_{id}\", quality = {}, price = {}.5, pos = {{ {id}, {} }}, rewards = {{ {{ itemId = {}, count = {} }}, {{ itemId = {}, count = 1 }} }} }},",
+            id % 5,
+            id * 10,
+            id * 2,
+            id % rows + 1,
+            id % 99 + 1,
+            (id + 1) % rows + 1,
+        );
+    }
+    data.push_str("}\nreturn items\n");
+    data
+}
+
+fn run(data: &str, data_mode: bool) -> (Duration, Duration) {
+    let mut analyze = Duration::ZERO;
+    let mut diagnose = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = Emmyrc::default();
+        if data_mode {
+            emmyrc.workspace.data_mode_globs = vec!["data/**/*.lua".to_string()];
+        } else {
+            emmyrc.workspace.data_mode_min_size = 0;
+        }
+        ws.update_emmyrc(emmyrc);
+        ws.def_file("schema.lua", SCHEMA);
+
+        let start = Instant::now();
+        let file_id = ws.def_file("data/item.lua", data);
+        analyze += start.elapsed();
+
+        let start = Instant::now();
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        diagnose += start.elapsed();
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    (analyze / ITERATIONS, diagnose / ITERATIONS)
+}
+
+fn main() {
+    let data = generate_data(ROWS);
+    println!(
+        "data file: {ROWS} rows, {} lines, {} KiB",
+        data.lines().count(),
+        data.len() / 1024
+    );
+
+    let (full_analyze, full_diagnose) = run(&data, false);
+    let (data_analyze, data_diagnose) = run(&data, true);
+    println!("{:<10} {:>12} {:>12}", "mode", "analyze", "diagnose");
+    println!(
+        "{:<10} {:>12.2?} {:>12.2?}",
+        "full", full_analyze, full_diagnose
+    );
+    println!(
+        "{:<10} {:>12.2?} {:>12.2?}",
+        "data", data_analyze, data_diagnose
+    );
+    println!(
+        "speedup: analyze {:.2}x, diagnose {:.2}x",
+        full_analyze.as_secs_f64() / data_analyze.as_secs_f64(),
+        full_diagnose.as_secs_f64() / data_diagnose.as_secs_f64()
+    );
+}
@@ -26,9 +26,70 @@
 
 ---@class ConfigTable
 
+-- 二维向量. 数据中可以写作`{x = 1, y = 2}`, 也可以按分量顺序写作`{1, 2}`.
+--
+-- 字段上的`v.range`作用于每个分量.
+---@class vector2: Bean
+---@field x float
+---@field y float
+
+-- 三维向量. 数据中可以写作`{x = 1, y = 2, z = 3}`, 也可以按分量顺序写作`{1, 2, 3}`.
+--
+-- 字段上的`v.range`作用于每个分量.
+---@class vector3: Bean
+---@field x float
+---@field y float
+---@field z float
+
+-- 四维向量. 数据中可以写作`{x = 1, y = 2, z = 3, w = 4}`, 也可以按分量顺序写作`{1, 2, 3, 4}`.
+--
+-- 字段上的`v.range`作用于每个分量.
+---@class vector4: Bean
+---@field x float
+---@field y float
+---@field z float
+---@field w float
+
+-- 颜色, 分量取值为`0`到`1`. 数据中可以写作`{r = 1, g = 0.5, b = 0}`, 也可以按分量顺序写作`{1, 0.5, 0}`.
+--
+-- 字段上的`v.range`作用于每个分量.
+---@class color: Bean
+---@field r float
+---@field g float
+---@field b float
+---@[v.default(1)]
+---@field a float # 省略时为 1
+
 -- 标记枚举为位域, 位域的值为2的幂次.
 ---@attribute flags()
 
+-- 枚举成员的别名. 配置数据中可以使用成员名或别名书写枚举值, 如`quality = "Red"`,
+-- `flags`枚举可以使用`|`连接多个名称, 如`element = "Fire|Ice"`.
+--
+-- ### 参数:
+--
+-- - `name`: 别名.
+--
+-- 示例:
+-- ```lua
+-- ---@enum Quality
+-- Quality = {
+--     ---@[alias("红")]
+--     Red = 3,
+-- }
+-- ```
+---@attribute alias(name: string)
+
+-- 从 CSV 导入数据时, 容器字段在一个单元格中书写的元素之间的分隔符, 默认为`,`. 与 Luban 的`sep`一致.
+--
+-- 示例:
+-- ```lua
+-- ---@class Item: Bean
+-- ---@[sep(";")]
+-- ---@field tags list<string> # 单元格写作`a;b;c`
+-- ```
+---@attribute sep(separator: string)
+
 -- 定义配置表的索引(主键)字段列表, 可以有多个索引字段.
 --
 -- 如果配置表的索引字段列表为空且配置表模式为"map", 则使用值类型的第一个字段作为索引字段.
@@ -47,6 +108,37 @@
 -- - "singleton": 单例.
 ---@attribute t.mode(mode: "map" | "list" | "singleton")
 
+-- 定义配置表中非主键字段的唯一性约束, 在配置表的所有数据(包括分布在多个文件中的数据)中检查.
+--
+-- 可以多次使用以声明多个唯一性约束.
+--
+-- ### 参数:
+--
+-- - `fields`: 唯一字段列表, 可以是字符串或字符串数组. 提供多个字段时要求字段值的组合唯一.
+---@attribute t.unique(fields: string|string[])
+
+-- 将配置表视为一棵树, 每条记录通过父字段引用同表中的父记录.
+--
+-- 父字段缺失或为`nil`的记录为根节点. 检查父字段是否形成环, 是否存在无法从根节点到达的记录, 以及树的层数.
+--
+-- 配置表必须只有一个主键.
+--
+-- ### 参数:
+--
+-- - `parent`: 父字段名称, 值为父记录的主键.
+-- - `maxDepth`: 树的最大层数, 根节点为第 1 层. 不提供则不限制.
+---@attribute t.tree(parent: string, maxDepth?: integer)
+
+-- 检查配置表中某字段的值是否覆盖了枚举的所有成员, 可以多次使用.
+--
+-- 缺少的成员与不属于枚举的值都会在配置表声明处报告. 对于`flags`枚举, 值为`0`的成员不要求出现, 由多个成员组合而成的值不视为多余.
+--
+-- ### 参数:
+--
+-- - `field`: 字段名称.
+-- - `enumName`: 枚举名称, 必须是`---@enum`声明的枚举.
+---@attribute t.cover_enum(field: string, enumName: string)
+
 --#region validator
 
 -- 检查某字段是否为某配置表的合法 key.
@@ -58,6 +150,23 @@
 --
 ---@attribute v.ref(tableName: string, key?: string)
 
+-- 检查 Bean 中多个字段组成的值是否为某联合主键配置表的合法 key.
+--
+-- ### 参数:
+--
+-- - `tableName`: 配置表名称, 必须是使用联合主键的`ConfigTable`的子类.
+-- - `fields`: 本地字段列表.
+-- - `keys`: 与`fields`一一对应的目标主键列表. 如果不提供, 则按顺序对应配置表的联合主键.
+--
+-- 示例:
+-- ```lua
+-- ---@[v.union_ref("TbStage", ["chapterId", "stageId"])]
+-- ---@class Reward: Bean
+-- ---@field chapterId int
+-- ---@field stageId int
+-- ```
+---@attribute v.union_ref(tableName: string, fields: string[], keys?: string[])
+
 -- 检查`array<Bean>`/`list<Bean>`/`set<Bean>`内指定字段的值是否唯一.
 --
 -- 被检查的元素类型必须为`Bean`.
@@ -97,4 +206,109 @@
 --
 ---@attribute v.set(values: (int|string)[])
 
+-- 检查字符串是否匹配正则表达式. 仅可用于字符串.
+--
+-- ### 参数:
+--
+-- - `pattern`: 正则表达式, 语法同 Rust `regex`. 需要完整匹配时请使用`^`与`$`.
+--
+-- 示例:
+-- ```lua
+-- ---@class Item: Bean
+-- ---@[v.regex("^icon_[a-z0-9_]+$")]
+-- ---@field icon string
+-- ```
+---@attribute v.regex(pattern: string)
+
+-- 检查字符串的字符个数是否在指定范围内. 仅可用于字符串.
+--
+-- ### 参数:
+--
+-- - `len`: 指定字符个数. 支持:
+--   - `10` (精确匹配)
+--   - `[1,10]` / `(1,10]` / `[1,10)` / `(1,10)` (开闭区间)
+--   - `[1,]` / `[,200]` / `(1,)` / `(,200)` (无穷区间)
+---@attribute v.len(len: integer|string)
+
+-- 检查字符串是否符合预设格式. 仅可用于字符串.
+--
+-- ### 参数:
+--
+-- - `format`: 预设格式:
+--   - `color`: `#RRGGBB` 或 `#RRGGBBAA`
+--   - `uuid`: `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+--   - `int_list`: 逗号分隔的整数列表, 如 `1,2,3`
+--   - `float_list`: 逗号分隔的数字列表, 如 `0.5,1,2.25`
+---@attribute v.format(format: "color"|"uuid"|"int_list"|"float_list")
+
+-- 检查字段对同表记录的引用不形成环, 可用于前置任务, 技能树等自引用的配置.
+--
+-- 字段值(或容器元素)为同表记录的主键. 所在配置表必须只有一个主键.
+--
+-- 示例:
+-- ```lua
+-- ---@class Quest: Bean
+-- ---@field id int
+-- ---@[v.acyclic]
+-- ---@field prerequisites list<int>
+-- ```
+---@attribute v.acyclic()
+
+-- 字段的默认值. 与 Luban 一致, Bean 中类型不可为空(如`int?`)且没有默认值的字段为必填字段,
+-- 数据中省略必填字段会报告错误, 省略带默认值的字段则使用默认值.
+--
+-- ### 参数:
+--
+-- - `value`: 默认值, 必须是与字段类型匹配的字面量.
+--
+-- 示例:
+-- ```lua
+-- ---@class Item: Bean
+-- ---@field id int
+-- ---@[v.default(1)]
+-- ---@field stack int # 省略时为 1
+-- ---@field icon string? # 可以省略
+-- ```
+---@attribute v.default(value: boolean|number|string)
+
+-- 检查权重: 每条记录的权重必须为正数, 同组记录的权重之和必须等于`total`.
+--
+-- 作用于 Bean 的权重字段, 对`list<Bean>`等容器的每个值分别检查; Bean 作为配置表的记录时对整张表检查.
+--
+-- ### 参数:
+--
+-- - `total`: 同组记录的权重之和
+-- - `groupBy`: 分组字段, 省略时所有记录为同一组
+--
+-- 示例:
+-- ```lua
+-- ---@class DropItem: Bean
+-- ---@field itemId int
+-- ---@[v.weight_sum(100)]
+-- ---@field weight int
+--
+-- ---@class Drop: Bean
+-- ---@field id int
+-- ---@field items list<DropItem> # 每个掉落的 weight 之和为 100
+-- ```
+---@attribute v.weight_sum(total: number, groupBy?: string)
+
+-- 检查概率: 每条记录的概率必须在`[0, 1]`内, 同组记录的概率之和必须为 1.
+--
+-- 作用范围与`v.weight_sum`相同.
+--
+-- ### 参数:
+--
+-- - `groupBy`: 分组字段, 省略时所有记录为同一组
+--
+-- 示例:
+-- ```lua
+-- ---@class GachaPool: Bean
+-- ---@field id int
+-- ---@field poolId int
+-- ---@[v.prob_sum("poolId")]
+-- ---@field prob float # 同一 poolId 的 prob 之和为 1
+-- ```
+---@attribute v.prob_sum(groupBy?: string)
+
 --#endregion
@@ -23,6 +23,10 @@ impl AnalysisPipeline for FlowAnalysisPipeline {
         let tree_list = context.tree_list.clone();
         // build decl and ref flow chain
         for in_filed_tree in &tree_list {
+            // 数据文件没有控制流, 不需要构建流树
+            if context.data_mode_files.contains(&in_filed_tree.file_id) {
+                continue;
+            }
             let chunk = in_filed_tree.value.clone();
             let file_id = in_filed_tree.file_id;
             let mut binder = FlowBinder::new(db, file_id);
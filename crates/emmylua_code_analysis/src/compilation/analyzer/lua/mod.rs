@@ -10,7 +10,7 @@ use std::collections::HashMap;
 
 use closure::analyze_closure;
 pub use closure::analyze_return_point;
-use emmylua_parser::{LuaAst, LuaAstNode, LuaExpr};
+use emmylua_parser::{LuaAst, LuaAstNode, LuaExpr, LuaTableField};
 use for_range_stat::analyze_for_range_stat;
 pub use for_range_stat::infer_for_range_iter_expr_func;
 pub use func_body::LuaReturnPoint;
@@ -46,9 +46,17 @@ impl AnalysisPipeline for LuaAnalysisPipeline {
         let order = file_dependency.get_best_analysis_order(&file_ids, &context.metas);
         for file_id in order {
             if let Some(root) = tree_map.get(&file_id) {
+                let data_mode = context.data_mode_files.contains(&file_id);
                 let mut analyzer = LuaAnalyzer::new(db, file_id, context);
-                for node in root.descendants::<LuaAst>() {
-                    analyze_node(&mut analyzer, node);
+                if data_mode {
+                    // 数据文件只有表字面量, 只需要推断表字段的类型
+                    for field in root.descendants::<LuaTableField>() {
+                        analyze_table_field(&mut analyzer, field);
+                    }
+                } else {
+                    for node in root.descendants::<LuaAst>() {
+                        analyze_node(&mut analyzer, node);
+                    }
                 }
                 analyze_chunk_return(&mut analyzer, root.clone());
             }
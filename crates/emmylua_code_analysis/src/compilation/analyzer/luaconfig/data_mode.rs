@@ -0,0 +1,124 @@
+use std::path::Path;
+
+use emmylua_parser::{
+    LuaAstNode, LuaChunk, LuaClosureExpr, LuaCommentOwner, LuaDocTag, LuaExpr, LuaLocalStat,
+    LuaStat, LuaTableExpr,
+};
+use wax::Pattern;
+
+use crate::{Emmyrc, FileId, db_index::DbIndex};
+
+use super::super::AnalyzeContext;
+
+/// 标记以数据模式分析的文件: 匹配 `workspace.dataModeGlobs` 的文件,
+/// 或超过 `workspace.dataModeMinSize` 且只包含带类型的表字面量与返回语句的文件
+pub fn mark_data_mode_files(db: &mut DbIndex, context: &mut AnalyzeContext) {
+    let emmyrc = context.config.clone();
+    let globs = emmyrc
+        .workspace
+        .data_mode_globs
+        .iter()
+        .map(|glob| glob.as_str())
+        .collect::<Vec<_>>();
+    let glob_set = if globs.is_empty() {
+        None
+    } else {
+        match wax::any(globs) {
+            Ok(glob_set) => Some(glob_set),
+            Err(e) => {
+                log::error!("Invalid data mode glob pattern: {:?}", e);
+                None
+            }
+        }
+    };
+
+    for in_filed_tree in context.tree_list.iter() {
+        let file_id = in_filed_tree.file_id;
+        let data_mode = glob_set
+            .as_ref()
+            .is_some_and(|glob_set| is_glob_matched(db, file_id, glob_set))
+            || is_auto_data_file(&emmyrc, &in_filed_tree.value);
+        db.get_config_index_mut()
+            .set_data_mode_file(file_id, data_mode);
+        if data_mode {
+            context.data_mode_files.insert(file_id);
+        }
+    }
+}
+
+fn is_glob_matched<'t>(db: &DbIndex, file_id: FileId, glob_set: &impl Pattern<'t>) -> bool {
+    let Some(path) = db.get_vfs().get_file_path(&file_id) else {
+        return false;
+    };
+    let path: &Path = path;
+    let relative_path = db
+        .get_module_index()
+        .get_workspace_relative_path(path)
+        .unwrap_or(path);
+    glob_set.is_match(relative_path)
+}
+
+fn is_auto_data_file(emmyrc: &Emmyrc, chunk: &LuaChunk) -> bool {
+    let min_size = emmyrc.workspace.data_mode_min_size;
+    if min_size == 0 || usize::from(chunk.syntax().text_range().len()) < min_size {
+        return false;
+    }
+
+    is_data_chunk(chunk)
+}
+
+/// 文件中只有带 `---@type` 的表字面量局部变量与返回语句, 且至少有一个表字面量
+pub fn is_data_chunk(chunk: &LuaChunk) -> bool {
+    let Some(block) = chunk.get_block() else {
+        return false;
+    };
+
+    let mut has_table = false;
+    for stat in block.get_stats() {
+        match stat {
+            LuaStat::LocalStat(local_stat) => {
+                if !is_typed_table_stat(&local_stat) {
+                    return false;
+                }
+                has_table = true;
+            }
+            LuaStat::ReturnStat(return_stat) => {
+                let is_data_return = return_stat.get_expr_list().all(|expr| match expr {
+                    LuaExpr::NameExpr(_) => true,
+                    LuaExpr::TableExpr(table) => !has_closure(&table),
+                    _ => false,
+                });
+                if !is_data_return {
+                    return false;
+                }
+            }
+            LuaStat::EmptyStat(_) => {}
+            _ => return false,
+        }
+    }
+
+    has_table
+}
+
+fn is_typed_table_stat(local_stat: &LuaLocalStat) -> bool {
+    let has_type_tag = local_stat.get_comments().iter().any(|comment| {
+        comment
+            .get_doc_tags()
+            .any(|tag| matches!(tag, LuaDocTag::Type(_)))
+    });
+    if !has_type_tag {
+        return false;
+    }
+
+    let mut value_exprs = local_stat.get_value_exprs().peekable();
+    value_exprs.peek().is_some()
+        && value_exprs.all(|expr| match expr {
+            LuaExpr::TableExpr(table) => !has_closure(&table),
+            _ => false,
+        })
+}
+
+/// 表中的函数需要完整分析, 不能以数据模式跳过
+fn has_closure(table: &LuaTableExpr) -> bool {
+    table.descendants::<LuaClosureExpr>().next().is_some()
+}
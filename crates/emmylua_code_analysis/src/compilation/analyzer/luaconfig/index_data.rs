@@ -1,13 +1,19 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
-use emmylua_parser::{LuaAstNode, LuaChunk, LuaTableExpr};
+use emmylua_parser::{LuaAstNode, LuaChunk, LuaExpr, LuaTableExpr};
 
 use crate::{
-    ConfigTableIndexKeys, ConfigTablePkOccurrence, LuaSemanticDeclId, LuaType, LuaTypeCache,
-    db_index::DbIndex, find_members_with_key, infer_expr,
+    ConfigTableDataOccurrence, ConfigTableEdgeOccurrence, ConfigTableFieldValueOccurrence,
+    ConfigTableIndexKeys, ConfigTablePkOccurrence, ConfigTableRow, ConfigTableUniqueOccurrence,
+    ConfigValue, LuaMemberKey, LuaSemanticDeclId, LuaType, LuaTypeCache, LuaTypeDeclId,
+    db_index::DbIndex,
+    find_members_with_key, infer_expr,
+    semantic::shared::luaconfig::{
+        CONFIG_TABLE, collect_bean_ref_tables, resolve_field_enum_value,
+    },
 };
 
-use super::super::infer_cache_manager::InferCacheManager;
+use super::{super::infer_cache_manager::InferCacheManager, index_rows};
 
 pub fn index_file(
     db: &mut DbIndex,
@@ -20,6 +26,11 @@ pub fn index_file(
     };
 
     let mut occurrences: Vec<ConfigTablePkOccurrence> = Vec::new();
+    let mut unique_occurrences: Vec<ConfigTableUniqueOccurrence> = Vec::new();
+    let mut edge_occurrences: Vec<ConfigTableEdgeOccurrence> = Vec::new();
+    let mut field_value_occurrences: Vec<ConfigTableFieldValueOccurrence> = Vec::new();
+    let mut data_occurrences: Vec<ConfigTableDataOccurrence> = Vec::new();
+    let mut rows: Vec<ConfigTableRow> = Vec::new();
     let infer_cache = infer_manager.get_infer_cache(file_id);
 
     for (decl_id, decl) in decl_tree.get_decls().iter() {
@@ -31,13 +42,58 @@ pub fn index_file(
             continue;
         };
 
-        let Some(index_keys) = db
+        if let Some(expr_id) = decl.get_value_syntax_id()
+            && CONFIG_TABLE.is_config_table(db, config_table_id)
+        {
+            data_occurrences.push(ConfigTableDataOccurrence {
+                config_table: config_table_id.clone(),
+                syntax_id: expr_id,
+            });
+            if let Some(table_expr) = expr_id
+                .to_node_from_root(root.syntax())
+                .and_then(LuaTableExpr::cast)
+            {
+                index_rows::collect_table_rows(
+                    db,
+                    infer_cache,
+                    config_table_id,
+                    &table_expr,
+                    &mut rows,
+                );
+            }
+        }
+
+        let index_keys = db
             .get_config_index()
             .get_config_table_keys(config_table_id)
-            .cloned()
-        else {
+            .cloned();
+        let uniques = db
+            .get_config_index()
+            .get_config_table_uniques(config_table_id)
+            .map(|uniques| uniques.to_vec());
+        let edge_fields = db
+            .get_config_index()
+            .get_config_table_graph_rules(config_table_id)
+            .map(|rules| rules.edge_fields());
+        let cover_fields = db
+            .get_config_index()
+            .get_config_table_cover_enums(config_table_id)
+            .map(|rules| {
+                let mut fields: Vec<LuaMemberKey> = Vec::new();
+                for rule in rules {
+                    if !fields.contains(&rule.field) {
+                        fields.push(rule.field.clone());
+                    }
+                }
+                fields
+            });
+        if index_keys.is_none()
+            && uniques.is_none()
+            && edge_fields.is_none()
+            && cover_fields.is_none()
+        {
             continue;
-        };
+        }
 
         let Some(expr_id) = decl.get_value_syntax_id() else {
             continue;
@@ -49,25 +105,300 @@ pub fn index_file(
         let Some(table_expr) = LuaTableExpr::cast(table_node) else {
             continue;
         };
+        let bean_id = CONFIG_TABLE.get_bean_id(db, config_table_id);
+        let bean_id = bean_id.as_ref();
+
+        if let Some(index_keys) = &index_keys {
+            collect_table_occurrences(
+                db,
+                infer_cache,
+                config_table_id.clone(),
+                bean_id,
+                index_keys,
+                &table_expr,
+                &mut occurrences,
+            );
+        }
+        if let Some(uniques) = &uniques {
+            collect_table_unique_occurrences(
+                db,
+                infer_cache,
+                config_table_id,
+                bean_id,
+                uniques,
+                &table_expr,
+                &mut unique_occurrences,
+            );
+        }
+        // 引用关系要求配置表只有一个主键
+        if let Some(edge_fields) = &edge_fields
+            && let Some(index_keys) = &index_keys
+            && let [pk] = index_keys.keys()
+        {
+            collect_table_edge_occurrences(
+                db,
+                infer_cache,
+                config_table_id,
+                bean_id,
+                pk,
+                edge_fields,
+                &table_expr,
+                &mut edge_occurrences,
+            );
+        }
+        if let Some(cover_fields) = &cover_fields {
+            collect_table_field_value_occurrences(
+                db,
+                infer_cache,
+                config_table_id,
+                bean_id,
+                cover_fields,
+                &table_expr,
+                &mut field_value_occurrences,
+            );
+        }
+    }
+
+    let ref_tables = collect_rows_ref_tables(db, &rows);
+    let config_index = db.get_config_index_mut();
+    config_index.add_config_table_pk_occurrences(file_id, occurrences);
+    config_index.add_config_table_unique_occurrences(file_id, unique_occurrences);
+    config_index.add_config_table_edge_occurrences(file_id, edge_occurrences);
+    config_index.add_config_table_field_value_occurrences(file_id, field_value_occurrences);
+    config_index.add_config_table_data_occurrences(file_id, data_occurrences);
+    config_index.add_config_table_rows(file_id, rows);
+    config_index.add_config_table_ref_tables(file_id, ref_tables);
+}
 
-        collect_table_occurrences(
-            db,
-            infer_cache,
-            config_table_id.clone(),
-            &index_keys,
-            &table_expr,
-            &mut occurrences,
-        );
+/// 收集记录中出现的 Bean (包括嵌套的 Bean) 通过 `v.ref`/`v.union_ref` 引用的配置表
+fn collect_rows_ref_tables(db: &DbIndex, rows: &[ConfigTableRow]) -> HashSet<LuaTypeDeclId> {
+    let mut beans = HashSet::new();
+    for row in rows {
+        collect_value_beans(&row.value.value, &mut beans);
     }
+    beans
+        .iter()
+        .flat_map(|bean_id| collect_bean_ref_tables(db, bean_id))
+        .collect()
+}
+
+fn collect_value_beans(value: &ConfigValue, out: &mut HashSet<LuaTypeDeclId>) {
+    if let Some(bean) = value.get_bean() {
+        out.insert(bean.clone());
+    }
+    for entry in value.get_entries() {
+        collect_value_beans(&entry.value.value, out);
+    }
+}
+
+/// 收集记录中指定字段的常量值
+fn collect_table_field_value_occurrences(
+    db: &DbIndex,
+    infer_cache: &mut crate::LuaInferCache,
+    config_table: &LuaTypeDeclId,
+    bean_id: Option<&LuaTypeDeclId>,
+    fields: &[LuaMemberKey],
+    table: &LuaTableExpr,
+    out: &mut Vec<ConfigTableFieldValueOccurrence>,
+) {
+    for field in table.get_fields() {
+        let Some(LuaExpr::TableExpr(row)) = field.get_value_expr() else {
+            continue;
+        };
+
+        for row_field in row.get_fields() {
+            let Some(field_key) = row_field.get_field_key() else {
+                continue;
+            };
+            let Ok(member_key) = LuaMemberKey::from_index_key(db, infer_cache, &field_key) else {
+                continue;
+            };
+            if !fields.contains(&member_key) {
+                continue;
+            }
+            let Some(value_expr) = row_field.get_value_expr() else {
+                continue;
+            };
+            let Ok(value) = infer_expr(db, infer_cache, value_expr) else {
+                continue;
+            };
+            if !is_literal_value(&value) {
+                continue;
+            }
+
+            out.push(ConfigTableFieldValueOccurrence {
+                config_table: config_table.clone(),
+                value: resolve_enum_value(db, bean_id, &member_key, value),
+                field: member_key,
+                range: row_field.get_range(),
+            });
+        }
+    }
+}
+
+/// 收集记录中引用同表记录的字段值, 字段值可以是字面量或字面量列表
+fn collect_table_edge_occurrences(
+    db: &DbIndex,
+    infer_cache: &mut crate::LuaInferCache,
+    config_table: &LuaTypeDeclId,
+    bean_id: Option<&LuaTypeDeclId>,
+    pk: &LuaMemberKey,
+    edge_fields: &[LuaMemberKey],
+    table: &LuaTableExpr,
+    out: &mut Vec<ConfigTableEdgeOccurrence>,
+) {
+    for field in table.get_fields() {
+        let Some(LuaExpr::TableExpr(row)) = field.get_value_expr() else {
+            continue;
+        };
+
+        let mut source: Option<LuaType> = None;
+        let mut edges: Vec<(LuaMemberKey, Vec<LuaType>, rowan::TextRange)> = Vec::new();
+        for row_field in row.get_fields() {
+            let Some(field_key) = row_field.get_field_key() else {
+                continue;
+            };
+            let Ok(member_key) = LuaMemberKey::from_index_key(db, infer_cache, &field_key) else {
+                continue;
+            };
+            let Some(value_expr) = row_field.get_value_expr() else {
+                continue;
+            };
+
+            if &member_key == pk {
+                source = infer_literal_value(db, infer_cache, value_expr.clone())
+                    .map(|value| resolve_enum_value(db, bean_id, pk, value));
+            }
+            if !edge_fields.contains(&member_key) {
+                continue;
+            }
+
+            let targets: Vec<LuaType> = match value_expr {
+                LuaExpr::TableExpr(list) => list
+                    .get_fields()
+                    .filter(|element| element.is_value_field())
+                    .filter_map(|element| element.get_value_expr())
+                    .filter_map(|element| infer_literal_value(db, infer_cache, element))
+                    .collect(),
+                value_expr => infer_literal_value(db, infer_cache, value_expr)
+                    .map(|value| resolve_enum_value(db, bean_id, &member_key, value))
+                    .into_iter()
+                    .collect(),
+            };
+            if !targets.is_empty() {
+                edges.push((member_key, targets, row_field.get_range()));
+            }
+        }
+
+        let Some(source) = source else {
+            continue;
+        };
+        for (field, targets, range) in edges {
+            out.push(ConfigTableEdgeOccurrence {
+                config_table: config_table.clone(),
+                field,
+                source: source.clone(),
+                targets,
+                range,
+            });
+        }
+    }
+}
+
+fn infer_literal_value(
+    db: &DbIndex,
+    infer_cache: &mut crate::LuaInferCache,
+    expr: LuaExpr,
+) -> Option<LuaType> {
+    if !matches!(expr, LuaExpr::LiteralExpr(_)) {
+        return None;
+    }
+    infer_expr(db, infer_cache, expr)
+        .ok()
+        .filter(is_literal_value)
+}
+
+/// 收集 `t.unique` 约束字段的值, 只收集全部字段均为字面量的记录
+fn collect_table_unique_occurrences(
+    db: &DbIndex,
+    infer_cache: &mut crate::LuaInferCache,
+    config_table: &LuaTypeDeclId,
+    bean_id: Option<&LuaTypeDeclId>,
+    uniques: &[Arc<[LuaMemberKey]>],
+    table: &LuaTableExpr,
+    out: &mut Vec<ConfigTableUniqueOccurrence>,
+) {
+    for field in table.get_fields() {
+        let Some(row_expr) = field.get_value_expr() else {
+            continue;
+        };
+
+        let Ok(row_typ) = infer_expr(db, infer_cache, row_expr) else {
+            continue;
+        };
+
+        'unique: for fields in uniques {
+            let mut values: Vec<LuaType> = Vec::with_capacity(fields.len());
+            let mut ranges = Vec::with_capacity(fields.len());
+            for key in fields.iter() {
+                let Some(member_info) = find_members_with_key(db, &row_typ, key.clone(), false)
+                    .and_then(|infos| infos.into_iter().next())
+                else {
+                    continue 'unique;
+                };
+                if !is_literal_value(&member_info.typ) {
+                    continue 'unique;
+                }
+                let Some(LuaSemanticDeclId::Member(member_id)) = member_info.property_owner_id
+                else {
+                    continue 'unique;
+                };
+
+                values.push(resolve_enum_value(db, bean_id, key, member_info.typ));
+                ranges.push(member_id.get_syntax_id().get_range());
+            }
+
+            out.push(ConfigTableUniqueOccurrence {
+                config_table: config_table.clone(),
+                fields: fields.clone(),
+                values,
+                ranges,
+            });
+        }
+    }
+}
+
+/// 按名称书写的枚举值以成员的值参与索引
+fn resolve_enum_value(
+    db: &DbIndex,
+    bean_id: Option<&LuaTypeDeclId>,
+    field: &LuaMemberKey,
+    value: LuaType,
+) -> LuaType {
+    match bean_id {
+        Some(bean_id) => resolve_field_enum_value(db, bean_id, field, value),
+        None => value,
+    }
+}
 
-    db.get_config_index_mut()
-        .add_config_table_pk_occurrences(file_id, occurrences);
+fn is_literal_value(typ: &LuaType) -> bool {
+    matches!(
+        typ,
+        LuaType::IntegerConst(_)
+            | LuaType::DocIntegerConst(_)
+            | LuaType::StringConst(_)
+            | LuaType::DocStringConst(_)
+            | LuaType::BooleanConst(_)
+            | LuaType::DocBooleanConst(_)
+            | LuaType::FloatConst(_)
+    )
 }
 
 fn collect_table_occurrences(
     db: &DbIndex,
     infer_cache: &mut crate::LuaInferCache,
-    config_table: crate::LuaTypeDeclId,
+    config_table: LuaTypeDeclId,
+    bean_id: Option<&LuaTypeDeclId>,
     index_keys: &ConfigTableIndexKeys,
     table: &LuaTableExpr,
     out: &mut Vec<ConfigTablePkOccurrence>,
@@ -109,7 +440,7 @@ fn collect_table_occurrences(
                     out.push(ConfigTablePkOccurrence::Solo {
                         config_table: config_table.clone(),
                         key: Arc::new(key.clone()),
-                        value: member_info.typ.clone(),
+                        value: resolve_enum_value(db, bean_id, key, member_info.typ.clone()),
                         range,
                     });
                 }
@@ -153,7 +484,12 @@ fn collect_table_occurrences(
                         }
                     };
 
-                    values.push(member_info.typ.clone());
+                    values.push(resolve_enum_value(
+                        db,
+                        bean_id,
+                        key,
+                        member_info.typ.clone(),
+                    ));
                     ranges.push(range);
                 }
 
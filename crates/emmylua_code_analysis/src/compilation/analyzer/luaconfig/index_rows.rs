@@ -0,0 +1,186 @@
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaExpr, LuaIndexKey, LuaTableExpr};
+
+use crate::{
+    ConfigEntry, ConfigTableRow, ConfigValue, ConfigValueNode, LuaInferCache, LuaMemberKey,
+    LuaType, LuaTypeDeclId,
+    db_index::DbIndex,
+    find_members_with_key, infer_expr,
+    semantic::shared::luaconfig::{
+        BEAN, CONFIG_TABLE, get_vector_component_key, get_vector_components,
+    },
+};
+
+/// 收集配置表数据中的全部记录
+pub fn collect_table_rows(
+    db: &DbIndex,
+    infer_cache: &mut LuaInferCache,
+    config_table: &LuaTypeDeclId,
+    table: &LuaTableExpr,
+    out: &mut Vec<ConfigTableRow>,
+) {
+    let row_type = CONFIG_TABLE.get_bean_id(db, config_table).map(LuaType::Ref);
+    let mut idx = 0;
+    for field in table.get_fields() {
+        let Some(value_expr) = field.get_value_expr() else {
+            continue;
+        };
+        let key = if field.is_value_field() {
+            idx += 1;
+            LuaMemberKey::Integer(idx)
+        } else {
+            let Some(field_key) = field.get_field_key() else {
+                continue;
+            };
+            let Ok(key) = LuaMemberKey::from_index_key(db, infer_cache, &field_key) else {
+                continue;
+            };
+            key
+        };
+
+        out.push(ConfigTableRow {
+            config_table: config_table.clone(),
+            key,
+            range: field.get_range(),
+            value: build_value(db, infer_cache, row_type.as_ref(), value_expr),
+        });
+    }
+}
+
+/// 按声明的类型构建值, `expected` 用于识别嵌套的 Bean 与容器元素类型
+fn build_value(
+    db: &DbIndex,
+    infer_cache: &mut LuaInferCache,
+    expected: Option<&LuaType>,
+    expr: LuaExpr,
+) -> ConfigValueNode {
+    let range = expr.get_range();
+    let value = match expr {
+        LuaExpr::TableExpr(table) => build_table(db, infer_cache, expected, &table),
+        LuaExpr::ParenExpr(paren) => match paren.get_expr() {
+            Some(inner) => return build_value(db, infer_cache, expected, inner),
+            None => ConfigValue::Unknown,
+        },
+        expr => infer_expr(db, infer_cache, expr)
+            .ok()
+            .and_then(|typ| ConfigValue::from_type(&typ))
+            .unwrap_or(ConfigValue::Unknown),
+    };
+    ConfigValueNode { value, range }
+}
+
+fn build_table(
+    db: &DbIndex,
+    infer_cache: &mut LuaInferCache,
+    expected: Option<&LuaType>,
+    table: &LuaTableExpr,
+) -> ConfigValue {
+    let bean = expected.and_then(|typ| find_bean_type(db, typ));
+    let element_type = match &bean {
+        Some(_) => None,
+        None => expected.and_then(get_element_type),
+    };
+    let components = bean.as_ref().and_then(|id| get_vector_components(db, id));
+
+    let mut entries = Vec::new();
+    let mut idx = 0;
+    for field in table.get_fields() {
+        let Some(value_expr) = field.get_value_expr() else {
+            continue;
+        };
+        let (key, key_range) = if field.is_value_field() {
+            idx += 1;
+            (LuaMemberKey::Integer(idx), None)
+        } else {
+            let Some(field_key) = field.get_field_key() else {
+                continue;
+            };
+            let Ok(key) = LuaMemberKey::from_index_key(db, infer_cache, &field_key) else {
+                continue;
+            };
+            (key, get_key_range(&field_key))
+        };
+        // 向量按位置书写的分量转换为分量名
+        let key = match components {
+            Some(components) => match get_vector_component_key(components, &key) {
+                Some(key) => key,
+                None => continue,
+            },
+            None => key,
+        };
+
+        let field_type = match &bean {
+            Some(bean) => get_field_type(db, bean, &key),
+            None => element_type.clone(),
+        };
+        entries.push(ConfigEntry {
+            key,
+            key_range,
+            value: build_value(db, infer_cache, field_type.as_ref(), value_expr),
+        });
+    }
+
+    ConfigValue::Table {
+        bean,
+        entries: entries.into_boxed_slice(),
+    }
+}
+
+fn get_key_range(key: &LuaIndexKey) -> Option<rowan::TextRange> {
+    match key {
+        LuaIndexKey::Name(name) => Some(name.get_range()),
+        LuaIndexKey::String(string) => Some(string.get_range()),
+        LuaIndexKey::Integer(number) => Some(number.get_range()),
+        LuaIndexKey::Expr(expr) => Some(expr.get_range()),
+        LuaIndexKey::Idx(_) => None,
+    }
+}
+
+/// 类型(去除 `nil` 与特性后)为 Bean 或内置向量类型时返回该类型
+fn find_bean_type(db: &DbIndex, typ: &LuaType) -> Option<LuaTypeDeclId> {
+    match typ.strip_attributed() {
+        LuaType::Ref(id) | LuaType::Def(id)
+            if BEAN.is_bean(db, id) || get_vector_components(db, id).is_some() =>
+        {
+            Some(id.clone())
+        }
+        LuaType::Union(union) => {
+            let mut types = union.into_vec().into_iter().filter(|inner| !inner.is_nil());
+            let (Some(inner), None) = (types.next(), types.next()) else {
+                return None;
+            };
+            find_bean_type(db, &inner)
+        }
+        _ => None,
+    }
+}
+
+/// 容器的元素类型, 映射为值的类型
+fn get_element_type(typ: &LuaType) -> Option<LuaType> {
+    match typ.strip_attributed() {
+        LuaType::Array(array) => Some(array.get_base().clone()),
+        LuaType::Generic(generic) => {
+            let params = generic.get_params();
+            match generic.get_base_type_id_ref().get_name() {
+                "array" | "list" | "set" => params.first().cloned(),
+                "map" => params.get(1).cloned(),
+                _ => None,
+            }
+        }
+        LuaType::TableGeneric(params) => params.get(1).cloned(),
+        LuaType::Union(union) => {
+            let mut types = union.into_vec().into_iter().filter(|inner| !inner.is_nil());
+            let (Some(inner), None) = (types.next(), types.next()) else {
+                return None;
+            };
+            get_element_type(&inner)
+        }
+        _ => None,
+    }
+}
+
+fn get_field_type(db: &DbIndex, bean: &LuaTypeDeclId, key: &LuaMemberKey) -> Option<LuaType> {
+    find_members_with_key(db, &LuaType::Ref(bean.clone()), key.clone(), false)?
+        .into_iter()
+        .next()
+        .map(|member| member.typ)
+}
@@ -1,4 +1,6 @@
+mod data_mode;
 mod index_data;
+mod index_rows;
 mod resolve_keys;
 
 use crate::{
@@ -8,13 +10,15 @@ use crate::{
 
 use super::{AnalyzeContext, infer_cache_manager::InferCacheManager};
 
+pub use data_mode::mark_data_mode_files;
+
 pub struct LuaConfigPipeline;
 
 impl AnalysisPipeline for LuaConfigPipeline {
     fn analyze(db: &mut DbIndex, context: &mut AnalyzeContext) {
         let _p = Profile::cond_new("luaconfig analyze", context.tree_list.len() > 1);
 
-        // 收集 ConfigTable 的主键字段
+        // 收集 ConfigTable 的主键字段与唯一性约束
         for in_filed_tree in context.tree_list.iter() {
             let file_id = in_filed_tree.file_id;
             // 获取当前文件定义的所有类型
@@ -26,6 +30,9 @@ impl AnalysisPipeline for LuaConfigPipeline {
                 if is_sub_type_of(db, &type_decl_id, CONFIG_TABLE.get_id()) {
                     resolve_keys::resolve_config_table_mode(db, file_id, &type_decl_id);
                     resolve_keys::resolve_config_table_index(db, file_id, &type_decl_id);
+                    resolve_keys::resolve_config_table_uniques(db, file_id, &type_decl_id);
+                    resolve_keys::resolve_config_table_graph_rules(db, file_id, &type_decl_id);
+                    resolve_keys::resolve_config_table_cover_enums(db, file_id, &type_decl_id);
                 }
             }
         }
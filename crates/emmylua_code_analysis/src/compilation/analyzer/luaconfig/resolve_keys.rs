@@ -1,11 +1,15 @@
+use std::sync::Arc;
+
 use crate::{
-    ConfigTableIndexKeys, LuaMemberKey, LuaSemanticDeclId, LuaType,
-    db_index::DbIndex,
+    ConfigTableCoverEnumRule, ConfigTableGraphRules, ConfigTableIndexKeys, ConfigTableTreeRule,
+    LuaMemberKey, LuaSemanticDeclId, LuaType,
+    db_index::{DbIndex, LuaMemberOwner},
     find_index_operations, is_sub_type_of,
     semantic::attributes::{
-        ConfigTableIndexMode, ConfigTableMode, TIndexAttribute, TModeAttribute,
+        ConfigTableIndexMode, ConfigTableMode, TCoverEnumAttribute, TIndexAttribute,
+        TModeAttribute, TTreeAttribute, TUniqueAttribute, VAcyclicAttribute,
     },
-    semantic::shared::luaconfig::BEAN,
+    semantic::shared::luaconfig::{BEAN, CONFIG_TABLE},
 };
 
 /// 解析 ConfigTable 的索引键并缓存到 LuaConfigIndex
@@ -14,8 +18,6 @@ pub fn resolve_config_table_index(
     file_id: crate::FileId,
     config_table_id: &crate::LuaTypeDeclId,
 ) {
-    use crate::db_index::LuaMemberOwner;
-
     // 检查是否已经缓存
     if db.get_config_index().has_config_table_keys(config_table_id) {
         return;
@@ -174,6 +176,170 @@ pub fn resolve_config_table_mode(
         .add_config_table_mode(file_id, config_table_id.clone(), inferred);
 }
 
+/// 解析 ConfigTable 的 t.unique 约束并缓存到 LuaConfigIndex
+///
+/// 不存在的字段会被忽略, 由 `InvalidIndexField` 诊断报告.
+pub fn resolve_config_table_uniques(
+    db: &mut DbIndex,
+    file_id: crate::FileId,
+    config_table_id: &crate::LuaTypeDeclId,
+) {
+    if db
+        .get_config_index()
+        .get_config_table_uniques(config_table_id)
+        .is_some()
+    {
+        return;
+    }
+
+    let Some(property) = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(config_table_id.clone()))
+    else {
+        return;
+    };
+    let unique_attrs = TUniqueAttribute::find_all_in(property);
+    if unique_attrs.is_empty() {
+        return;
+    }
+
+    let Some(bean_id) = CONFIG_TABLE.get_bean_id(db, config_table_id) else {
+        return;
+    };
+    let Some(bean_members) = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(bean_id))
+    else {
+        return;
+    };
+
+    let mut uniques: Vec<Arc<[LuaMemberKey]>> = Vec::new();
+    for attr in unique_attrs {
+        let mut fields: Vec<LuaMemberKey> = Vec::new();
+        for name in attr.get_fields() {
+            let key = LuaMemberKey::Name(name);
+            if bean_members.iter().any(|m| m.get_key() == &key) && !fields.contains(&key) {
+                fields.push(key);
+            }
+        }
+        if !fields.is_empty() {
+            uniques.push(Arc::from(fields));
+        }
+    }
+
+    if !uniques.is_empty() {
+        db.get_config_index_mut().add_config_table_uniques(
+            file_id,
+            config_table_id.clone(),
+            uniques,
+        );
+    }
+}
+
+/// 解析 ConfigTable 的 t.tree 与 Bean 字段上的 v.acyclic 约束并缓存到 LuaConfigIndex
+pub fn resolve_config_table_graph_rules(
+    db: &mut DbIndex,
+    file_id: crate::FileId,
+    config_table_id: &crate::LuaTypeDeclId,
+) {
+    if db
+        .get_config_index()
+        .get_config_table_graph_rules(config_table_id)
+        .is_some()
+    {
+        return;
+    }
+
+    let Some(bean_id) = CONFIG_TABLE.get_bean_id(db, config_table_id) else {
+        return;
+    };
+    let Some(bean_members) = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(bean_id))
+    else {
+        return;
+    };
+
+    let property_index = db.get_property_index();
+    let mut rules = ConfigTableGraphRules::default();
+    for member in bean_members.iter() {
+        let is_acyclic = property_index
+            .get_property(&LuaSemanticDeclId::Member(member.get_id()))
+            .is_some_and(VAcyclicAttribute::is_in);
+        if is_acyclic && !rules.acyclic_fields.contains(member.get_key()) {
+            rules.acyclic_fields.push(member.get_key().clone());
+        }
+    }
+
+    let tree_attr = property_index
+        .get_property(&LuaSemanticDeclId::TypeDecl(config_table_id.clone()))
+        .and_then(TTreeAttribute::find_in);
+    if let Some(tree_attr) = tree_attr
+        && let Some(parent) = tree_attr.get_parent()
+    {
+        let parent = LuaMemberKey::Name(parent.into());
+        if bean_members.iter().any(|m| m.get_key() == &parent) {
+            rules.tree = Some(ConfigTableTreeRule {
+                parent,
+                max_depth: tree_attr.get_max_depth(),
+            });
+        }
+    }
+
+    if rules.acyclic_fields.is_empty() && rules.tree.is_none() {
+        return;
+    }
+    db.get_config_index_mut()
+        .add_config_table_graph_rules(file_id, config_table_id.clone(), rules);
+}
+
+/// 解析 ConfigTable 的 t.cover_enum 约束并缓存到 LuaConfigIndex
+///
+/// 签名不合法的约束会被忽略, 由 `EnumCoverageMismatch` 诊断报告.
+pub fn resolve_config_table_cover_enums(
+    db: &mut DbIndex,
+    file_id: crate::FileId,
+    config_table_id: &crate::LuaTypeDeclId,
+) {
+    if db
+        .get_config_index()
+        .get_config_table_cover_enums(config_table_id)
+        .is_some()
+    {
+        return;
+    }
+
+    let Some(property) = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(config_table_id.clone()))
+    else {
+        return;
+    };
+    let rules: Vec<ConfigTableCoverEnumRule> = TCoverEnumAttribute::find_all_in(property)
+        .iter()
+        .filter_map(|attr| {
+            let enum_decl = db
+                .get_type_index()
+                .find_type_decl(file_id, attr.get_enum_name()?)?;
+            if !enum_decl.is_enum() {
+                return None;
+            }
+            Some(ConfigTableCoverEnumRule {
+                field: LuaMemberKey::Name(attr.get_field()?.into()),
+                enum_id: enum_decl.get_id(),
+            })
+        })
+        .collect();
+
+    if !rules.is_empty() {
+        db.get_config_index_mut().add_config_table_cover_enums(
+            file_id,
+            config_table_id.clone(),
+            rules,
+        );
+    }
+}
+
 /// 从 t.index 属性解析索引键
 fn resolve_index_keys_from_attr(
     index_attr: &TIndexAttribute,
@@ -29,6 +29,7 @@ pub fn analyze(db: &mut DbIndex, need_analyzed_files: Vec<InFiled<LuaChunk>>, co
     for (workspace_id, mut context) in contexts {
         let profile_log = format!("analyze workspace {}", workspace_id);
         let _p = Profile::cond_new(&profile_log, context.tree_list.len() > 1);
+        luaconfig::mark_data_mode_files(db, &mut context);
         run_analysis::<decl::DeclAnalysisPipeline>(db, &mut context);
         run_analysis::<doc::DocAnalysisPipeline>(db, &mut context);
         run_analysis::<flow::FlowAnalysisPipeline>(db, &mut context);
@@ -117,7 +118,7 @@ fn module_analyze(
         }
     }
 
-    contexts.sort_by(|a, b| a.0.cmp(&b.0));
+    contexts.sort_by_key(|a| a.0);
 
     contexts.extend(main_vec);
     contexts
@@ -129,6 +130,8 @@ pub struct AnalyzeContext {
     #[allow(unused)]
     config: Arc<Emmyrc>,
     metas: HashSet<FileId>,
+    /// 以数据模式分析的文件, 跳过流分析与闭包分析
+    data_mode_files: HashSet<FileId>,
     unresolves: Vec<(UnResolve, InferFailReason)>,
     infer_manager: InferCacheManager,
 }
@@ -139,6 +142,7 @@ impl AnalyzeContext {
             tree_list: Vec::new(),
             config: emmyrc,
             metas: HashSet::new(),
+            data_mode_files: HashSet::new(),
             unresolves: Vec::new(),
             infer_manager: InferCacheManager::new(),
         }
@@ -53,6 +53,7 @@ impl LuaCompilation {
         }
 
         analyzer::analyze(&mut self.db, need_analyzed_files, self.emmyrc.clone());
+        self.db.update_generation();
     }
 
     pub fn remove_index(&mut self, file_ids: Vec<FileId>) {
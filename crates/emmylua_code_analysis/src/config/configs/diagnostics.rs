@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 use lsp_types::DiagnosticSeverity;
 use schemars::JsonSchema;
@@ -31,6 +31,14 @@ pub struct EmmyrcDiagnostic {
     /// Delay between opening/changing a file and scanning it for errors, in milliseconds.
     #[schemars(extend("x-vscode-setting" = true))]
     pub diagnostic_interval: Option<u64>,
+    /// External executables that validate ConfigTable data with project-specific rules.
+    /// They only run when the language server is started with `--allow-external-validators true`.
+    #[serde(default)]
+    pub external_validators: Vec<EmmyrcExternalValidator>,
+    /// Number of threads used to validate ConfigTable data of multiple files in batch checks.
+    /// `0` uses the available parallelism.
+    #[serde(default)]
+    pub luaconfig_threads: usize,
 }
 
 impl Default for EmmyrcDiagnostic {
@@ -43,6 +51,8 @@ impl Default for EmmyrcDiagnostic {
             severity: HashMap::new(),
             enables: Vec::new(),
             diagnostic_interval: Some(500),
+            external_validators: Vec::new(),
+            luaconfig_threads: 0,
         }
     }
 }
@@ -51,6 +61,36 @@ fn default_true() -> bool {
     true
 }
 
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+/// An external validator for ConfigTable data.
+///
+/// The executable receives the schema and rows of the selected ConfigTables as JSON on stdin
+/// and writes the diagnostics it found as JSON to stdout.
+pub struct EmmyrcExternalValidator {
+    /// The validator name, used in diagnostic codes as `external-validator/<name>/<rule>`.
+    pub name: String,
+    /// The command to run the validator. Paths are resolved against the workspace root,
+    /// which is also the working directory of the validator.
+    pub program: String,
+    /// The arguments to pass to the validator. Arguments starting with `./` or `~`
+    /// and `${workspaceFolder}` are expanded like other paths in the config.
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Names of the ConfigTables whose data is sent to the validator.
+    pub tables: Vec<String>,
+    /// Maximum time to wait for the validator, in milliseconds.
+    #[serde(default = "default_validator_timeout")]
+    pub timeout: u64,
+    /// The directory to run the validator in, set to the workspace root when the config is loaded.
+    #[serde(skip)]
+    pub working_dir: Option<PathBuf>,
+}
+
+fn default_validator_timeout() -> u64 {
+    10000
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum DiagnosticSeveritySetting {
@@ -59,6 +59,22 @@ pub struct EmmyrcInlayHint {
     #[serde(default = "default_false")]
     #[schemars(extend("x-vscode-setting" = true))]
     pub enum_param_hint: bool,
+    /// Show the default values of Bean fields omitted in config data.
+    ///
+    /// Example:
+    ///
+    /// ```lua
+    /// ---@class Item: Bean
+    /// ---@field id int
+    /// ---@[v.default(1)]
+    /// ---@field stack int
+    ///
+    /// ---@type Item
+    /// local item = { id = 1 --[[ Hint: stack = 1 ]] }
+    /// ```
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub default_value_hint: bool,
 }
 
 impl Default for EmmyrcInlayHint {
@@ -71,6 +87,7 @@ impl Default for EmmyrcInlayHint {
             override_hint: default_true(),
             meta_call_hint: default_true(),
             enum_param_hint: default_false(),
+            default_value_hint: default_true(),
         }
     }
 }
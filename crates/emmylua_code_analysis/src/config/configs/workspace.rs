@@ -41,6 +41,14 @@ pub struct EmmyrcWorkspace {
     #[serde(default = "enable_reindex_default")]
     #[schemars(extend("x-vscode-setting" = true))]
     pub enable_reindex: bool,
+    /// Globs of generated data files analyzed in data mode, which skips flow and closure analysis
+    /// and only runs type-shape and config table diagnostics. eg: ["data/**/*.lua"]
+    #[serde(default)]
+    pub data_mode_globs: Vec<String>,
+    /// Files larger than this size in bytes whose statements are only typed table literals and
+    /// returns are analyzed in data mode automatically. `0` disables auto detection.
+    #[serde(default = "data_mode_min_size_default")]
+    pub data_mode_min_size: usize,
 }
 
 impl Default for EmmyrcWorkspace {
@@ -56,6 +64,8 @@ impl Default for EmmyrcWorkspace {
             module_map: Vec::new(),
             reindex_duration: 5000,
             enable_reindex: false,
+            data_mode_globs: Vec::new(),
+            data_mode_min_size: data_mode_min_size_default(),
         }
     }
 }
@@ -77,3 +87,7 @@ fn reindex_duration_default() -> u64 {
 fn enable_reindex_default() -> bool {
     false
 }
+
+fn data_mode_min_size_default() -> usize {
+    64 * 1024
+}
@@ -10,10 +10,10 @@ use std::{
 pub use config_loader::{load_configs, load_configs_raw};
 pub use configs::{
     DiagnosticSeveritySetting, DocSyntax, EmmyrcCodeAction, EmmyrcCodeLens, EmmyrcCompletion,
-    EmmyrcDiagnostic, EmmyrcDoc, EmmyrcDocumentColor, EmmyrcExternalTool, EmmyrcFilenameConvention,
-    EmmyrcHover, EmmyrcInlayHint, EmmyrcInlineValues, EmmyrcLuaVersion, EmmyrcReference,
-    EmmyrcReformat, EmmyrcResource, EmmyrcRuntime, EmmyrcSemanticToken, EmmyrcSignature,
-    EmmyrcStrict, EmmyrcWorkspace, EmmyrcWorkspaceModuleMap,
+    EmmyrcDiagnostic, EmmyrcDoc, EmmyrcDocumentColor, EmmyrcExternalTool, EmmyrcExternalValidator,
+    EmmyrcFilenameConvention, EmmyrcHover, EmmyrcInlayHint, EmmyrcInlineValues, EmmyrcLuaVersion,
+    EmmyrcReference, EmmyrcReformat, EmmyrcResource, EmmyrcRuntime, EmmyrcSemanticToken,
+    EmmyrcSignature, EmmyrcStrict, EmmyrcWorkspace, EmmyrcWorkspaceModuleMap,
 };
 use emmylua_parser::{LuaLanguageLevel, LuaNonStdSymbolSet, ParserConfig, SpecialFunction};
 use regex::Regex;
@@ -128,9 +128,26 @@ impl Emmyrc {
             process_and_dedup(self.workspace.ignore_dir.iter(), workspace_root);
 
         self.resource.paths = process_and_dedup(self.resource.paths.iter(), workspace_root);
+
+        for validator in &mut self.diagnostics.external_validators {
+            if is_path_arg(&validator.program) || validator.program.contains(['/', '\\']) {
+                validator.program = pre_process_path(&validator.program, workspace_root);
+            }
+            for arg in &mut validator.args {
+                if is_path_arg(arg) {
+                    *arg = pre_process_path(arg, workspace_root);
+                }
+            }
+            validator.working_dir = Some(workspace_root.to_path_buf());
+        }
     }
 }
 
+/// 命令参数中的路径: 以 `./`、`~` 开头或包含 `${workspaceFolder}` 等变量, 其他参数原样传递
+fn is_path_arg(arg: &str) -> bool {
+    arg.starts_with("./") || arg.starts_with('~') || arg.contains("${") || arg.starts_with('$')
+}
+
 fn pre_process_path(path: &str, workspace: &Path) -> String {
     let mut path = path.to_string();
     path = replace_env_var(&path);
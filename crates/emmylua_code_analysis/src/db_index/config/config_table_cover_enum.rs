@@ -0,0 +1,19 @@
+use rowan::TextRange;
+
+use crate::{LuaMemberKey, LuaType, LuaTypeDeclId};
+
+/// 配置表 `t.cover_enum` 约束
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigTableCoverEnumRule {
+    pub field: LuaMemberKey,
+    pub enum_id: LuaTypeDeclId,
+}
+
+/// 配置表记录中被 `t.cover_enum` 约束的字段值
+#[derive(Debug, Clone)]
+pub struct ConfigTableFieldValueOccurrence {
+    pub config_table: LuaTypeDeclId,
+    pub field: LuaMemberKey,
+    pub value: LuaType,
+    pub range: TextRange,
+}
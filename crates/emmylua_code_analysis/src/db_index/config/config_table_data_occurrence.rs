@@ -0,0 +1,10 @@
+use emmylua_parser::LuaSyntaxId;
+
+use crate::LuaTypeDeclId;
+
+/// 配置表数据的位置, 即类型为配置表的变量的初始值
+#[derive(Debug, Clone)]
+pub struct ConfigTableDataOccurrence {
+    pub config_table: LuaTypeDeclId,
+    pub syntax_id: LuaSyntaxId,
+}
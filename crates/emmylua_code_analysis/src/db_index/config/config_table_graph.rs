@@ -0,0 +1,43 @@
+use rowan::TextRange;
+
+use crate::{LuaMemberKey, LuaType, LuaTypeDeclId};
+
+/// 配置表记录间引用关系的约束
+#[derive(Debug, Clone, Default)]
+pub struct ConfigTableGraphRules {
+    /// 声明了 `v.acyclic` 的字段
+    pub acyclic_fields: Vec<LuaMemberKey>,
+    /// `t.tree` 约束
+    pub tree: Option<ConfigTableTreeRule>,
+}
+
+impl ConfigTableGraphRules {
+    /// 需要收集引用关系的字段
+    pub fn edge_fields(&self) -> Vec<LuaMemberKey> {
+        let mut fields = self.acyclic_fields.clone();
+        if let Some(tree) = &self.tree
+            && !fields.contains(&tree.parent)
+        {
+            fields.push(tree.parent.clone());
+        }
+        fields
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigTableTreeRule {
+    pub parent: LuaMemberKey,
+    pub max_depth: Option<usize>,
+}
+
+/// 配置表记录通过字段引用同表记录
+#[derive(Debug, Clone)]
+pub struct ConfigTableEdgeOccurrence {
+    pub config_table: LuaTypeDeclId,
+    pub field: LuaMemberKey,
+    /// 所在记录的主键值
+    pub source: LuaType,
+    /// 被引用记录的主键值
+    pub targets: Vec<LuaType>,
+    pub range: TextRange,
+}
@@ -0,0 +1,271 @@
+use std::collections::{HashMap, HashSet};
+
+use rowan::TextRange;
+
+use crate::{FileId, LuaMemberKey, LuaType, LuaTypeDeclId};
+
+use super::ConfigTablePkOccurrence;
+
+/// 主键值在数据中的位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigTablePkLocation {
+    pub file_id: FileId,
+    pub range: TextRange,
+}
+
+/// 配置表主键值索引, 随数据文件的增删增量维护
+///
+/// 联合主键的每个字段也会单独记录, 以便 `v.ref` 引用联合主键中的单个字段.
+#[derive(Debug, Default)]
+pub struct ConfigTablePkValueIndex {
+    // table -> key -> value -> locations
+    values:
+        HashMap<LuaTypeDeclId, HashMap<LuaMemberKey, HashMap<LuaType, Vec<ConfigTablePkLocation>>>>,
+    // table -> 按主键顺序排列的值 -> locations
+    union_values: HashMap<LuaTypeDeclId, HashMap<Vec<LuaType>, Vec<ConfigTablePkLocation>>>,
+    // table -> 自上次取出以来出现或消失的值, 同一个值先消失再出现时相互抵消
+    changes: HashMap<LuaTypeDeclId, HashSet<PkValueChange>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PkValueChange {
+    Solo(LuaMemberKey, LuaType),
+    Union(Vec<LuaType>),
+}
+
+impl ConfigTablePkValueIndex {
+    pub fn add_occurrences(&mut self, file_id: FileId, occurrences: &[ConfigTablePkOccurrence]) {
+        for occ in occurrences {
+            match occ {
+                ConfigTablePkOccurrence::Solo {
+                    config_table,
+                    key,
+                    value,
+                    range,
+                } => {
+                    self.add_value(file_id, config_table, key, value, *range);
+                }
+                ConfigTablePkOccurrence::Union {
+                    config_table,
+                    keys,
+                    values,
+                    ranges,
+                } => {
+                    for ((key, value), range) in keys.iter().zip(values).zip(ranges) {
+                        self.add_value(file_id, config_table, key, value, *range);
+                    }
+
+                    let Some(range) = ranges.iter().copied().reduce(|a, b| a.cover(b)) else {
+                        continue;
+                    };
+                    let locations = self
+                        .union_values
+                        .entry(config_table.clone())
+                        .or_default()
+                        .entry(values.clone())
+                        .or_default();
+                    if locations.is_empty() {
+                        toggle_change(
+                            &mut self.changes,
+                            config_table,
+                            PkValueChange::Union(values.clone()),
+                        );
+                    }
+                    locations.push(ConfigTablePkLocation { file_id, range });
+                }
+            }
+        }
+    }
+
+    /// 移除文件之前添加的主键值, `occurrences` 必须是该文件之前添加的全部主键
+    pub fn remove_occurrences(&mut self, file_id: FileId, occurrences: &[ConfigTablePkOccurrence]) {
+        for occ in occurrences {
+            match occ {
+                ConfigTablePkOccurrence::Solo {
+                    config_table,
+                    key,
+                    value,
+                    ..
+                } => {
+                    self.remove_value(file_id, config_table, key, value);
+                }
+                ConfigTablePkOccurrence::Union {
+                    config_table,
+                    keys,
+                    values,
+                    ..
+                } => {
+                    for (key, value) in keys.iter().zip(values) {
+                        self.remove_value(file_id, config_table, key, value);
+                    }
+
+                    let Some(table_values) = self.union_values.get_mut(config_table) else {
+                        continue;
+                    };
+                    if let Some(locations) = table_values.get_mut(values) {
+                        locations.retain(|location| location.file_id != file_id);
+                        if locations.is_empty() {
+                            table_values.remove(values);
+                            toggle_change(
+                                &mut self.changes,
+                                config_table,
+                                PkValueChange::Union(values.clone()),
+                            );
+                        }
+                    }
+                    if table_values.is_empty() {
+                        self.union_values.remove(config_table);
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_value(
+        &mut self,
+        file_id: FileId,
+        config_table: &LuaTypeDeclId,
+        key: &LuaMemberKey,
+        value: &LuaType,
+        range: TextRange,
+    ) {
+        let locations = self
+            .values
+            .entry(config_table.clone())
+            .or_default()
+            .entry(key.clone())
+            .or_default()
+            .entry(value.clone())
+            .or_default();
+        if locations.is_empty() {
+            toggle_change(
+                &mut self.changes,
+                config_table,
+                PkValueChange::Solo(key.clone(), value.clone()),
+            );
+        }
+        locations.push(ConfigTablePkLocation { file_id, range });
+    }
+
+    fn remove_value(
+        &mut self,
+        file_id: FileId,
+        config_table: &LuaTypeDeclId,
+        key: &LuaMemberKey,
+        value: &LuaType,
+    ) {
+        let Some(table_values) = self.values.get_mut(config_table) else {
+            return;
+        };
+        if let Some(key_values) = table_values.get_mut(key) {
+            if let Some(locations) = key_values.get_mut(value) {
+                locations.retain(|location| location.file_id != file_id);
+                if locations.is_empty() {
+                    key_values.remove(value);
+                    toggle_change(
+                        &mut self.changes,
+                        config_table,
+                        PkValueChange::Solo(key.clone(), value.clone()),
+                    );
+                }
+            }
+            if key_values.is_empty() {
+                table_values.remove(key);
+            }
+        }
+        if table_values.is_empty() {
+            self.values.remove(config_table);
+        }
+    }
+
+    /// 获取主键字段为指定值的所有位置
+    pub fn get_locations(
+        &self,
+        config_table: &LuaTypeDeclId,
+        key: &LuaMemberKey,
+        value: &LuaType,
+    ) -> &[ConfigTablePkLocation] {
+        self.values
+            .get(config_table)
+            .and_then(|table_values| table_values.get(key))
+            .and_then(|key_values| key_values.get(value))
+            .map(|locations| locations.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn contains(
+        &self,
+        config_table: &LuaTypeDeclId,
+        key: &LuaMemberKey,
+        value: &LuaType,
+    ) -> bool {
+        !self.get_locations(config_table, key, value).is_empty()
+    }
+
+    /// 主键字段是否存在任何值
+    pub fn has_values(&self, config_table: &LuaTypeDeclId, key: &LuaMemberKey) -> bool {
+        self.values
+            .get(config_table)
+            .and_then(|table_values| table_values.get(key))
+            .is_some_and(|key_values| !key_values.is_empty())
+    }
+
+    /// 遍历主键字段的所有值
+    pub fn iter_values(
+        &self,
+        config_table: &LuaTypeDeclId,
+        key: &LuaMemberKey,
+    ) -> impl Iterator<Item = &LuaType> {
+        self.values
+            .get(config_table)
+            .and_then(|table_values| table_values.get(key))
+            .into_iter()
+            .flat_map(|key_values| key_values.keys())
+    }
+
+    /// 获取联合主键为指定值的所有位置, `values` 按配置表主键的顺序排列
+    pub fn get_union_locations(
+        &self,
+        config_table: &LuaTypeDeclId,
+        values: &[LuaType],
+    ) -> &[ConfigTablePkLocation] {
+        self.union_values
+            .get(config_table)
+            .and_then(|table_values| table_values.get(values))
+            .map(|locations| locations.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 联合主键是否存在任何值
+    pub fn has_union_values(&self, config_table: &LuaTypeDeclId) -> bool {
+        self.union_values
+            .get(config_table)
+            .is_some_and(|table_values| !table_values.is_empty())
+    }
+
+    /// 取出自上次调用以来主键值集合发生变化的配置表
+    pub fn take_changed_tables(&mut self) -> Vec<LuaTypeDeclId> {
+        self.changes
+            .drain()
+            .filter(|(_, changes)| !changes.is_empty())
+            .map(|(config_table, _)| config_table)
+            .collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.union_values.clear();
+        self.changes.clear();
+    }
+}
+
+fn toggle_change(
+    changes: &mut HashMap<LuaTypeDeclId, HashSet<PkValueChange>>,
+    config_table: &LuaTypeDeclId,
+    change: PkValueChange,
+) {
+    let table_changes = changes.entry(config_table.clone()).or_default();
+    if !table_changes.remove(&change) {
+        table_changes.insert(change);
+    }
+}
@@ -0,0 +1,143 @@
+use internment::ArcIntern;
+use rowan::{TextRange, TextSize};
+use smol_str::SmolStr;
+
+use crate::{LuaMemberKey, LuaType, LuaTypeDeclId};
+
+/// 配置数据中的值, 常量表达式在分析时折叠
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    Nil,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(ArcIntern<SmolStr>),
+    /// 表, `bean` 为声明的 Bean 类型, 容器等其余表为 `None`
+    Table {
+        bean: Option<LuaTypeDeclId>,
+        entries: Box<[ConfigEntry]>,
+    },
+    /// 无法在分析时求值的表达式
+    Unknown,
+}
+
+impl ConfigValue {
+    /// 常量类型对应的值
+    pub fn from_type(typ: &LuaType) -> Option<Self> {
+        match typ {
+            LuaType::Nil => Some(Self::Nil),
+            LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b) => Some(Self::Boolean(*b)),
+            LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => Some(Self::Integer(*i)),
+            LuaType::FloatConst(f) => Some(Self::Float(*f)),
+            LuaType::StringConst(s) | LuaType::DocStringConst(s) => Some(Self::String(s.clone())),
+            _ => None,
+        }
+    }
+
+    /// 值对应的常量类型, 与主键索引中的值一致
+    pub fn to_type(&self) -> Option<LuaType> {
+        match self {
+            Self::Nil => Some(LuaType::Nil),
+            Self::Boolean(b) => Some(LuaType::BooleanConst(*b)),
+            Self::Integer(i) => Some(LuaType::IntegerConst(*i)),
+            Self::Float(f) => Some(LuaType::FloatConst(*f)),
+            Self::String(s) => Some(LuaType::StringConst(s.clone())),
+            Self::Table { .. } | Self::Unknown => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// 整数与浮点数均视为数值
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Integer(i) => Some(*i as f64),
+            Self::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn get_bean(&self) -> Option<&LuaTypeDeclId> {
+        match self {
+            Self::Table { bean, .. } => bean.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// 表中的全部条目, 非表的值为空
+    pub fn get_entries(&self) -> &[ConfigEntry] {
+        match self {
+            Self::Table { entries, .. } => entries,
+            _ => &[],
+        }
+    }
+
+    /// 表中指定键的值, 同一个键出现多次时取第一个
+    pub fn get(&self, key: &LuaMemberKey) -> Option<&ConfigValueNode> {
+        self.get_entries()
+            .iter()
+            .find(|entry| entry.key == *key)
+            .map(|entry| &entry.value)
+    }
+
+    pub fn is_table(&self) -> bool {
+        matches!(self, Self::Table { .. })
+    }
+}
+
+/// 值及其在源码中的位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigValueNode {
+    pub value: ConfigValue,
+    pub range: TextRange,
+}
+
+/// 表中的一个条目
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigEntry {
+    pub key: LuaMemberKey,
+    /// 按位置书写的条目为 `None`
+    pub key_range: Option<TextRange>,
+    pub value: ConfigValueNode,
+}
+
+/// 配置表数据中的一条记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigTableRow {
+    pub config_table: LuaTypeDeclId,
+    /// 记录在数据表中的键, 按位置书写的记录为从 1 开始的下标
+    pub key: LuaMemberKey,
+    /// 整条记录的范围, 包括键
+    pub range: TextRange,
+    pub value: ConfigValueNode,
+}
+
+impl ConfigTableRow {
+    /// 记录中指定字段的值
+    pub fn get_field(&self, key: &LuaMemberKey) -> Option<&ConfigValueNode> {
+        self.value.value.get(key)
+    }
+
+    pub fn contains(&self, offset: TextSize) -> bool {
+        self.range.contains_inclusive(offset)
+    }
+}
@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use rowan::TextRange;
+
+use crate::{LuaMemberKey, LuaType, LuaTypeDeclId};
+
+/// 配置表中 `t.unique` 约束字段值的出现位置
+#[derive(Debug, Clone)]
+pub struct ConfigTableUniqueOccurrence {
+    pub config_table: LuaTypeDeclId,
+    pub fields: Arc<[LuaMemberKey]>,
+    pub values: Vec<LuaType>,
+    pub ranges: Vec<TextRange>,
+}
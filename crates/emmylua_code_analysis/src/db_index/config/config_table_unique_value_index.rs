@@ -0,0 +1,86 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{FileId, LuaMemberKey, LuaType, LuaTypeDeclId};
+
+use super::{ConfigTablePkLocation, ConfigTableUniqueOccurrence};
+
+/// 配置表 `t.unique` 约束字段值索引, 随数据文件的增删增量维护
+#[derive(Debug, Default)]
+pub struct ConfigTableUniqueValueIndex {
+    // table -> fields -> values -> locations
+    values: HashMap<
+        LuaTypeDeclId,
+        HashMap<Arc<[LuaMemberKey]>, HashMap<Vec<LuaType>, Vec<ConfigTablePkLocation>>>,
+    >,
+}
+
+impl ConfigTableUniqueValueIndex {
+    pub fn add_occurrences(
+        &mut self,
+        file_id: FileId,
+        occurrences: &[ConfigTableUniqueOccurrence],
+    ) {
+        for occ in occurrences {
+            let Some(range) = occ.ranges.first() else {
+                continue;
+            };
+            self.values
+                .entry(occ.config_table.clone())
+                .or_default()
+                .entry(occ.fields.clone())
+                .or_default()
+                .entry(occ.values.clone())
+                .or_default()
+                .push(ConfigTablePkLocation {
+                    file_id,
+                    range: *range,
+                });
+        }
+    }
+
+    /// 移除文件之前添加的字段值, `occurrences` 必须是该文件之前添加的全部出现位置
+    pub fn remove_occurrences(
+        &mut self,
+        file_id: FileId,
+        occurrences: &[ConfigTableUniqueOccurrence],
+    ) {
+        for occ in occurrences {
+            let Some(table_values) = self.values.get_mut(&occ.config_table) else {
+                continue;
+            };
+            if let Some(field_values) = table_values.get_mut(&occ.fields) {
+                if let Some(locations) = field_values.get_mut(&occ.values) {
+                    locations.retain(|location| location.file_id != file_id);
+                    if locations.is_empty() {
+                        field_values.remove(&occ.values);
+                    }
+                }
+                if field_values.is_empty() {
+                    table_values.remove(&occ.fields);
+                }
+            }
+            if table_values.is_empty() {
+                self.values.remove(&occ.config_table);
+            }
+        }
+    }
+
+    /// 获取唯一性约束字段为指定值的所有位置
+    pub fn get_locations(
+        &self,
+        config_table: &LuaTypeDeclId,
+        fields: &[LuaMemberKey],
+        values: &[LuaType],
+    ) -> &[ConfigTablePkLocation] {
+        self.values
+            .get(config_table)
+            .and_then(|table_values| table_values.get(fields))
+            .and_then(|field_values| field_values.get(values))
+            .map(|locations| locations.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
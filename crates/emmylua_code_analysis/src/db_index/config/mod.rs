@@ -1,13 +1,36 @@
+mod config_table_cover_enum;
+mod config_table_data_occurrence;
+mod config_table_graph;
 mod config_table_index_keys;
 mod config_table_pk_occurrence;
+mod config_table_pk_value_index;
+mod config_table_row;
+mod config_table_unique_occurrence;
+mod config_table_unique_value_index;
+mod test;
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
+pub use config_table_cover_enum::{ConfigTableCoverEnumRule, ConfigTableFieldValueOccurrence};
+pub use config_table_data_occurrence::ConfigTableDataOccurrence;
+pub use config_table_graph::{
+    ConfigTableEdgeOccurrence, ConfigTableGraphRules, ConfigTableTreeRule,
+};
 pub use config_table_index_keys::ConfigTableIndexKeys;
 pub use config_table_pk_occurrence::ConfigTablePkOccurrence;
+pub use config_table_pk_value_index::{ConfigTablePkLocation, ConfigTablePkValueIndex};
+pub use config_table_row::{ConfigEntry, ConfigTableRow, ConfigValue, ConfigValueNode};
+pub use config_table_unique_occurrence::ConfigTableUniqueOccurrence;
+pub use config_table_unique_value_index::ConfigTableUniqueValueIndex;
+
+use rowan::TextSize;
 
 use crate::{
-    FileId, LuaTypeDeclId, db_index::traits::LuaIndex, semantic::attributes::ConfigTableMode,
+    FileId, LuaMemberKey, LuaType, LuaTypeDeclId, db_index::traits::LuaIndex,
+    semantic::attributes::ConfigTableMode,
 };
 
 #[derive(Debug)]
@@ -16,6 +39,21 @@ pub struct LuaConfigIndex {
     config_table_modes: HashMap<LuaTypeDeclId, ConfigTableMode>,
     in_file_types: HashMap<FileId, HashSet<LuaTypeDeclId>>,
     config_table_pk_occurrences: HashMap<FileId, Vec<ConfigTablePkOccurrence>>,
+    pk_value_index: ConfigTablePkValueIndex,
+    config_table_uniques: HashMap<LuaTypeDeclId, Vec<Arc<[LuaMemberKey]>>>,
+    config_table_unique_occurrences: HashMap<FileId, Vec<ConfigTableUniqueOccurrence>>,
+    unique_value_index: ConfigTableUniqueValueIndex,
+    config_table_graph_rules: HashMap<LuaTypeDeclId, ConfigTableGraphRules>,
+    config_table_edge_occurrences: HashMap<FileId, Vec<ConfigTableEdgeOccurrence>>,
+    config_table_cover_enums: HashMap<LuaTypeDeclId, Vec<ConfigTableCoverEnumRule>>,
+    config_table_field_value_occurrences: HashMap<FileId, Vec<ConfigTableFieldValueOccurrence>>,
+    config_table_data_occurrences: HashMap<FileId, Vec<ConfigTableDataOccurrence>>,
+    config_table_rows: HashMap<FileId, Vec<ConfigTableRow>>,
+    // file -> 数据引用的配置表
+    config_table_ref_tables: HashMap<FileId, HashSet<LuaTypeDeclId>>,
+    // table -> 数据引用了该配置表的文件
+    config_table_dependents: HashMap<LuaTypeDeclId, HashSet<FileId>>,
+    data_mode_files: HashSet<FileId>,
 }
 
 impl Default for LuaConfigIndex {
@@ -31,6 +69,19 @@ impl LuaConfigIndex {
             config_table_modes: HashMap::new(),
             in_file_types: HashMap::new(),
             config_table_pk_occurrences: HashMap::new(),
+            pk_value_index: ConfigTablePkValueIndex::default(),
+            config_table_uniques: HashMap::new(),
+            config_table_unique_occurrences: HashMap::new(),
+            unique_value_index: ConfigTableUniqueValueIndex::default(),
+            config_table_graph_rules: HashMap::new(),
+            config_table_edge_occurrences: HashMap::new(),
+            config_table_cover_enums: HashMap::new(),
+            config_table_field_value_occurrences: HashMap::new(),
+            config_table_data_occurrences: HashMap::new(),
+            config_table_rows: HashMap::new(),
+            config_table_ref_tables: HashMap::new(),
+            config_table_dependents: HashMap::new(),
+            data_mode_files: HashSet::new(),
         }
     }
 
@@ -78,6 +129,67 @@ impl LuaConfigIndex {
         self.config_table_modes.contains_key(id)
     }
 
+    /// 添加 ConfigTable 的唯一性约束缓存
+    pub fn add_config_table_uniques(
+        &mut self,
+        file_id: FileId,
+        id: LuaTypeDeclId,
+        uniques: Vec<Arc<[LuaMemberKey]>>,
+    ) {
+        self.config_table_uniques.insert(id.clone(), uniques);
+        self.in_file_types.entry(file_id).or_default().insert(id);
+    }
+
+    /// 获取 ConfigTable 的唯一性约束
+    pub fn get_config_table_uniques(&self, id: &LuaTypeDeclId) -> Option<&[Arc<[LuaMemberKey]>]> {
+        self.config_table_uniques.get(id).map(|v| v.as_slice())
+    }
+
+    /// 添加 ConfigTable 的引用关系约束缓存
+    pub fn add_config_table_graph_rules(
+        &mut self,
+        file_id: FileId,
+        id: LuaTypeDeclId,
+        rules: ConfigTableGraphRules,
+    ) {
+        self.config_table_graph_rules.insert(id.clone(), rules);
+        self.in_file_types.entry(file_id).or_default().insert(id);
+    }
+
+    /// 获取 ConfigTable 的引用关系约束
+    pub fn get_config_table_graph_rules(
+        &self,
+        id: &LuaTypeDeclId,
+    ) -> Option<&ConfigTableGraphRules> {
+        self.config_table_graph_rules.get(id)
+    }
+
+    /// 遍历所有 ConfigTable 的引用关系约束
+    pub fn iter_config_table_graph_rules(
+        &self,
+    ) -> impl Iterator<Item = (&LuaTypeDeclId, &ConfigTableGraphRules)> {
+        self.config_table_graph_rules.iter()
+    }
+
+    /// 添加 ConfigTable 的枚举覆盖约束缓存
+    pub fn add_config_table_cover_enums(
+        &mut self,
+        file_id: FileId,
+        id: LuaTypeDeclId,
+        rules: Vec<ConfigTableCoverEnumRule>,
+    ) {
+        self.config_table_cover_enums.insert(id.clone(), rules);
+        self.in_file_types.entry(file_id).or_default().insert(id);
+    }
+
+    /// 获取 ConfigTable 的枚举覆盖约束
+    pub fn get_config_table_cover_enums(
+        &self,
+        id: &LuaTypeDeclId,
+    ) -> Option<&[ConfigTableCoverEnumRule]> {
+        self.config_table_cover_enums.get(id).map(|v| v.as_slice())
+    }
+
     pub fn get_config_table_pk_occurrences(
         &self,
         file_id: &FileId,
@@ -93,26 +205,271 @@ impl LuaConfigIndex {
             .flat_map(|v| v.iter())
     }
 
+    /// 按文件遍历所有主键出现位置
+    pub fn iter_file_config_table_pk_occurrences(
+        &self,
+    ) -> impl Iterator<Item = (&FileId, &Vec<ConfigTablePkOccurrence>)> {
+        self.config_table_pk_occurrences.iter()
+    }
+
+    /// 所有配置表的主键值索引
+    pub fn get_pk_value_index(&self) -> &ConfigTablePkValueIndex {
+        &self.pk_value_index
+    }
+
+    /// 取出自上次调用以来主键值集合发生变化的配置表
+    pub fn take_changed_config_tables(&mut self) -> Vec<LuaTypeDeclId> {
+        self.pk_value_index.take_changed_tables()
+    }
+
     pub fn add_config_table_pk_occurrences(
         &mut self,
         file_id: FileId,
         occurrences: Vec<ConfigTablePkOccurrence>,
     ) {
-        self.config_table_pk_occurrences.remove(&file_id);
+        self.remove_config_table_pk_occurrences(file_id);
         if !occurrences.is_empty() {
+            self.pk_value_index.add_occurrences(file_id, &occurrences);
             self.config_table_pk_occurrences
                 .insert(file_id, occurrences);
         }
     }
+
+    fn remove_config_table_pk_occurrences(&mut self, file_id: FileId) {
+        if let Some(occurrences) = self.config_table_pk_occurrences.remove(&file_id) {
+            self.pk_value_index
+                .remove_occurrences(file_id, &occurrences);
+        }
+    }
+
+    pub fn get_config_table_unique_occurrences(
+        &self,
+        file_id: &FileId,
+    ) -> Option<&Vec<ConfigTableUniqueOccurrence>> {
+        self.config_table_unique_occurrences.get(file_id)
+    }
+
+    /// 所有配置表的唯一性约束字段值索引
+    pub fn get_unique_value_index(&self) -> &ConfigTableUniqueValueIndex {
+        &self.unique_value_index
+    }
+
+    pub fn add_config_table_unique_occurrences(
+        &mut self,
+        file_id: FileId,
+        occurrences: Vec<ConfigTableUniqueOccurrence>,
+    ) {
+        self.remove_config_table_unique_occurrences(file_id);
+        if !occurrences.is_empty() {
+            self.unique_value_index
+                .add_occurrences(file_id, &occurrences);
+            self.config_table_unique_occurrences
+                .insert(file_id, occurrences);
+        }
+    }
+
+    fn remove_config_table_unique_occurrences(&mut self, file_id: FileId) {
+        if let Some(occurrences) = self.config_table_unique_occurrences.remove(&file_id) {
+            self.unique_value_index
+                .remove_occurrences(file_id, &occurrences);
+        }
+    }
+
+    pub fn get_config_table_edge_occurrences(
+        &self,
+        file_id: &FileId,
+    ) -> Option<&Vec<ConfigTableEdgeOccurrence>> {
+        self.config_table_edge_occurrences.get(file_id)
+    }
+
+    /// 按文件遍历所有记录间引用关系
+    pub fn iter_file_config_table_edge_occurrences(
+        &self,
+    ) -> impl Iterator<Item = (&FileId, &Vec<ConfigTableEdgeOccurrence>)> {
+        self.config_table_edge_occurrences.iter()
+    }
+
+    pub fn add_config_table_edge_occurrences(
+        &mut self,
+        file_id: FileId,
+        occurrences: Vec<ConfigTableEdgeOccurrence>,
+    ) {
+        self.config_table_edge_occurrences.remove(&file_id);
+        if !occurrences.is_empty() {
+            self.config_table_edge_occurrences
+                .insert(file_id, occurrences);
+        }
+    }
+
+    /// 按文件遍历所有被枚举覆盖约束的字段值
+    pub fn iter_file_config_table_field_value_occurrences(
+        &self,
+    ) -> impl Iterator<Item = (&FileId, &Vec<ConfigTableFieldValueOccurrence>)> {
+        self.config_table_field_value_occurrences.iter()
+    }
+
+    pub fn add_config_table_field_value_occurrences(
+        &mut self,
+        file_id: FileId,
+        occurrences: Vec<ConfigTableFieldValueOccurrence>,
+    ) {
+        self.config_table_field_value_occurrences.remove(&file_id);
+        if !occurrences.is_empty() {
+            self.config_table_field_value_occurrences
+                .insert(file_id, occurrences);
+        }
+    }
+
+    pub fn get_config_table_data_occurrences(
+        &self,
+        file_id: &FileId,
+    ) -> Option<&Vec<ConfigTableDataOccurrence>> {
+        self.config_table_data_occurrences.get(file_id)
+    }
+
+    /// 按文件遍历所有配置表数据的位置
+    pub fn iter_file_config_table_data_occurrences(
+        &self,
+    ) -> impl Iterator<Item = (&FileId, &Vec<ConfigTableDataOccurrence>)> {
+        self.config_table_data_occurrences.iter()
+    }
+
+    pub fn add_config_table_data_occurrences(
+        &mut self,
+        file_id: FileId,
+        occurrences: Vec<ConfigTableDataOccurrence>,
+    ) {
+        self.config_table_data_occurrences.remove(&file_id);
+        if !occurrences.is_empty() {
+            self.config_table_data_occurrences
+                .insert(file_id, occurrences);
+        }
+    }
+
+    /// 文件中所有配置表的记录, 按位置排序
+    pub fn get_config_table_rows(&self, file_id: &FileId) -> Option<&Vec<ConfigTableRow>> {
+        self.config_table_rows.get(file_id)
+    }
+
+    /// 遍历配置表在所有文件中的记录
+    pub fn iter_config_table_rows(
+        &self,
+        config_table: &LuaTypeDeclId,
+    ) -> impl Iterator<Item = (FileId, &ConfigTableRow)> {
+        let config_table = config_table.clone();
+        self.config_table_rows
+            .iter()
+            .flat_map(move |(file_id, rows)| {
+                let config_table = config_table.clone();
+                rows.iter()
+                    .filter(move |row| row.config_table == config_table)
+                    .map(move |row| (*file_id, row))
+            })
+    }
+
+    /// 查找包含指定位置的记录
+    pub fn find_config_table_row_at(
+        &self,
+        file_id: &FileId,
+        offset: TextSize,
+    ) -> Option<&ConfigTableRow> {
+        let rows = self.config_table_rows.get(file_id)?;
+        let idx = rows.partition_point(|row| row.range.start() <= offset);
+        let row = rows.get(idx.checked_sub(1)?)?;
+        row.contains(offset).then_some(row)
+    }
+
+    /// 按主键值查找记录, 主键值重复时返回其中一条
+    pub fn find_config_table_row(
+        &self,
+        config_table: &LuaTypeDeclId,
+        key: &LuaMemberKey,
+        value: &LuaType,
+    ) -> Option<(FileId, &ConfigTableRow)> {
+        self.pk_value_index
+            .get_locations(config_table, key, value)
+            .iter()
+            .find_map(|location| {
+                let row =
+                    self.find_config_table_row_at(&location.file_id, location.range.start())?;
+                (row.config_table == *config_table).then_some((location.file_id, row))
+            })
+    }
+
+    /// 获取数据中的 `v.ref`/`v.union_ref` 引用了指定配置表的文件
+    pub fn get_config_table_dependents(&self, id: &LuaTypeDeclId) -> Option<&HashSet<FileId>> {
+        self.config_table_dependents.get(id)
+    }
+
+    /// 记录文件数据中的 `v.ref`/`v.union_ref` 引用的配置表
+    pub fn add_config_table_ref_tables(&mut self, file_id: FileId, tables: HashSet<LuaTypeDeclId>) {
+        self.remove_config_table_ref_tables(file_id);
+        if tables.is_empty() {
+            return;
+        }
+        for table in &tables {
+            self.config_table_dependents
+                .entry(table.clone())
+                .or_default()
+                .insert(file_id);
+        }
+        self.config_table_ref_tables.insert(file_id, tables);
+    }
+
+    fn remove_config_table_ref_tables(&mut self, file_id: FileId) {
+        let Some(tables) = self.config_table_ref_tables.remove(&file_id) else {
+            return;
+        };
+        for table in tables {
+            if let Some(files) = self.config_table_dependents.get_mut(&table) {
+                files.remove(&file_id);
+                if files.is_empty() {
+                    self.config_table_dependents.remove(&table);
+                }
+            }
+        }
+    }
+
+    /// 标记文件是否以数据模式分析
+    pub fn set_data_mode_file(&mut self, file_id: FileId, data_mode: bool) {
+        if data_mode {
+            self.data_mode_files.insert(file_id);
+        } else {
+            self.data_mode_files.remove(&file_id);
+        }
+    }
+
+    /// 数据模式的文件跳过流分析与闭包分析, 只运行类型结构与配置表诊断
+    pub fn is_data_mode_file(&self, file_id: &FileId) -> bool {
+        self.data_mode_files.contains(file_id)
+    }
+
+    pub fn add_config_table_rows(&mut self, file_id: FileId, mut rows: Vec<ConfigTableRow>) {
+        self.config_table_rows.remove(&file_id);
+        if !rows.is_empty() {
+            rows.sort_by_key(|row| row.range.start());
+            self.config_table_rows.insert(file_id, rows);
+        }
+    }
 }
 
 impl LuaIndex for LuaConfigIndex {
     fn remove(&mut self, file_id: FileId) {
-        self.config_table_pk_occurrences.remove(&file_id);
+        self.remove_config_table_pk_occurrences(file_id);
+        self.remove_config_table_unique_occurrences(file_id);
+        self.config_table_edge_occurrences.remove(&file_id);
+        self.config_table_field_value_occurrences.remove(&file_id);
+        self.config_table_data_occurrences.remove(&file_id);
+        self.config_table_rows.remove(&file_id);
+        self.remove_config_table_ref_tables(file_id);
+        self.data_mode_files.remove(&file_id);
         if let Some(type_ids) = self.in_file_types.remove(&file_id) {
             for type_id in type_ids {
                 self.config_table_keys.remove(&type_id);
                 self.config_table_modes.remove(&type_id);
+                self.config_table_uniques.remove(&type_id);
+                self.config_table_graph_rules.remove(&type_id);
+                self.config_table_cover_enums.remove(&type_id);
             }
         }
     }
@@ -122,5 +479,18 @@ impl LuaIndex for LuaConfigIndex {
         self.config_table_modes.clear();
         self.in_file_types.clear();
         self.config_table_pk_occurrences.clear();
+        self.pk_value_index.clear();
+        self.config_table_uniques.clear();
+        self.config_table_unique_occurrences.clear();
+        self.unique_value_index.clear();
+        self.config_table_graph_rules.clear();
+        self.config_table_edge_occurrences.clear();
+        self.config_table_cover_enums.clear();
+        self.config_table_field_value_occurrences.clear();
+        self.config_table_data_occurrences.clear();
+        self.config_table_rows.clear();
+        self.config_table_ref_tables.clear();
+        self.config_table_dependents.clear();
+        self.data_mode_files.clear();
     }
 }
@@ -0,0 +1,289 @@
+#[cfg(test)]
+mod tests {
+    use crate::{ConfigValue, LuaMemberKey, LuaType, LuaTypeDeclId, VirtualWorkspace};
+
+    fn def_schema(ws: &mut VirtualWorkspace) {
+        ws.def(
+            r#"
+            ---@class Reward: Bean
+            ---@field itemId int
+            ---@field count int
+
+            ---@class Item: Bean
+            ---@field id int
+            ---@field name string
+            ---@field pos vector2
+            ---@field reward Reward
+            ---@field tags list<string>
+            ---@field rewards list<Reward>
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+    }
+
+    fn name(key: &str) -> LuaMemberKey {
+        LuaMemberKey::Name(key.into())
+    }
+
+    #[test]
+    fn test_row_values() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        def_schema(&mut ws);
+        let file_id = ws.def_file(
+            "item.lua",
+            r#"
+            ---@type TbItem
+            local items = {
+                [1] = {
+                    id = 1,
+                    name = "sword",
+                    pos = { 1, 2.5 },
+                    reward = { itemId = 2, count = 3 },
+                    tags = { "a", "b" },
+                    rewards = { { itemId = 4, count = 5 } },
+                },
+            }
+            "#,
+        );
+
+        let config_index = ws.analysis.compilation.get_db().get_config_index();
+        let rows = config_index.get_config_table_rows(&file_id).unwrap();
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(row.config_table, LuaTypeDeclId::new("TbItem"));
+        assert_eq!(row.key, LuaMemberKey::Integer(1));
+        assert_eq!(
+            row.value.value.get_bean(),
+            Some(&LuaTypeDeclId::new("Item"))
+        );
+        assert_eq!(
+            row.get_field(&name("id")).unwrap().value.as_integer(),
+            Some(1)
+        );
+        assert_eq!(
+            row.get_field(&name("name")).unwrap().value.as_str(),
+            Some("sword")
+        );
+
+        let pos = &row.get_field(&name("pos")).unwrap().value;
+        assert_eq!(pos.get_bean(), Some(&LuaTypeDeclId::new("vector2")));
+        assert_eq!(pos.get(&name("y")).unwrap().value, ConfigValue::Float(2.5));
+
+        let reward = &row.get_field(&name("reward")).unwrap().value;
+        assert_eq!(reward.get_bean(), Some(&LuaTypeDeclId::new("Reward")));
+        assert_eq!(
+            reward.get(&name("count")).unwrap().value.as_integer(),
+            Some(3)
+        );
+
+        let tags = &row.get_field(&name("tags")).unwrap().value;
+        assert_eq!(tags.get_bean(), None);
+        assert_eq!(tags.get_entries().len(), 2);
+        assert!(
+            tags.get_entries()
+                .iter()
+                .all(|entry| entry.key_range.is_none())
+        );
+        assert_eq!(
+            tags.get(&LuaMemberKey::Integer(2)).unwrap().value.as_str(),
+            Some("b")
+        );
+
+        let rewards = &row.get_field(&name("rewards")).unwrap().value;
+        let first = &rewards.get(&LuaMemberKey::Integer(1)).unwrap().value;
+        assert_eq!(first.get_bean(), Some(&LuaTypeDeclId::new("Reward")));
+        assert_eq!(
+            first.get(&name("itemId")).unwrap().value.as_integer(),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn test_non_constant_value() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        def_schema(&mut ws);
+        let file_id = ws.def_file(
+            "item.lua",
+            r#"
+            local function make_name() return tostring(1) end
+            ---@type TbItem
+            local items = {
+                { id = 1, name = make_name() },
+            }
+            "#,
+        );
+
+        let config_index = ws.analysis.compilation.get_db().get_config_index();
+        let row = &config_index.get_config_table_rows(&file_id).unwrap()[0];
+        assert_eq!(row.key, LuaMemberKey::Integer(1));
+        assert_eq!(
+            row.get_field(&name("name")).unwrap().value,
+            ConfigValue::Unknown
+        );
+    }
+
+    #[test]
+    fn test_find_row() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        def_schema(&mut ws);
+        ws.def_file(
+            "item_a.lua",
+            r#"
+            ---@type TbItem
+            local items = { [1] = { id = 1, name = "a" } }
+            "#,
+        );
+        let file_b = ws.def_file(
+            "item_b.lua",
+            r#"
+            ---@type TbItem
+            local items = { [2] = { id = 2, name = "b" } }
+            "#,
+        );
+
+        let table = LuaTypeDeclId::new("TbItem");
+        let config_index = ws.analysis.compilation.get_db().get_config_index();
+        assert_eq!(config_index.iter_config_table_rows(&table).count(), 2);
+        let (file_id, row) = config_index
+            .find_config_table_row(&table, &name("id"), &LuaType::IntegerConst(2))
+            .unwrap();
+        assert_eq!(file_id, file_b);
+        assert_eq!(
+            row.get_field(&name("name")).unwrap().value.as_str(),
+            Some("b")
+        );
+        assert_eq!(
+            config_index.find_config_table_row_at(&file_id, row.value.range.start()),
+            Some(row)
+        );
+
+        let uri = ws.virtual_url_generator.new_uri("item_b.lua");
+        ws.analysis.remove_file_by_uri(&uri);
+        let config_index = ws.analysis.compilation.get_db().get_config_index();
+        assert_eq!(config_index.iter_config_table_rows(&table).count(), 1);
+        assert!(
+            config_index
+                .find_config_table_row(&table, &name("id"), &LuaType::IntegerConst(2))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_config_table_dependents() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        def_schema(&mut ws);
+        ws.def(
+            r#"
+            ---@class Goods: Bean
+            ---@field id int
+            ---@[v.ref("TbItem")]
+            ---@field itemId int
+
+            ---@class TbShop: ConfigTable
+            ---@field [int] Goods
+            "#,
+        );
+        let item_data = |name: &str| {
+            format!(
+                r#"
+                ---@type TbItem
+                local items = {{ [1] = {{ id = 1, name = "{name}" }}, [2] = {{ id = 2 }} }}
+                "#
+            )
+        };
+        ws.def_file("item.lua", &item_data("a"));
+        let shop = ws.def_file(
+            "shop.lua",
+            r#"
+            ---@type TbShop
+            local shop = { [1] = { id = 1, itemId = 2 } }
+            "#,
+        );
+        ws.analysis.take_config_table_dependents();
+
+        // 非主键字段变化时不影响引用方
+        ws.def_file("item.lua", &item_data("b"));
+        assert!(ws.analysis.take_config_table_dependents().is_empty());
+
+        ws.def_file(
+            "item.lua",
+            r#"
+            ---@type TbItem
+            local items = { [1] = { id = 1 } }
+            "#,
+        );
+        assert_eq!(ws.analysis.take_config_table_dependents(), vec![shop]);
+        assert!(ws.analysis.take_config_table_dependents().is_empty());
+
+        // 没有数据引用 TbShop, 其主键变化不需要重新诊断其他文件
+        ws.def_file(
+            "shop.lua",
+            r#"
+            ---@type TbShop
+            local shop = { [2] = { id = 2, itemId = 1 } }
+            "#,
+        );
+        assert!(ws.analysis.take_config_table_dependents().is_empty());
+
+        let uri = ws.virtual_url_generator.new_uri("item.lua");
+        ws.analysis.remove_file_by_uri(&uri);
+        assert_eq!(ws.analysis.take_config_table_dependents(), vec![shop]);
+
+        // 移除引用方后不再记录为依赖
+        let uri = ws.virtual_url_generator.new_uri("shop.lua");
+        ws.analysis.remove_file_by_uri(&uri);
+        let config_index = ws.analysis.compilation.get_db().get_config_index();
+        assert!(
+            config_index
+                .get_config_table_dependents(&LuaTypeDeclId::new("TbItem"))
+                .is_none()
+        );
+        ws.def_file("item.lua", &item_data("a"));
+        assert!(ws.analysis.take_config_table_dependents().is_empty());
+    }
+
+    #[test]
+    fn test_config_table_cover_enum_dependents() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let decl = ws.def_file(
+            "quality.lua",
+            r#"
+            ---@enum Quality
+            Quality = {
+                Common = 1,
+                Rare = 2,
+            }
+
+            ---@class QualityColor: Bean
+            ---@field quality int
+            ---@field color string
+
+            ---@[t.cover_enum("quality", "Quality")]
+            ---@class TbQualityColor: ConfigTable
+            ---@field [int] QualityColor
+            "#,
+        );
+        ws.def_file(
+            "quality_color.lua",
+            r#"
+            ---@type TbQualityColor
+            local colors = { [1] = { quality = 1, color = "white" } }
+            "#,
+        );
+        assert_eq!(ws.analysis.take_config_table_dependents(), vec![decl]);
+
+        ws.def_file(
+            "quality_color.lua",
+            r#"
+            ---@type TbQualityColor
+            local colors = {
+                [1] = { quality = 1, color = "white" },
+                [2] = { quality = 2, color = "blue" },
+            }
+            "#,
+        );
+        assert_eq!(ws.analysis.take_config_table_dependents(), vec![decl]);
+    }
+}
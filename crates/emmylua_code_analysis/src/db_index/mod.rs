@@ -15,7 +15,10 @@ mod signature;
 mod traits;
 mod r#type;
 
-use std::sync::Arc;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
 
 use crate::{Emmyrc, FileId, Vfs};
 pub use config::*;
@@ -53,6 +56,14 @@ pub struct DbIndex {
     metatable_index: LuaMetatableIndex,
     global_index: LuaGlobalIndex,
     emmyrc: Arc<Emmyrc>,
+    generation: u64,
+}
+
+/// 分配在所有 `DbIndex` 之间唯一的代数
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+fn next_generation() -> u64 {
+    NEXT_GENERATION.fetch_add(1, Ordering::Relaxed)
 }
 
 #[allow(unused)]
@@ -81,9 +92,19 @@ impl DbIndex {
             metatable_index: LuaMetatableIndex::new(),
             global_index: LuaGlobalIndex::new(),
             emmyrc: Arc::new(Emmyrc::default()),
+            generation: next_generation(),
         }
     }
 
+    /// 索引的代数, 索引每次变化后更新. 不同 `DbIndex` 的代数也不相同, 可以作为缓存的键
+    pub fn get_generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn update_generation(&mut self) {
+        self.generation = next_generation();
+    }
+
     pub fn remove_index(&mut self, file_ids: Vec<FileId>) {
         for file_id in file_ids {
             self.remove(file_id);
@@ -211,6 +232,7 @@ impl DbIndex {
     }
 
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
+        self.update_generation();
         self.vfs.update_config(config.clone());
         self.modules_index.update_config(config.clone());
         self.emmyrc = config;
@@ -237,6 +259,7 @@ impl LuaIndex for DbIndex {
         self.file_dependencies_index.remove(file_id);
         self.metatable_index.remove(file_id);
         self.global_index.remove(file_id);
+        self.update_generation();
     }
 
     fn clear(&mut self) {
@@ -254,5 +277,6 @@ impl LuaIndex for DbIndex {
         self.file_dependencies_index.clear();
         self.metatable_index.clear();
         self.global_index.clear();
+        self.update_generation();
     }
 }
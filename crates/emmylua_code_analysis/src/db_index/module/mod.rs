@@ -503,6 +503,14 @@ impl LuaModuleIndex {
 
         None
     }
+
+    /// 路径相对于所在工作区根目录的部分, 不在任何工作区中时返回 `None`
+    pub fn get_workspace_relative_path<'a>(&self, path: &'a Path) -> Option<&'a Path> {
+        self.workspaces
+            .iter()
+            .filter_map(|workspace| path.strip_prefix(&workspace.root).ok())
+            .min_by_key(|relative_path| relative_path.as_os_str().len())
+    }
 }
 
 impl LuaIndex for LuaModuleIndex {
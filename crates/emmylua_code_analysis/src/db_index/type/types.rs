@@ -933,12 +933,11 @@ impl LuaObjectType {
         }
 
         let mut ty = LuaType::Unknown;
-        let mut count = 1;
         let mut fields = self.fields.iter().collect::<Vec<_>>();
 
-        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+        fields.sort_by_key(|(key, _)| *key);
 
-        for (key, value_type) in fields {
+        for (count, (key, value_type)) in (1..).zip(fields) {
             let idx = match key {
                 LuaMemberKey::Integer(i) => i,
                 _ => {
@@ -950,8 +949,6 @@ impl LuaObjectType {
                 return None;
             }
 
-            count += 1;
-
             ty = TypeOps::Union.apply(db, &ty, value_type);
         }
 
@@ -10,6 +10,9 @@ use crate::{
     DiagnosticCode, LuaDeclExtra, LuaDeclId, LuaMemberKey, LuaSemanticDeclId, LuaType,
     SemanticDeclLevel, SemanticModel, TypeCheckFailReason, TypeCheckResult, VariadicType,
     infer_index_expr,
+    semantic::shared::luaconfig::{
+        BEAN, find_vector_type, get_vector_component_key, get_vector_components, is_enum_name_data,
+    },
 };
 
 use super::{Checker, DiagnosticContext, humanize_lint_type};
@@ -263,6 +266,16 @@ fn check_table_expr_content(
         let Some(member_key) = semantic_model.get_member_key(&field_key) else {
             continue;
         };
+        // 内置向量类型按分量顺序书写的值
+        let member_key = match find_vector_type(semantic_model.get_db(), table_type)
+            .and_then(|vector_id| get_vector_components(semantic_model.get_db(), &vector_id))
+        {
+            Some(components) => match get_vector_component_key(components, &member_key) {
+                Some(component_key) => component_key,
+                None => continue,
+            },
+            None => member_key,
+        };
 
         let source_type = match semantic_model.infer_member_type(table_type, &member_key) {
             Ok(typ) => typ,
@@ -271,6 +284,13 @@ fn check_table_expr_content(
             }
         };
 
+        // Bean 数据中的枚举可以按名称书写, 名称由 `unknown-enum-name` 检查
+        if is_bean_type(semantic_model, table_type)
+            && is_enum_name_data(semantic_model.get_db(), &source_type, &expr_type)
+        {
+            continue;
+        }
+
         if (source_type.is_table() || source_type.is_custom_type())
             && let Some(table_expr) = LuaTableExpr::cast(value_expr.syntax().clone())
         {
@@ -300,6 +320,10 @@ fn check_table_expr_content(
     Some(has_diagnostic)
 }
 
+fn is_bean_type(semantic_model: &SemanticModel, ty: &LuaType) -> bool {
+    matches!(ty.strip_attributed(), LuaType::Ref(id) | LuaType::Def(id) if BEAN.is_bean(semantic_model.get_db(), id))
+}
+
 fn check_table_last_variadic_type(
     context: &mut DiagnosticContext,
     semantic_model: &SemanticModel,
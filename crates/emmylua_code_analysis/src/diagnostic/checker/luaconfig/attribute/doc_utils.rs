@@ -0,0 +1,91 @@
+use emmylua_parser::{
+    LuaAstNode, LuaComment, LuaDocTag, LuaDocTagAttributeUse, LuaDocTagField, LuaDocType, LuaKind,
+    LuaLiteralToken, LuaSyntaxKind, NumberResult,
+};
+
+use crate::{LuaTypeDeclId, SemanticModel};
+
+/// 与分析器一致: 特性标注作用于其后第一个 `---@field`
+pub fn find_attached_field(tag_use: &LuaDocTagAttributeUse) -> Option<LuaDocTagField> {
+    if tag_use.syntax().parent()?.kind() != LuaSyntaxKind::Comment.into() {
+        return None;
+    }
+
+    let mut next_sibling = tag_use.syntax().next_sibling_or_token();
+    while let Some(sibling) = next_sibling {
+        match sibling.kind() {
+            LuaKind::Syntax(LuaSyntaxKind::DocTagField) => {
+                return LuaDocTagField::cast(sibling.into_node()?);
+            }
+            LuaKind::Syntax(
+                LuaSyntaxKind::DocTagClass
+                | LuaSyntaxKind::DocTagParam
+                | LuaSyntaxKind::DocTagReturn
+                | LuaSyntaxKind::DocTagEnum
+                | LuaSyntaxKind::Comment,
+            ) => return None,
+            _ => {}
+        }
+        next_sibling = sibling.next_sibling_or_token();
+    }
+    None
+}
+
+/// 特性所在注释中声明的 Bean
+pub fn find_attached_class(
+    semantic_model: &SemanticModel,
+    tag_use: &LuaDocTagAttributeUse,
+) -> Option<LuaTypeDeclId> {
+    let comment = tag_use.get_parent::<LuaComment>()?;
+    let class = comment.get_doc_tags().find_map(|tag| match tag {
+        LuaDocTag::Class(class) => Some(class),
+        _ => None,
+    })?;
+    let name = class.get_name_token()?;
+    let decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .find_type_decl(semantic_model.get_file_id(), name.get_name_text())?;
+    Some(decl.get_id())
+}
+
+pub fn doc_type_string_literal(ty: &LuaDocType) -> Option<String> {
+    let LuaDocType::Literal(literal) = ty else {
+        return None;
+    };
+
+    match literal.get_literal()? {
+        LuaLiteralToken::String(token) => Some(token.get_value()),
+        _ => None,
+    }
+}
+
+pub fn doc_type_number_literal(ty: &LuaDocType) -> Option<f64> {
+    let LuaDocType::Literal(literal) = ty else {
+        return None;
+    };
+
+    match literal.get_literal()? {
+        LuaLiteralToken::Number(token) => match token.get_number_value() {
+            NumberResult::Int(i) => Some(i as f64),
+            NumberResult::Uint(u) => Some(u as f64),
+            NumberResult::Float(f) => Some(f),
+        },
+        _ => None,
+    }
+}
+
+pub fn doc_type_integer_literal(ty: &LuaDocType) -> Option<i64> {
+    let LuaDocType::Literal(literal) = ty else {
+        return None;
+    };
+
+    match literal.get_literal()? {
+        LuaLiteralToken::Number(token) => match token.get_number_value() {
+            NumberResult::Int(i) => Some(i),
+            NumberResult::Uint(u) => i64::try_from(u).ok(),
+            NumberResult::Float(_) => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,129 @@
+use emmylua_parser::{LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse};
+
+use crate::{
+    DbIndex, DiagnosticCode, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaTypeDeclId,
+    SemanticModel,
+    attributes::{TTreeAttribute, VAcyclicAttribute},
+    diagnostic::checker::{
+        Checker, DiagnosticContext,
+        luaconfig::attribute::doc_utils::{find_attached_class, find_attached_field},
+    },
+    semantic::shared::luaconfig::CONFIG_TABLE,
+};
+
+/// 检查 `t.tree` 的父字段存在, 且 `t.tree` / `v.acyclic` 作用于单主键的配置表
+pub struct GraphSignatureChecker;
+
+impl Checker for GraphSignatureChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::InvalidGraphSignature];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+
+        for tag_use in root.descendants::<LuaDocTagAttributeUse>() {
+            for attribute_use in tag_use.get_attribute_uses() {
+                let Some(name) = get_graph_attribute_name(&attribute_use) else {
+                    continue;
+                };
+
+                let result = if name == TTreeAttribute::NAME {
+                    validate_tree_signature(semantic_model, &tag_use)
+                } else {
+                    validate_acyclic_signature(semantic_model, &tag_use)
+                };
+                if let Err(reason) = result {
+                    context.add_diagnostic(
+                        DiagnosticCode::InvalidGraphSignature,
+                        attribute_use.get_range(),
+                        t!("Invalid %{name}: %{reason}", name = name, reason = reason).to_string(),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn get_graph_attribute_name(attribute_use: &LuaDocAttributeUse) -> Option<&'static str> {
+    let name_token = attribute_use.get_type()?.get_name_token()?;
+    [TTreeAttribute::NAME, VAcyclicAttribute::NAME]
+        .into_iter()
+        .find(|name| *name == name_token.get_name_text())
+}
+
+fn validate_tree_signature(
+    semantic_model: &SemanticModel,
+    tag_use: &LuaDocTagAttributeUse,
+) -> Result<(), String> {
+    let db = semantic_model.get_db();
+    let Some(table_id) = find_attached_class(semantic_model, tag_use) else {
+        return Ok(());
+    };
+    let Some(bean_id) = CONFIG_TABLE.get_bean_id(db, &table_id) else {
+        return Ok(());
+    };
+
+    let parent = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(table_id.clone()))
+        .and_then(TTreeAttribute::find_in)
+        .and_then(|attr| attr.get_parent().map(str::to_string));
+    let Some(parent) = parent else {
+        return Err("parent must be a string literal".to_string());
+    };
+
+    // 与分析器一致, 父字段只在 Bean 自身声明的字段中查找
+    let key = LuaMemberKey::Name(parent.as_str().into());
+    let has_parent = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(bean_id.clone()))
+        .is_some_and(|members| members.iter().any(|m| m.get_key() == &key));
+    if !has_parent {
+        return Err(format!(
+            "`{}` has no field `{}`",
+            bean_id.get_name(),
+            parent
+        ));
+    }
+
+    check_single_key(db, &table_id)
+}
+
+fn validate_acyclic_signature(
+    semantic_model: &SemanticModel,
+    tag_use: &LuaDocTagAttributeUse,
+) -> Result<(), String> {
+    if find_attached_field(tag_use).is_none() {
+        return Err(format!(
+            "{} can only be applied to `---@field`",
+            VAcyclicAttribute::NAME
+        ));
+    }
+    let Some(bean_id) = find_attached_class(semantic_model, tag_use) else {
+        return Ok(());
+    };
+
+    // 使用该 Bean 的配置表都需要是单主键
+    let db = semantic_model.get_db();
+    let mut tables = db
+        .get_config_index()
+        .iter_config_table_graph_rules()
+        .filter(|(_, rules)| !rules.acyclic_fields.is_empty())
+        .map(|(table_id, _)| table_id)
+        .filter(|table_id| CONFIG_TABLE.get_bean_id(db, table_id).as_ref() == Some(&bean_id))
+        .collect::<Vec<_>>();
+    tables.sort_by_key(|table_id| table_id.get_name());
+    tables
+        .into_iter()
+        .try_for_each(|table_id| check_single_key(db, table_id))
+}
+
+fn check_single_key(db: &DbIndex, table_id: &LuaTypeDeclId) -> Result<(), String> {
+    match db.get_config_index().get_config_table_keys(table_id) {
+        Some(keys) if keys.keys().len() == 1 => Ok(()),
+        _ => Err(format!(
+            "`{}` must have exactly one index key",
+            table_id.get_name()
+        )),
+    }
+}
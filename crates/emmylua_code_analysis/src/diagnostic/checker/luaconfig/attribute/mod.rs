@@ -1,5 +1,11 @@
+mod doc_utils;
 pub mod flags_enum_value;
+pub mod graph_signature;
+pub mod vdefault_signature;
 pub mod vrange_signature;
 pub mod vref_signature;
 pub mod vset_signature;
 pub mod vsize_signature;
+pub mod vstring_signature;
+pub mod vunion_ref_signature;
+pub mod vweight_signature;
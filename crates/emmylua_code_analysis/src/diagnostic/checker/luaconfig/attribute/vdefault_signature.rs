@@ -0,0 +1,95 @@
+use emmylua_parser::{LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse};
+
+use crate::{
+    DiagnosticCode, DocTypeInferContext, LuaType, RenderLevel, SemanticModel,
+    attributes::VDefaultAttribute,
+    diagnostic::checker::{
+        Checker, DiagnosticContext, luaconfig::attribute::doc_utils::find_attached_field,
+    },
+    humanize_type, infer_doc_type,
+};
+
+/// 检查 `v.default` 作用于字段且默认值与字段类型匹配
+pub struct VDefaultSignatureChecker;
+
+impl Checker for VDefaultSignatureChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::InvalidDefaultSignature];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+
+        for tag_use in root.descendants::<LuaDocTagAttributeUse>() {
+            for attribute_use in tag_use.get_attribute_uses() {
+                if !is_vdefault_attribute_use(&attribute_use) {
+                    continue;
+                }
+
+                if let Err(reason) =
+                    validate_vdefault_signature(semantic_model, &tag_use, &attribute_use)
+                {
+                    context.add_diagnostic(
+                        DiagnosticCode::InvalidDefaultSignature,
+                        attribute_use.get_range(),
+                        t!("Invalid v.default: %{reason}", reason = reason).to_string(),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn is_vdefault_attribute_use(attribute_use: &LuaDocAttributeUse) -> bool {
+    attribute_use
+        .get_type()
+        .and_then(|ty| ty.get_name_token())
+        .is_some_and(|token| token.get_name_text() == VDefaultAttribute::NAME)
+}
+
+fn validate_vdefault_signature(
+    semantic_model: &SemanticModel,
+    tag_use: &LuaDocTagAttributeUse,
+    attribute_use: &LuaDocAttributeUse,
+) -> Result<(), String> {
+    let Some(field) = find_attached_field(tag_use) else {
+        return Err("v.default can only be applied to `---@field`".to_string());
+    };
+
+    let args = attribute_use
+        .get_arg_list()
+        .map(|l| l.get_args().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let [arg] = args.as_slice() else {
+        return Err("v.default expects exactly one parameter".to_string());
+    };
+
+    let db = semantic_model.get_db();
+    let doc_ctx = DocTypeInferContext::new(db, semantic_model.get_file_id());
+    let value = infer_doc_type(doc_ctx, arg);
+    if !matches!(
+        value,
+        LuaType::DocIntegerConst(_)
+            | LuaType::FloatConst(_)
+            | LuaType::DocStringConst(_)
+            | LuaType::DocBooleanConst(_)
+    ) {
+        return Err("default value must be a boolean, number or string literal".to_string());
+    }
+
+    let Some(field_type) = field.get_type() else {
+        return Ok(());
+    };
+    let field_type = infer_doc_type(doc_ctx, &field_type);
+    if semantic_model
+        .type_check(field_type.strip_attributed(), &value)
+        .is_err()
+    {
+        return Err(format!(
+            "default value `{}` does not match field type `{}`",
+            humanize_type(db, &value, RenderLevel::Simple),
+            humanize_type(db, &field_type, RenderLevel::Simple)
+        ));
+    }
+
+    Ok(())
+}
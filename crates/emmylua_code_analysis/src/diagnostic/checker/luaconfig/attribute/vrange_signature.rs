@@ -1,11 +1,12 @@
-use emmylua_parser::{
-    LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse, LuaDocType, LuaLiteralToken,
-};
+use emmylua_parser::{LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse};
 
 use crate::{
     DiagnosticCode, SemanticModel,
     attributes::{RangeSpec, parse_range_spec},
-    diagnostic::checker::{Checker, DiagnosticContext},
+    diagnostic::checker::{
+        Checker, DiagnosticContext,
+        luaconfig::attribute::doc_utils::{doc_type_number_literal, doc_type_string_literal},
+    },
 };
 
 pub struct VRangeSignatureChecker;
@@ -76,29 +77,3 @@ fn validate_vrange_spec(spec: &Result<RangeSpec, String>) -> Result<(), String>
         Err(err) => Err(err.clone()),
     }
 }
-
-fn doc_type_string_literal(ty: &LuaDocType) -> Option<String> {
-    let LuaDocType::Literal(literal) = ty else {
-        return None;
-    };
-
-    match literal.get_literal()? {
-        LuaLiteralToken::String(token) => Some(token.get_value()),
-        _ => None,
-    }
-}
-
-fn doc_type_number_literal(ty: &LuaDocType) -> Option<f64> {
-    let LuaDocType::Literal(literal) = ty else {
-        return None;
-    };
-
-    match literal.get_literal()? {
-        LuaLiteralToken::Number(token) => match token.get_number_value() {
-            emmylua_parser::NumberResult::Int(i) => Some(i as f64),
-            emmylua_parser::NumberResult::Uint(u) => Some(u as f64),
-            emmylua_parser::NumberResult::Float(f) => Some(f),
-        },
-        _ => None,
-    }
-}
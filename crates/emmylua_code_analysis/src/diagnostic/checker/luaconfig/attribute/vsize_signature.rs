@@ -1,13 +1,13 @@
-use emmylua_parser::{
-    LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse, LuaDocType, LuaLiteralToken,
-    NumberResult,
-};
+use emmylua_parser::{LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse, LuaDocType};
 use rowan::NodeOrToken;
 
 use crate::{
     DiagnosticCode, SemanticModel,
     attributes::parse_range_spec,
-    diagnostic::checker::{Checker, DiagnosticContext},
+    diagnostic::checker::{
+        Checker, DiagnosticContext,
+        luaconfig::attribute::doc_utils::{doc_type_number_literal, doc_type_string_literal},
+    },
 };
 
 pub struct VSizeSignatureChecker;
@@ -50,14 +50,13 @@ fn validate_vsize_signature(
     attribute_use: &LuaDocAttributeUse,
     target_type: Option<&LuaDocType>,
 ) -> Result<(), String> {
-    if target_type.is_none() {
+    let Some(target_type) = target_type else {
         return Err(
             "v.size must be used as a type attribute (e.g. ([v.size(1)] array<integer>))"
                 .to_string(),
         );
-    }
+    };
 
-    let target_type = target_type.unwrap();
     if !is_container_doc_type(target_type) {
         return Err("v.size can only be applied to container types".to_string());
     }
@@ -150,29 +149,3 @@ fn resolve_target_doc_type(tag_use: &LuaDocTagAttributeUse) -> Option<LuaDocType
     }
     None
 }
-
-fn doc_type_string_literal(ty: &LuaDocType) -> Option<String> {
-    let LuaDocType::Literal(literal) = ty else {
-        return None;
-    };
-
-    match literal.get_literal()? {
-        LuaLiteralToken::String(token) => Some(token.get_value()),
-        _ => None,
-    }
-}
-
-fn doc_type_number_literal(ty: &LuaDocType) -> Option<f64> {
-    let LuaDocType::Literal(literal) = ty else {
-        return None;
-    };
-
-    match literal.get_literal()? {
-        LuaLiteralToken::Number(token) => match token.get_number_value() {
-            NumberResult::Int(i) => Some(i as f64),
-            NumberResult::Uint(u) => Some(u as f64),
-            NumberResult::Float(f) => Some(f),
-        },
-        _ => None,
-    }
-}
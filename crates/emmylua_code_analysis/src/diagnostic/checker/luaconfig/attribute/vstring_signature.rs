@@ -0,0 +1,149 @@
+use emmylua_parser::{LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse, LuaDocType};
+use rowan::NodeOrToken;
+use smol_str::SmolStr;
+
+use crate::{
+    DiagnosticCode, LuaType, SemanticModel,
+    attributes::{
+        StringFormat, VFormatAttribute, VLenAttribute, VRegexAttribute, compile_pattern,
+        parse_size_type,
+    },
+    diagnostic::checker::{
+        Checker, DiagnosticContext,
+        luaconfig::attribute::doc_utils::{doc_type_integer_literal, doc_type_string_literal},
+    },
+};
+
+/// 检查 `v.regex` / `v.len` / `v.format` 的参数
+pub struct VStringSignatureChecker;
+
+impl Checker for VStringSignatureChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::InvalidStringSignature];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+
+        for tag_use in root.descendants::<LuaDocTagAttributeUse>() {
+            let target_type = resolve_target_doc_type(&tag_use);
+            for attribute_use in tag_use.get_attribute_uses() {
+                let Some(name) = get_string_attribute_name(&attribute_use) else {
+                    continue;
+                };
+
+                if let Err(reason) =
+                    validate_string_signature(name, &attribute_use, target_type.as_ref())
+                {
+                    context.add_diagnostic(
+                        DiagnosticCode::InvalidStringSignature,
+                        attribute_use.get_range(),
+                        t!("Invalid %{name}: %{reason}", name = name, reason = reason).to_string(),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn get_string_attribute_name(attribute_use: &LuaDocAttributeUse) -> Option<&'static str> {
+    let name_token = attribute_use.get_type()?.get_name_token()?;
+    [
+        VRegexAttribute::NAME,
+        VLenAttribute::NAME,
+        VFormatAttribute::NAME,
+    ]
+    .into_iter()
+    .find(|name| *name == name_token.get_name_text())
+}
+
+fn validate_string_signature(
+    name: &str,
+    attribute_use: &LuaDocAttributeUse,
+    target_type: Option<&LuaDocType>,
+) -> Result<(), String> {
+    if target_type.is_some_and(is_container_doc_type) {
+        return Err(format!(
+            "{name} can only be applied to strings, use it on the element type instead"
+        ));
+    }
+
+    let args = attribute_use
+        .get_arg_list()
+        .map(|l| l.get_args().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let [arg] = args.as_slice() else {
+        return Err(format!("{name} expects exactly one parameter"));
+    };
+
+    match name {
+        VRegexAttribute::NAME => {
+            let Some(pattern) = doc_type_string_literal(arg) else {
+                return Err("pattern must be a string".to_string());
+            };
+            compile_pattern(&pattern)
+                .map(|_| ())
+                .map_err(|err| format!("invalid pattern `{pattern}`: {err}"))
+        }
+        VLenAttribute::NAME => {
+            let ty = if let Some(s) = doc_type_string_literal(arg) {
+                LuaType::DocStringConst(SmolStr::new(s).into())
+            } else if let Some(n) = doc_type_integer_literal(arg) {
+                LuaType::DocIntegerConst(n)
+            } else {
+                return Err("len must be an integer or a string range".to_string());
+            };
+            parse_size_type(&ty, "len")
+                .map(|_| ())
+                .map_err(|err| err.to_string())
+        }
+        _ => {
+            let format = doc_type_string_literal(arg).unwrap_or_default();
+            if StringFormat::from_name(&format).is_some() {
+                return Ok(());
+            }
+            let formats = StringFormat::ALL
+                .iter()
+                .map(|format| format!("`{format}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(format!(
+                "unknown format `{format}`, expected one of {formats}"
+            ))
+        }
+    }
+}
+
+fn is_container_doc_type(ty: &LuaDocType) -> bool {
+    match ty {
+        LuaDocType::Array(_) => true,
+        LuaDocType::Generic(generic) => generic
+            .get_name_type()
+            .and_then(|name_type| name_type.get_name_text())
+            .is_some_and(|name| {
+                matches!(name.as_str(), "array" | "list" | "set" | "map" | "table")
+            }),
+        _ => false,
+    }
+}
+
+fn resolve_target_doc_type(tag_use: &LuaDocTagAttributeUse) -> Option<LuaDocType> {
+    let mut cursor = tag_use.syntax().clone().next_sibling_or_token();
+    while let Some(element) = cursor {
+        match element {
+            NodeOrToken::Token(token) => {
+                if token.text().trim().is_empty() {
+                    cursor = token.next_sibling_or_token();
+                    continue;
+                }
+                return None;
+            }
+            NodeOrToken::Node(node) => {
+                if LuaDocType::can_cast(node.kind().into()) {
+                    return LuaDocType::cast(node);
+                }
+                return None;
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,86 @@
+use emmylua_parser::{LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse};
+
+use crate::{
+    DiagnosticCode, DocTypeInferContext, LuaType, SemanticModel,
+    attributes::{collect_string_names, parse_vunion_ref_signature},
+    diagnostic::checker::{
+        Checker, DiagnosticContext, luaconfig::attribute::doc_utils::find_attached_class,
+    },
+    infer_doc_type,
+};
+
+pub struct VUnionRefSignatureChecker;
+
+impl Checker for VUnionRefSignatureChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::InvalidRefSignature];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let file_id = semantic_model.get_file_id();
+        let db = semantic_model.get_db();
+        let root = semantic_model.get_root().clone();
+
+        for tag_use in root.descendants::<LuaDocTagAttributeUse>() {
+            for attribute_use in tag_use.get_attribute_uses() {
+                if !is_vunion_ref_attribute_use(&attribute_use) {
+                    continue;
+                }
+
+                let Some(args) = infer_vunion_ref_args(semantic_model, &attribute_use) else {
+                    continue;
+                };
+                let Some(table_name) = args.first().and_then(get_string_const) else {
+                    continue;
+                };
+                let fields = args.get(1).map(collect_string_names).unwrap_or_default();
+                let keys = args.get(2).map(collect_string_names);
+                let bean_id = find_attached_class(semantic_model, &tag_use);
+
+                if let Err(err) = parse_vunion_ref_signature(
+                    db,
+                    file_id,
+                    bean_id.as_ref(),
+                    table_name,
+                    &fields,
+                    keys.as_deref(),
+                ) {
+                    context.add_diagnostic(
+                        DiagnosticCode::InvalidRefSignature,
+                        attribute_use.get_range(),
+                        err.to_message(),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn is_vunion_ref_attribute_use(attribute_use: &LuaDocAttributeUse) -> bool {
+    attribute_use
+        .get_type()
+        .and_then(|ty| ty.get_name_token())
+        .is_some_and(|token| token.get_name_text() == "v.union_ref")
+}
+
+fn infer_vunion_ref_args(
+    semantic_model: &SemanticModel,
+    attribute_use: &LuaDocAttributeUse,
+) -> Option<Vec<LuaType>> {
+    let args = attribute_use
+        .get_arg_list()?
+        .get_args()
+        .map(|arg| {
+            let doc_ctx =
+                DocTypeInferContext::new(semantic_model.get_db(), semantic_model.get_file_id());
+            infer_doc_type(doc_ctx, &arg)
+        })
+        .collect();
+    Some(args)
+}
+
+fn get_string_const(ty: &LuaType) -> Option<&str> {
+    match ty {
+        LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+        _ => None,
+    }
+}
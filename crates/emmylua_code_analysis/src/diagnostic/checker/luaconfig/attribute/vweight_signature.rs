@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use emmylua_parser::{LuaAstNode, LuaDocAttributeUse, LuaDocTagAttributeUse};
+
+use crate::{
+    DiagnosticCode, DocTypeInferContext, LuaMemberOwner, LuaType, LuaTypeDeclId, SemanticModel,
+    attributes::{VProbSumAttribute, VWeightSumAttribute},
+    diagnostic::checker::{
+        Checker, DiagnosticContext,
+        luaconfig::attribute::doc_utils::{
+            doc_type_number_literal, doc_type_string_literal, find_attached_class,
+            find_attached_field,
+        },
+    },
+    infer_doc_type,
+};
+
+/// 检查 `v.weight_sum` / `v.prob_sum` 作用于数值字段, 且参数合法
+pub struct VWeightSignatureChecker;
+
+impl Checker for VWeightSignatureChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::InvalidWeightSignature];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+
+        for tag_use in root.descendants::<LuaDocTagAttributeUse>() {
+            for attribute_use in tag_use.get_attribute_uses() {
+                let Some(name) = get_weight_attribute_name(&attribute_use) else {
+                    continue;
+                };
+
+                if let Err(reason) =
+                    validate_weight_signature(semantic_model, name, &tag_use, &attribute_use)
+                {
+                    context.add_diagnostic(
+                        DiagnosticCode::InvalidWeightSignature,
+                        attribute_use.get_range(),
+                        t!("Invalid %{name}: %{reason}", name = name, reason = reason).to_string(),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn get_weight_attribute_name(attribute_use: &LuaDocAttributeUse) -> Option<&'static str> {
+    let name_token = attribute_use.get_type()?.get_name_token()?;
+    [VWeightSumAttribute::NAME, VProbSumAttribute::NAME]
+        .into_iter()
+        .find(|name| *name == name_token.get_name_text())
+}
+
+fn validate_weight_signature(
+    semantic_model: &SemanticModel,
+    name: &str,
+    tag_use: &LuaDocTagAttributeUse,
+    attribute_use: &LuaDocAttributeUse,
+) -> Result<(), String> {
+    let Some(field) = find_attached_field(tag_use) else {
+        return Err(format!("{name} can only be applied to `---@field`"));
+    };
+
+    let args = attribute_use
+        .get_arg_list()
+        .map(|l| l.get_args().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let group_by = match (name, args.as_slice()) {
+        (VWeightSumAttribute::NAME, [total, rest @ ..]) if rest.len() <= 1 => {
+            match doc_type_number_literal(total) {
+                Some(total) if total > 0.0 => {}
+                _ => return Err("total must be a positive number".to_string()),
+            }
+            rest.first()
+        }
+        (VWeightSumAttribute::NAME, _) => {
+            return Err(format!("{name} expects total and an optional groupBy"));
+        }
+        (_, []) => None,
+        (_, [group_by]) => Some(group_by),
+        _ => return Err(format!("{name} expects an optional groupBy")),
+    };
+
+    let db = semantic_model.get_db();
+    if let Some(field_type) = field.get_type() {
+        let doc_ctx = DocTypeInferContext::new(db, semantic_model.get_file_id());
+        let field_type = infer_doc_type(doc_ctx, &field_type);
+        if semantic_model
+            .type_check(&LuaType::Number, field_type.strip_attributed())
+            .is_err()
+        {
+            return Err(format!("{name} can only be applied to number fields"));
+        }
+    }
+
+    if let Some(group_by) = group_by {
+        let Some(group_by) = doc_type_string_literal(group_by) else {
+            return Err("groupBy must be a string literal".to_string());
+        };
+        if let Some(bean_id) = find_attached_class(semantic_model, tag_use)
+            && !collect_field_names(semantic_model, &bean_id).contains(&group_by)
+        {
+            return Err(format!(
+                "groupBy field `{}` not found in `{}`",
+                group_by,
+                bean_id.get_name()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_field_names(semantic_model: &SemanticModel, bean_id: &LuaTypeDeclId) -> HashSet<String> {
+    let db = semantic_model.get_db();
+    bean_id
+        .collect_super_types_with_self(db, LuaType::Ref(bean_id.clone()))
+        .into_iter()
+        .filter_map(|typ| match typ {
+            LuaType::Ref(type_id) => db
+                .get_member_index()
+                .get_members(&LuaMemberOwner::Type(type_id)),
+            _ => None,
+        })
+        .flatten()
+        .map(|member| member.get_key().to_path())
+        .collect()
+}
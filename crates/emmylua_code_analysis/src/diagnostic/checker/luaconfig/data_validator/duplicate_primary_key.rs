@@ -1,11 +1,5 @@
-use std::{
-    collections::{HashMap, HashSet},
-    ops::Deref,
-};
-
 use crate::{
-    ConfigTablePkOccurrence, DiagnosticCode, LuaMemberKey, LuaType, LuaTypeDeclId, RenderLevel,
-    SemanticModel,
+    ConfigTablePkOccurrence, DiagnosticCode, RenderLevel, SemanticModel,
     diagnostic::checker::{Checker, DiagnosticContext},
     humanize_type,
 };
@@ -25,46 +19,7 @@ impl Checker for DuplicatePrimaryKeyChecker {
             return;
         };
 
-        // 当前文件中所有的 ConfigTable
-        let mut relevant_tables: HashSet<LuaTypeDeclId> = HashSet::new();
-        for occ in occurrences.iter() {
-            relevant_tables.insert(occ.get_config_table().clone());
-        }
-
-        let mut solo_counts: HashMap<(LuaTypeDeclId, LuaMemberKey, LuaType), u32> = HashMap::new();
-        let mut union_counts: HashMap<(LuaTypeDeclId, Vec<LuaType>), u32> = HashMap::new();
-
-        // 遍历所有 ConfigTable 的索引键
-        for occ in db.get_config_index().iter_config_table_pk_occurrences() {
-            let config_table = occ.get_config_table();
-            if !relevant_tables.contains(config_table) {
-                continue;
-            }
-
-            match occ {
-                ConfigTablePkOccurrence::Solo {
-                    config_table,
-                    key,
-                    value,
-                    ..
-                } => {
-                    *solo_counts
-                        .entry((config_table.clone(), key.deref().clone(), value.clone()))
-                        .or_default() += 1;
-                }
-                ConfigTablePkOccurrence::Union {
-                    config_table,
-                    keys: _,
-                    values,
-                    ..
-                } => {
-                    *union_counts
-                        .entry((config_table.clone(), values.clone()))
-                        .or_default() += 1;
-                }
-            }
-        }
-
+        let pk_index = db.get_config_index().get_pk_value_index();
         for occ in occurrences {
             match occ {
                 ConfigTablePkOccurrence::Solo {
@@ -73,10 +28,7 @@ impl Checker for DuplicatePrimaryKeyChecker {
                     value,
                     range,
                 } => {
-                    let count = solo_counts
-                        .get(&(config_table.clone(), key.deref().clone(), value.clone()))
-                        .copied()
-                        .unwrap_or(0);
+                    let count = pk_index.get_locations(config_table, key, value).len();
                     if count <= 1 {
                         continue;
                     }
@@ -110,10 +62,7 @@ impl Checker for DuplicatePrimaryKeyChecker {
                     values,
                     ranges,
                 } => {
-                    let count = union_counts
-                        .get(&(config_table.clone(), values.clone()))
-                        .copied()
-                        .unwrap_or(0);
+                    let count = pk_index.get_union_locations(config_table, values).len();
                     if count <= 1 {
                         continue;
                     }
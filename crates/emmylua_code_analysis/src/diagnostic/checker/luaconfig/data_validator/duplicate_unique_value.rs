@@ -0,0 +1,75 @@
+use crate::{
+    DiagnosticCode, RenderLevel, SemanticModel,
+    diagnostic::checker::{Checker, DiagnosticContext},
+    humanize_type,
+};
+
+pub struct DuplicateUniqueValueChecker;
+
+impl Checker for DuplicateUniqueValueChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::DuplicateUniqueValue];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let file_id = semantic_model.get_file_id();
+        let db = semantic_model.get_db();
+        let config_index = db.get_config_index();
+        let Some(occurrences) = config_index.get_config_table_unique_occurrences(&file_id) else {
+            return;
+        };
+
+        let unique_value_index = config_index.get_unique_value_index();
+        for occ in occurrences {
+            let all = unique_value_index.get_locations(&occ.config_table, &occ.fields, &occ.values);
+            if all.len() <= 1 {
+                continue;
+            }
+            let Some(first_range) = occ.ranges.first() else {
+                continue;
+            };
+
+            let name = occ
+                .fields
+                .iter()
+                .zip(occ.values.iter())
+                .map(|(key, value)| {
+                    format!(
+                        "{}={}",
+                        key.to_path(),
+                        humanize_type(db, value, RenderLevel::Simple)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let related = all
+                .iter()
+                .filter(|location| !(location.file_id == file_id && location.range == *first_range))
+                .map(|location| {
+                    (
+                        location.file_id,
+                        location.range,
+                        t!(
+                            "Duplicate value `%{name}` is also defined here",
+                            name = name
+                        )
+                        .to_string(),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            for range in occ.ranges.iter() {
+                context.add_diagnostic_with_related(
+                    DiagnosticCode::DuplicateUniqueValue,
+                    *range,
+                    t!(
+                        "Duplicate unique value `%{name}` in `%{table}`",
+                        name = name,
+                        table = occ.config_table.get_name()
+                    )
+                    .to_string(),
+                    related.clone(),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashSet;
+
+use rowan::TextRange;
+use smol_str::SmolStr;
+
+use crate::{
+    DbIndex, DiagnosticCode, LuaMemberKey, LuaType, LuaTypeDeclId, RenderLevel, SemanticModel,
+    attributes::{TCoverEnumAttribute, is_flags_attribute},
+    db_index::{LuaMemberOwner, LuaSemanticDeclId},
+    diagnostic::checker::{Checker, DiagnosticContext},
+    find_members_with_key, humanize_type,
+    semantic::shared::luaconfig::CONFIG_TABLE,
+};
+
+pub struct EnumCoverageChecker;
+
+impl Checker for EnumCoverageChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::EnumCoverageMismatch];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let db = semantic_model.get_db();
+        let file_id = semantic_model.get_file_id();
+        let Some(file_types) = db.get_type_index().get_file_types(&file_id) else {
+            return;
+        };
+
+        for type_decl_id in file_types {
+            if !CONFIG_TABLE.is_config_table(db, type_decl_id) {
+                continue;
+            }
+            let Some(property) = db
+                .get_property_index()
+                .get_property(&LuaSemanticDeclId::TypeDecl(type_decl_id.clone()))
+            else {
+                continue;
+            };
+            let attrs = TCoverEnumAttribute::find_all_in(property);
+            if attrs.is_empty() {
+                continue;
+            }
+            let Some(range) = db
+                .get_type_index()
+                .get_type_decl(type_decl_id)
+                .and_then(|decl| {
+                    decl.get_locations()
+                        .iter()
+                        .find(|location| location.file_id == file_id)
+                        .map(|location| location.range)
+                })
+            else {
+                continue;
+            };
+
+            for attr in attrs {
+                check_cover_enum(context, semantic_model, type_decl_id, &attr, range);
+            }
+        }
+    }
+}
+
+fn check_cover_enum(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    config_table: &LuaTypeDeclId,
+    attr: &TCoverEnumAttribute,
+    range: TextRange,
+) -> Option<()> {
+    let db = semantic_model.get_db();
+    let field_name = attr.get_field()?;
+    let enum_name = attr.get_enum_name()?;
+
+    let bean_id = CONFIG_TABLE.get_bean_id(db, config_table)?;
+    let field = LuaMemberKey::Name(field_name.into());
+    let has_field = find_members_with_key(db, &LuaType::Ref(bean_id.clone()), field.clone(), false)
+        .is_some_and(|members| !members.is_empty());
+    if !has_field {
+        context.add_diagnostic(
+            DiagnosticCode::EnumCoverageMismatch,
+            range,
+            t!(
+                "Invalid t.cover_enum: `%{bean}` has no field `%{field}`",
+                bean = bean_id.get_name(),
+                field = field_name
+            )
+            .to_string(),
+            None,
+        );
+        return None;
+    }
+
+    let enum_decl = db
+        .get_type_index()
+        .find_type_decl(semantic_model.get_file_id(), enum_name)
+        .filter(|decl| decl.is_enum());
+    let Some(enum_decl) = enum_decl else {
+        context.add_diagnostic(
+            DiagnosticCode::EnumCoverageMismatch,
+            range,
+            t!(
+                "Invalid t.cover_enum: `%{name}` is not an enum",
+                name = enum_name
+            )
+            .to_string(),
+            None,
+        );
+        return None;
+    };
+    let enum_id = enum_decl.get_id();
+    let is_flags = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(enum_id.clone()))
+        .is_some_and(is_flags_attribute);
+    let members = collect_enum_members(db, &enum_id, enum_decl.is_enum_key());
+
+    // 配置表在所有文件中的字段值
+    let mut values: Vec<LuaType> = Vec::new();
+    let mut seen: HashSet<LuaType> = HashSet::new();
+    for (_, occurrences) in db
+        .get_config_index()
+        .iter_file_config_table_field_value_occurrences()
+    {
+        for occ in occurrences {
+            if &occ.config_table != config_table || occ.field != field {
+                continue;
+            }
+            if let Some(value) = normalize_value(&occ.value)
+                && seen.insert(value.clone())
+            {
+                values.push(value);
+            }
+        }
+    }
+    // 配置表没有数据时不检查
+    if values.is_empty() {
+        return Some(());
+    }
+
+    let missing: Vec<&str> = members
+        .iter()
+        .filter(|(_, value)| !(is_flags && *value == LuaType::IntegerConst(0)))
+        .filter(|(_, value)| !seen.contains(value))
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if !missing.is_empty() {
+        context.add_diagnostic(
+            DiagnosticCode::EnumCoverageMismatch,
+            range,
+            t!(
+                "`%{table}.%{field}` is missing rows for `%{enum_name}` members: %{members}",
+                table = config_table.get_name(),
+                field = field_name,
+                enum_name = enum_id.get_name(),
+                members = missing
+                    .iter()
+                    .map(|name| format!("`{}`", name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    let member_values: HashSet<&LuaType> = members.iter().map(|(_, value)| value).collect();
+    let flags_mask = members
+        .iter()
+        .filter_map(|(_, value)| match value {
+            LuaType::IntegerConst(i) => Some(*i),
+            _ => None,
+        })
+        .fold(0i64, |mask, value| mask | value);
+    let extra: Vec<String> = values
+        .iter()
+        .filter(|value| !member_values.contains(value))
+        .filter(|value| match value {
+            LuaType::IntegerConst(i) if is_flags => i & !flags_mask != 0,
+            _ => true,
+        })
+        .map(|value| format!("`{}`", humanize_type(db, value, RenderLevel::Simple)))
+        .collect();
+    if !extra.is_empty() {
+        context.add_diagnostic(
+            DiagnosticCode::EnumCoverageMismatch,
+            range,
+            t!(
+                "`%{table}.%{field}` has values not in `%{enum_name}`: %{values}",
+                table = config_table.get_name(),
+                field = field_name,
+                enum_name = enum_id.get_name(),
+                values = extra.join(", ")
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    Some(())
+}
+
+/// 枚举成员的 (名称, 值), `---@enum (key)` 的值为成员名称
+fn collect_enum_members(
+    db: &DbIndex,
+    enum_id: &LuaTypeDeclId,
+    is_enum_key: bool,
+) -> Vec<(String, LuaType)> {
+    let Some(members) = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(enum_id.clone()))
+    else {
+        return Vec::new();
+    };
+
+    let mut members = members.to_vec();
+    members.sort_by_key(|member| member.get_sort_key());
+    members
+        .into_iter()
+        .filter_map(|member| {
+            let name = match member.get_key() {
+                LuaMemberKey::Name(name) => name.to_string(),
+                LuaMemberKey::Integer(i) => i.to_string(),
+                _ => return None,
+            };
+            let value = if is_enum_key {
+                LuaType::StringConst(SmolStr::new(&name).into())
+            } else {
+                let type_cache = db
+                    .get_type_index()
+                    .get_type_cache(&member.get_id().into())?;
+                normalize_value(type_cache.as_type())?
+            };
+            Some((name, value))
+        })
+        .collect()
+}
+
+fn normalize_value(ty: &LuaType) -> Option<LuaType> {
+    match ty {
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => Some(LuaType::IntegerConst(*i)),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => {
+            Some(LuaType::StringConst(s.clone()))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,350 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    ConfigTableEdgeOccurrence, ConfigTableTreeRule, DbIndex, DiagnosticCode, LuaMemberKey, LuaType,
+    LuaTypeDeclId, RenderLevel, SemanticModel,
+    diagnostic::checker::{Checker, DiagnosticContext},
+    humanize_type,
+};
+
+pub struct InvalidGraphChecker;
+
+impl Checker for InvalidGraphChecker {
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::RefCycle,
+        DiagnosticCode::UnreachableRow,
+        DiagnosticCode::TreeDepthExceeded,
+    ];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let file_id = semantic_model.get_file_id();
+        let db = semantic_model.get_db();
+        let config_index = db.get_config_index();
+        let Some(local_edges) = config_index.get_config_table_edge_occurrences(&file_id) else {
+            return;
+        };
+
+        let tables: HashSet<&LuaTypeDeclId> =
+            local_edges.iter().map(|occ| &occ.config_table).collect();
+        for table in tables {
+            let Some(rules) = config_index.get_config_table_graph_rules(table) else {
+                continue;
+            };
+            let local: Vec<&ConfigTableEdgeOccurrence> = local_edges
+                .iter()
+                .filter(|occ| &occ.config_table == table)
+                .collect();
+
+            for field in rules.acyclic_fields.iter() {
+                check_acyclic(context, db, table, field, &local);
+            }
+            if let Some(tree) = &rules.tree {
+                // 父字段同时声明了 v.acyclic 时, 环已经被报告过
+                let report_cycle = !rules.acyclic_fields.contains(&tree.parent);
+                check_tree(context, db, table, tree, report_cycle, &local);
+            }
+        }
+    }
+}
+
+/// 检查字段引用是否形成环
+fn check_acyclic(
+    context: &mut DiagnosticContext,
+    db: &DbIndex,
+    table: &LuaTypeDeclId,
+    field: &LuaMemberKey,
+    local: &[&ConfigTableEdgeOccurrence],
+) {
+    let graph = RefGraph::build(db, table, field);
+    let components = graph.strongly_connected_components();
+
+    for occ in local.iter().filter(|occ| &occ.field == field) {
+        let Some(source) = graph.get(&occ.source) else {
+            continue;
+        };
+        let cycle = occ.targets.iter().find_map(|target| {
+            let target = graph.get(target)?;
+            if source == target {
+                Some(vec![source, source])
+            } else if components[source] == components[target] {
+                graph.find_cycle(source, target, &components)
+            } else {
+                None
+            }
+        });
+        let Some(cycle) = cycle else {
+            continue;
+        };
+
+        context.add_diagnostic(
+            DiagnosticCode::RefCycle,
+            occ.range,
+            t!(
+                "Reference cycle `%{path}` in `%{table}.%{field}`",
+                path = graph.render_path(db, &cycle),
+                table = table.get_name(),
+                field = field.to_path()
+            )
+            .to_string(),
+            None,
+        );
+    }
+}
+
+/// 检查树结构: 父字段形成的环, 无法从根节点到达的记录与树的层数
+fn check_tree(
+    context: &mut DiagnosticContext,
+    db: &DbIndex,
+    table: &LuaTypeDeclId,
+    tree: &ConfigTableTreeRule,
+    report_cycle: bool,
+    local: &[&ConfigTableEdgeOccurrence],
+) {
+    let config_index = db.get_config_index();
+    let mut parents: HashMap<&LuaType, &LuaType> = HashMap::new();
+    for (_, occurrences) in config_index.iter_file_config_table_edge_occurrences() {
+        for occ in occurrences {
+            if &occ.config_table != table || occ.field != tree.parent {
+                continue;
+            }
+            if let Some(parent) = occ.targets.first() {
+                parents.entry(&occ.source).or_insert(parent);
+            }
+        }
+    }
+
+    let pk_index = config_index.get_pk_value_index();
+    let mut rows: HashSet<&LuaType> = HashSet::new();
+    if let Some(keys) = config_index.get_config_table_keys(table)
+        && keys.is_solo()
+    {
+        for key in keys.keys() {
+            rows.extend(pk_index.iter_values(table, key));
+        }
+    }
+
+    'occ: for occ in local.iter().filter(|occ| occ.field == tree.parent) {
+        let row = humanize_type(db, &occ.source, RenderLevel::Simple);
+        let mut chain: Vec<&LuaType> = vec![&occ.source];
+        let mut current = &occ.source;
+        while let Some(parent) = parents.get(current).copied() {
+            if let Some(pos) = chain.iter().position(|node| *node == parent) {
+                if pos == 0 {
+                    if report_cycle {
+                        let path = chain
+                            .iter()
+                            .chain(std::iter::once(&parent))
+                            .map(|node| humanize_type(db, node, RenderLevel::Simple))
+                            .collect::<Vec<_>>()
+                            .join(" -> ");
+                        context.add_diagnostic(
+                            DiagnosticCode::RefCycle,
+                            occ.range,
+                            t!(
+                                "Reference cycle `%{path}` in `%{table}.%{field}`",
+                                path = path,
+                                table = table.get_name(),
+                                field = tree.parent.to_path()
+                            )
+                            .to_string(),
+                            None,
+                        );
+                    }
+                } else {
+                    context.add_diagnostic(
+                        DiagnosticCode::UnreachableRow,
+                        occ.range,
+                        t!(
+                            "Row `%{row}` is unreachable from any root: its ancestors form a cycle",
+                            row = row
+                        )
+                        .to_string(),
+                        None,
+                    );
+                }
+                continue 'occ;
+            }
+
+            if !rows.contains(parent) {
+                context.add_diagnostic(
+                    DiagnosticCode::UnreachableRow,
+                    occ.range,
+                    t!(
+                        "Row `%{row}` is unreachable from any root: `%{parent}` not found in `%{table}`",
+                        row = row,
+                        parent = humanize_type(db, parent, RenderLevel::Simple),
+                        table = table.get_name()
+                    )
+                    .to_string(),
+                    None,
+                );
+                continue 'occ;
+            }
+
+            chain.push(parent);
+            current = parent;
+        }
+
+        if let Some(max_depth) = tree.max_depth
+            && chain.len() > max_depth
+        {
+            context.add_diagnostic(
+                DiagnosticCode::TreeDepthExceeded,
+                occ.range,
+                t!(
+                    "Row `%{row}` is at depth %{depth}, exceeding maxDepth %{max} of `%{table}`",
+                    row = row,
+                    depth = chain.len(),
+                    max = max_depth,
+                    table = table.get_name()
+                )
+                .to_string(),
+                None,
+            );
+        }
+    }
+}
+
+/// 配置表记录间的引用图, 节点为记录的主键值
+#[derive(Default)]
+struct RefGraph {
+    nodes: Vec<LuaType>,
+    index: HashMap<LuaType, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl RefGraph {
+    fn build(db: &DbIndex, table: &LuaTypeDeclId, field: &LuaMemberKey) -> Self {
+        let mut graph = Self::default();
+        // 按文件路径排序, 使报告的环路径稳定
+        let mut files = db
+            .get_config_index()
+            .iter_file_config_table_edge_occurrences()
+            .collect::<Vec<_>>();
+        files.sort_by_cached_key(|(file_id, _)| db.get_vfs().get_file_path(file_id).cloned());
+        for (_, occurrences) in files {
+            for occ in occurrences {
+                if &occ.config_table != table || &occ.field != field {
+                    continue;
+                }
+                let source = graph.add_node(&occ.source);
+                for target in occ.targets.iter() {
+                    let target = graph.add_node(target);
+                    graph.edges[source].push(target);
+                }
+            }
+        }
+        graph
+    }
+
+    fn add_node(&mut self, value: &LuaType) -> usize {
+        if let Some(idx) = self.index.get(value) {
+            return *idx;
+        }
+        let idx = self.nodes.len();
+        self.nodes.push(value.clone());
+        self.index.insert(value.clone(), idx);
+        self.edges.push(Vec::new());
+        idx
+    }
+
+    fn get(&self, value: &LuaType) -> Option<usize> {
+        self.index.get(value).copied()
+    }
+
+    /// Tarjan 强连通分量, 返回每个节点所属分量的编号
+    fn strongly_connected_components(&self) -> Vec<usize> {
+        const UNVISITED: usize = usize::MAX;
+        let n = self.nodes.len();
+        let mut index = vec![UNVISITED; n];
+        let mut low = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack: Vec<usize> = Vec::new();
+        let mut components = vec![UNVISITED; n];
+        let mut next_index = 0;
+        let mut next_component = 0;
+
+        for start in 0..n {
+            if index[start] != UNVISITED {
+                continue;
+            }
+
+            index[start] = next_index;
+            low[start] = next_index;
+            next_index += 1;
+            stack.push(start);
+            on_stack[start] = true;
+            let mut call_stack: Vec<(usize, usize)> = vec![(start, 0)];
+
+            while let Some(frame) = call_stack.last_mut() {
+                let node = frame.0;
+                if let Some(&next) = self.edges[node].get(frame.1) {
+                    frame.1 += 1;
+                    if index[next] == UNVISITED {
+                        index[next] = next_index;
+                        low[next] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        call_stack.push((next, 0));
+                    } else if on_stack[next] {
+                        low[node] = low[node].min(index[next]);
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+                if low[node] == index[node] {
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        components[member] = next_component;
+                        if member == node {
+                            break;
+                        }
+                    }
+                    next_component += 1;
+                }
+            }
+        }
+
+        components
+    }
+
+    /// 在同一强连通分量内查找 `source -> target -> ... -> source` 的环
+    fn find_cycle(&self, source: usize, target: usize, components: &[usize]) -> Option<Vec<usize>> {
+        let component = components[source];
+        let mut prev: HashMap<usize, usize> = HashMap::new();
+        let mut queue = VecDeque::from([target]);
+        let mut visited = HashSet::from([target]);
+        while let Some(node) = queue.pop_front() {
+            if node == source {
+                let mut path = vec![source];
+                let mut current = source;
+                while current != target {
+                    current = *prev.get(&current)?;
+                    path.push(current);
+                }
+                path.push(source);
+                path.reverse();
+                return Some(path);
+            }
+            for &next in self.edges[node].iter() {
+                if components[next] == component && visited.insert(next) {
+                    prev.insert(next, node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    fn render_path(&self, db: &DbIndex, path: &[usize]) -> String {
+        path.iter()
+            .map(|idx| humanize_type(db, &self.nodes[*idx], RenderLevel::Simple))
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use crate::{
     DiagnosticCode, LuaMemberKey, LuaType, LuaTypeDeclId, SemanticModel,
-    attributes::TIndexAttribute,
+    attributes::{TIndexAttribute, TUniqueAttribute},
     db_index::{DbIndex, LuaMemberOwner},
     diagnostic::checker::{Checker, DiagnosticContext},
     find_index_operations, is_sub_type_of,
@@ -62,15 +62,15 @@ fn check_attribute_use(
     tag_use: &LuaDocTagAttributeUse,
     config_table_ids: &HashSet<LuaTypeDeclId>,
 ) -> Option<()> {
-    // 检查是否为 t.index 属性
+    // 检查是否为 t.index / t.unique 属性
     let attr_type = attribute_use.get_type()?;
     let attr_name = attr_type.get_name_text()?;
-    if attr_name != TIndexAttribute::NAME {
+    if attr_name != TIndexAttribute::NAME && attr_name != TUniqueAttribute::NAME {
         return Some(());
     }
 
     // 找到此属性附加到的类型声明
-    // @[t.index] 在 @class 之前，需要查找下一个兄弟节点 LuaDocTagClass
+    // @[t.index] / @[t.unique] 在 @class 之前，需要查找下一个兄弟节点 LuaDocTagClass
     let tag_class = find_next_class_sibling(tag_use)?;
     let class_name_token = tag_class.get_name_token()?;
     let class_name = class_name_token.get_name_text().to_string();
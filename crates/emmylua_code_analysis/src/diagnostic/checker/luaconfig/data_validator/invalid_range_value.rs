@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use emmylua_parser::{LuaAstNode, LuaTableExpr};
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaTableExpr};
 
 use crate::{
     DbIndex, DiagnosticCode, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId, RenderLevel,
@@ -9,7 +9,9 @@ use crate::{
     db_index::LuaSemanticDeclId,
     diagnostic::checker::{Checker, DiagnosticContext},
     humanize_type, infer_expr, infer_table_should_be,
-    semantic::shared::luaconfig::BEAN,
+    semantic::shared::luaconfig::{
+        BEAN, find_vector_type, get_vector_component_key, get_vector_components,
+    },
 };
 
 pub struct InvalidRangeValueChecker;
@@ -147,6 +149,11 @@ fn validate_container_table_data(
                     continue;
                 };
 
+                if let LuaExpr::TableExpr(vector_table) = &value_expr {
+                    validate_vector_components(context, db, infer_cache, value_spec, vector_table);
+                    continue;
+                }
+
                 let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone()) else {
                     continue;
                 };
@@ -263,6 +270,11 @@ fn validate_bean_table_data(
             continue;
         };
 
+        if let LuaExpr::TableExpr(vector_table) = &value_expr {
+            validate_vector_components(context, db, infer_cache, spec, vector_table);
+            continue;
+        }
+
         let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone()) else {
             continue;
         };
@@ -288,6 +300,59 @@ fn validate_bean_table_data(
     }
 }
 
+/// 字段类型为内置向量类型时, `v.range` 作用于每个分量
+fn validate_vector_components(
+    context: &mut DiagnosticContext,
+    db: &DbIndex,
+    infer_cache: &mut crate::LuaInferCache,
+    spec: &RangeSpec,
+    table: &LuaTableExpr,
+) {
+    let Some(components) = infer_table_should_be(db, infer_cache, table.clone())
+        .ok()
+        .and_then(|ty| find_vector_type(db, &ty))
+        .and_then(|vector_id| get_vector_components(db, &vector_id))
+    else {
+        return;
+    };
+
+    for field in table.get_fields() {
+        let Some(field_key) = field.get_field_key() else {
+            continue;
+        };
+        let Some(component_key) = LuaMemberKey::from_index_key(db, infer_cache, &field_key)
+            .ok()
+            .and_then(|key| get_vector_component_key(components, &key))
+        else {
+            continue;
+        };
+        let Some(value_expr) = field.get_value_expr() else {
+            continue;
+        };
+        let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone()) else {
+            continue;
+        };
+        let Some(value) = extract_number_value(&value_typ) else {
+            continue;
+        };
+
+        if !spec.contains(value) {
+            context.add_diagnostic(
+                DiagnosticCode::InvalidRangeValue,
+                value_expr.get_range(),
+                t!(
+                    "v.range component `%{component}` value `%{value}` is out of range `%{range}`",
+                    component = component_key.to_path(),
+                    value = humanize_type(db, &value_typ, RenderLevel::Simple),
+                    range = spec.to_string()
+                )
+                .to_string(),
+                None,
+            );
+        }
+    }
+}
+
 fn collect_bean_range_rules(db: &DbIndex, bean_id: &LuaTypeDeclId) -> HashMap<String, RangeSpec> {
     let mut out: HashMap<String, RangeSpec> = HashMap::new();
 
@@ -4,15 +4,13 @@ use emmylua_parser::{LuaAstNode, LuaExpr, LuaIndexKey, LuaTableExpr};
 use internment::ArcIntern;
 use rowan::TextRange;
 
-use super::super::attribute::vref_signature::parse_vref_signature;
-
 use crate::{
-    ConfigTablePkOccurrence, DiagnosticCode, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId,
+    ConfigTablePkValueIndex, DiagnosticCode, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId,
     LuaType, LuaTypeDeclId, RenderLevel, SemanticModel,
-    attributes::VRefAttribute,
+    attributes::{VRefAttribute, VUnionRefAttribute, VUnionRefTarget, parse_vref_signature},
     diagnostic::checker::{Checker, DiagnosticContext},
     humanize_type, infer_expr, infer_table_should_be,
-    semantic::shared::luaconfig::BEAN,
+    semantic::shared::luaconfig::{BEAN, resolve_field_enum_value},
 };
 
 pub struct InvalidRefChecker;
@@ -33,9 +31,6 @@ impl Checker for InvalidRefChecker {
         let mut bean_tables: Vec<(LuaTableExpr, LuaTypeDeclId)> = Vec::new();
         let mut container_tables: Vec<(LuaTableExpr, ContainerRefRule)> = Vec::new();
 
-        // 仅收集本文件实际用到的 (target_table -> target_keys)，用于过滤主键值集合构建
-        let mut needed: HashMap<LuaTypeDeclId, HashSet<LuaMemberKey>> = HashMap::new();
-
         for table_expr in root.descendants::<LuaTableExpr>() {
             let Ok(table_should_be) =
                 infer_table_should_be(db, &mut infer_cache, table_expr.clone())
@@ -57,18 +52,6 @@ impl Checker for InvalidRefChecker {
                 table_expr.get_range(),
                 &table_should_be,
             ) {
-                if let Some(rule) = rule.key_rule.as_ref() {
-                    needed
-                        .entry(rule.target_table.clone())
-                        .or_default()
-                        .insert(rule.target_key.clone());
-                }
-                if let Some(rule) = rule.value_rule.as_ref() {
-                    needed
-                        .entry(rule.target_table.clone())
-                        .or_default()
-                        .insert(rule.target_key.clone());
-                }
                 container_tables.push((table_expr, rule));
             }
         }
@@ -84,25 +67,26 @@ impl Checker for InvalidRefChecker {
             if rules.is_empty() {
                 continue;
             }
-            for rule in rules.iter() {
-                needed
-                    .entry(rule.target_table.clone())
-                    .or_default()
-                    .insert(rule.target_key.clone());
-            }
             bean_rules_cache.insert(bean_id, rules);
         }
 
-        if needed.is_empty() {
-            return;
+        // Bean -> v.union_ref 目标
+        let mut bean_union_rules_cache: HashMap<LuaTypeDeclId, Vec<VUnionRefTarget>> =
+            HashMap::new();
+        for (_, bean_id) in bean_tables.iter() {
+            if bean_union_rules_cache.contains_key(bean_id) {
+                continue;
+            }
+            let targets = collect_vunion_ref_targets_for_bean(db, bean_id);
+            bean_union_rules_cache.insert(bean_id.clone(), targets);
         }
 
-        let pk_sets = PkValueSets::new_filtered(db, &needed);
+        let pk_index = db.get_config_index().get_pk_value_index();
 
         // 对于不存在任何主键值的 (table,key)，只报一次并跳过值校验，避免大量噪音。
         for rules in bean_rules_cache.values_mut() {
             rules.retain(|rule| {
-                if pk_sets.has_any(&rule.target_table, &rule.target_key) {
+                if pk_index.has_values(&rule.target_table, &rule.target_key) {
                     return true;
                 }
 
@@ -125,7 +109,7 @@ impl Checker for InvalidRefChecker {
         let mut filtered_container_tables = Vec::new();
         for (table_expr, mut rule) in container_tables {
             if let Some(key_rule) = rule.key_rule.as_ref()
-                && !pk_sets.has_any(&key_rule.target_table, &key_rule.target_key)
+                && !pk_index.has_values(&key_rule.target_table, &key_rule.target_key)
             {
                 if reported_no_values
                     .insert((key_rule.target_table.clone(), key_rule.target_key.clone()))
@@ -146,7 +130,7 @@ impl Checker for InvalidRefChecker {
             }
 
             if let Some(value_rule) = rule.value_rule.as_ref()
-                && !pk_sets.has_any(&value_rule.target_table, &value_rule.target_key)
+                && !pk_index.has_values(&value_rule.target_table, &value_rule.target_key)
             {
                 if reported_no_values.insert((
                     value_rule.target_table.clone(),
@@ -173,14 +157,33 @@ impl Checker for InvalidRefChecker {
         }
 
         for (table_expr, bean_id) in bean_tables {
-            let Some(rules) = bean_rules_cache.get(&bean_id) else {
-                continue;
-            };
-            if rules.is_empty() {
-                continue;
+            if let Some(rules) = bean_rules_cache.get(&bean_id)
+                && !rules.is_empty()
+            {
+                validate_bean_table_data(
+                    context,
+                    db,
+                    &mut infer_cache,
+                    pk_index,
+                    &bean_id,
+                    rules,
+                    &table_expr,
+                );
             }
 
-            validate_bean_table_data(context, db, &mut infer_cache, &pk_sets, rules, &table_expr);
+            if let Some(targets) = bean_union_rules_cache.get(&bean_id)
+                && !targets.is_empty()
+            {
+                validate_bean_table_union_refs(
+                    context,
+                    db,
+                    &mut infer_cache,
+                    pk_index,
+                    &bean_id,
+                    targets,
+                    &table_expr,
+                );
+            }
         }
 
         for (table_expr, rule) in filtered_container_tables {
@@ -188,7 +191,7 @@ impl Checker for InvalidRefChecker {
                 context,
                 db,
                 &mut infer_cache,
-                &pk_sets,
+                pk_index,
                 &rule,
                 &table_expr,
             );
@@ -226,80 +229,6 @@ struct ContainerRefRule {
     value_rule: Option<ValidatedVRefTarget>,
 }
 
-#[derive(Default)]
-struct PkValueSets {
-    // table -> (key -> set(values))
-    values: HashMap<LuaTypeDeclId, HashMap<LuaMemberKey, HashSet<LuaType>>>,
-}
-
-impl PkValueSets {
-    fn new_filtered(
-        db: &crate::DbIndex,
-        needed: &HashMap<LuaTypeDeclId, HashSet<LuaMemberKey>>,
-    ) -> Self {
-        let mut out = Self::default();
-
-        for occ in db.get_config_index().iter_config_table_pk_occurrences() {
-            match occ {
-                ConfigTablePkOccurrence::Solo {
-                    config_table,
-                    key,
-                    value,
-                    ..
-                } => {
-                    if needed
-                        .get(config_table)
-                        .is_some_and(|keys| keys.contains(key))
-                    {
-                        out.insert_value(config_table, key, value.clone());
-                    }
-                }
-                ConfigTablePkOccurrence::Union {
-                    config_table,
-                    keys,
-                    values,
-                    ..
-                } => {
-                    let Some(needed_keys) = needed.get(config_table) else {
-                        continue;
-                    };
-
-                    for (key, value) in keys.iter().zip(values.iter()) {
-                        if needed_keys.contains(key) {
-                            out.insert_value(config_table, key, value.clone());
-                        }
-                    }
-                }
-            }
-        }
-
-        out
-    }
-
-    fn insert_value(&mut self, table: &LuaTypeDeclId, key: &LuaMemberKey, value: LuaType) {
-        self.values
-            .entry(table.clone())
-            .or_default()
-            .entry(key.clone())
-            .or_default()
-            .insert(value);
-    }
-
-    fn has_any(&self, table: &LuaTypeDeclId, key: &LuaMemberKey) -> bool {
-        self.values
-            .get(table)
-            .and_then(|m| m.get(key))
-            .is_some_and(|set| !set.is_empty())
-    }
-
-    fn contains(&self, table: &LuaTypeDeclId, key: &LuaMemberKey, value: &LuaType) -> bool {
-        self.values
-            .get(table)
-            .and_then(|m| m.get(key))
-            .is_some_and(|set| set.contains(value))
-    }
-}
-
 fn infer_key_type_from_index_key(
     db: &crate::DbIndex,
     infer_cache: &mut crate::LuaInferCache,
@@ -318,7 +247,8 @@ fn validate_bean_table_data(
     context: &mut DiagnosticContext,
     db: &crate::DbIndex,
     infer_cache: &mut crate::LuaInferCache,
-    pk_sets: &PkValueSets,
+    pk_index: &ConfigTablePkValueIndex,
+    bean_id: &LuaTypeDeclId,
     rules: &[ValidatedVRefRule],
     table: &LuaTableExpr,
 ) {
@@ -347,12 +277,13 @@ fn validate_bean_table_data(
         let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone()) else {
             continue;
         };
+        let value_typ = resolve_field_enum_value(db, bean_id, &rule.source_key, value_typ);
 
         if !is_checkable_literal_key(&value_typ) {
             continue;
         }
 
-        if pk_sets.contains(&rule.target_table, &rule.target_key, &value_typ) {
+        if pk_index.contains(&rule.target_table, &rule.target_key, &value_typ) {
             continue;
         }
 
@@ -375,11 +306,99 @@ fn validate_bean_table_data(
     }
 }
 
+fn validate_bean_table_union_refs(
+    context: &mut DiagnosticContext,
+    db: &crate::DbIndex,
+    infer_cache: &mut crate::LuaInferCache,
+    pk_index: &ConfigTablePkValueIndex,
+    bean_id: &LuaTypeDeclId,
+    targets: &[VUnionRefTarget],
+    table: &LuaTableExpr,
+) {
+    let mut field_map: HashMap<LuaMemberKey, (LuaExpr, TextRange)> = HashMap::new();
+    for field in table.get_fields() {
+        let Some(field_key) = field.get_field_key() else {
+            continue;
+        };
+        let Ok(member_key) = LuaMemberKey::from_index_key(db, infer_cache, &field_key) else {
+            continue;
+        };
+        let Some(value_expr) = field.get_value_expr() else {
+            continue;
+        };
+        field_map.insert(member_key, (value_expr, field.get_range()));
+    }
+
+    'target: for target in targets {
+        // 目标表没有任何数据时由 v.ref 的规则统一处理, 这里不重复报错
+        if !pk_index.has_union_values(&target.table) {
+            continue;
+        }
+
+        let mut tuple = Vec::with_capacity(target.pairs.len());
+        let mut report_range: Option<TextRange> = None;
+        for (local_field, _) in target.pairs.iter() {
+            // 缺失字段或非字面量时跳过
+            let Some((value_expr, range)) = field_map.get(local_field) else {
+                continue 'target;
+            };
+            let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone()) else {
+                continue 'target;
+            };
+            let value_typ = resolve_field_enum_value(db, bean_id, local_field, value_typ);
+            if !is_checkable_literal_key(&value_typ) {
+                continue 'target;
+            }
+
+            report_range = Some(match report_range {
+                Some(prev) if prev.start() <= range.start() => prev,
+                _ => *range,
+            });
+            tuple.push(value_typ);
+        }
+
+        if !pk_index
+            .get_union_locations(&target.table, &tuple)
+            .is_empty()
+        {
+            continue;
+        }
+        let Some(range) = report_range else {
+            continue;
+        };
+
+        let value = tuple
+            .iter()
+            .map(|typ| humanize_type(db, typ, RenderLevel::Simple))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let keys = target
+            .pairs
+            .iter()
+            .map(|(_, key)| key.to_path())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        context.add_diagnostic(
+            DiagnosticCode::InvalidRef,
+            range,
+            t!(
+                "Invalid reference `(%{value})`: not found in `%{table}.(%{keys})`",
+                value = value,
+                table = target.table.get_name(),
+                keys = keys
+            )
+            .to_string(),
+            None,
+        );
+    }
+}
+
 fn validate_container_table_data(
     context: &mut DiagnosticContext,
     db: &crate::DbIndex,
     infer_cache: &mut crate::LuaInferCache,
-    pk_sets: &PkValueSets,
+    pk_index: &ConfigTablePkValueIndex,
     rule: &ContainerRefRule,
     table: &LuaTableExpr,
 ) {
@@ -406,7 +425,7 @@ fn validate_container_table_data(
                     continue;
                 }
 
-                if pk_sets.contains(&value_rule.target_table, &value_rule.target_key, &value_typ) {
+                if pk_index.contains(&value_rule.target_table, &value_rule.target_key, &value_typ) {
                     continue;
                 }
 
@@ -440,7 +459,11 @@ fn validate_container_table_data(
                         infer_key_type_from_index_key(db, infer_cache, &field_key)
                 {
                     if is_checkable_literal_key(&key_typ)
-                        && !pk_sets.contains(&key_rule.target_table, &key_rule.target_key, &key_typ)
+                        && !pk_index.contains(
+                            &key_rule.target_table,
+                            &key_rule.target_key,
+                            &key_typ,
+                        )
                     {
                         let value = humanize_type(db, &key_typ, RenderLevel::Simple);
                         let key_path = key_rule.target_key.to_path();
@@ -465,7 +488,7 @@ fn validate_container_table_data(
                     && let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone())
                 {
                     if is_checkable_literal_key(&value_typ)
-                        && !pk_sets.contains(
+                        && !pk_index.contains(
                             &value_rule.target_table,
                             &value_rule.target_key,
                             &value_typ,
@@ -542,6 +565,30 @@ fn collect_vref_rules_for_bean(
     out
 }
 
+fn collect_vunion_ref_targets_for_bean(
+    db: &crate::DbIndex,
+    bean_id: &LuaTypeDeclId,
+) -> Vec<VUnionRefTarget> {
+    let Some(property) = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(bean_id.clone()))
+    else {
+        return Vec::new();
+    };
+    let Some(file_id) = db
+        .get_type_index()
+        .get_type_decl(bean_id)
+        .and_then(|decl| decl.get_locations().first().map(|loc| loc.file_id))
+    else {
+        return Vec::new();
+    };
+
+    VUnionRefAttribute::find_all_in(property)
+        .iter()
+        .filter_map(|attr| attr.resolve_target(db, file_id, bean_id))
+        .collect()
+}
+
 fn resolve_expected_bean_id(
     db: &crate::DbIndex,
     file_id: crate::FileId,
@@ -8,7 +8,7 @@ use crate::{
     attributes::{SetSpec, SetValue, VSetAttribute},
     diagnostic::checker::{Checker, DiagnosticContext},
     humanize_type, infer_expr, infer_table_should_be,
-    semantic::shared::luaconfig::BEAN,
+    semantic::shared::luaconfig::{BEAN, resolve_field_enum_value},
 };
 
 pub struct InvalidSetValueChecker;
@@ -39,7 +39,14 @@ impl Checker for InvalidSetValueChecker {
                     continue;
                 }
 
-                validate_bean_table_data(context, db, &mut infer_cache, rules, &table_expr);
+                validate_bean_table_data(
+                    context,
+                    db,
+                    &mut infer_cache,
+                    &bean_id,
+                    rules,
+                    &table_expr,
+                );
                 continue;
             }
 
@@ -238,6 +245,7 @@ fn validate_bean_table_data(
     context: &mut DiagnosticContext,
     db: &DbIndex,
     infer_cache: &mut crate::LuaInferCache,
+    bean_id: &LuaTypeDeclId,
     rules: &HashMap<String, SetSpec>,
     table: &LuaTableExpr,
 ) {
@@ -250,7 +258,7 @@ fn validate_bean_table_data(
             continue;
         };
 
-        let LuaMemberKey::Name(name) = member_key else {
+        let LuaMemberKey::Name(name) = &member_key else {
             continue;
         };
 
@@ -265,6 +273,7 @@ fn validate_bean_table_data(
         let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone()) else {
             continue;
         };
+        let value_typ = resolve_field_enum_value(db, bean_id, &member_key, value_typ);
 
         let Some(value) = extract_set_value(&value_typ) else {
             continue;
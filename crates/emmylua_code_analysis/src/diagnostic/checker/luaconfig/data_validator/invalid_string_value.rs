@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{LuaAstNode, LuaTableExpr};
+
+use crate::{
+    DbIndex, DiagnosticCode, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId, SemanticModel,
+    attributes::StringSpec,
+    db_index::LuaSemanticDeclId,
+    diagnostic::checker::{Checker, DiagnosticContext},
+    infer_expr, infer_table_should_be,
+    semantic::shared::luaconfig::BEAN,
+};
+
+pub struct InvalidStringValueChecker;
+
+impl Checker for InvalidStringValueChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::InvalidStringValue];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let db = semantic_model.get_db();
+        let root = semantic_model.get_root().clone();
+
+        let mut infer_cache = semantic_model.get_cache().borrow_mut();
+
+        let mut bean_rules_cache: HashMap<LuaTypeDeclId, HashMap<String, StringSpec>> =
+            HashMap::new();
+        // 同一容器类型的规则只解析一次, 避免每条记录都重新编译正则
+        let mut container_rules_cache: HashMap<LuaType, Option<ContainerStringRule>> =
+            HashMap::new();
+
+        for table_expr in root.descendants::<LuaTableExpr>() {
+            let Ok(table_should_be) =
+                infer_table_should_be(db, &mut infer_cache, table_expr.clone())
+            else {
+                continue;
+            };
+
+            if let Some(bean_id) = resolve_expected_bean_id(db, &table_should_be) {
+                let rules = bean_rules_cache
+                    .entry(bean_id.clone())
+                    .or_insert_with(|| collect_bean_string_rules(db, &bean_id));
+                if rules.is_empty() {
+                    continue;
+                }
+
+                validate_bean_table_data(context, db, &mut infer_cache, rules, &table_expr);
+                continue;
+            }
+
+            if let Some(rule) = container_rules_cache
+                .entry(table_should_be)
+                .or_insert_with_key(resolve_expected_container_rule)
+            {
+                validate_container_table_data(context, db, &mut infer_cache, rule, &table_expr);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ContainerKind {
+    ArrayLike,
+    Map,
+}
+
+#[derive(Debug, Clone)]
+struct ContainerStringRule {
+    kind: ContainerKind,
+    key: Option<StringSpec>,
+    value: Option<StringSpec>,
+}
+
+fn resolve_expected_container_rule(ty: &LuaType) -> Option<ContainerStringRule> {
+    let ty = ty.strip_attributed();
+    match ty {
+        LuaType::Generic(generic) => {
+            let base_name = generic.get_base_type_id_ref().get_name();
+            let params = generic.get_params();
+
+            match base_name {
+                "array" | "list" | "set" => {
+                    let element_ty = params.first()?;
+                    let value = extract_string_spec_from_type(element_ty)?;
+                    Some(ContainerStringRule {
+                        kind: ContainerKind::ArrayLike,
+                        key: None,
+                        value: Some(value),
+                    })
+                }
+                "map" => {
+                    let key_ty = params.first()?;
+                    let value_ty = params.get(1)?;
+                    let key = extract_string_spec_from_type(key_ty);
+                    let value = extract_string_spec_from_type(value_ty);
+                    if key.is_none() && value.is_none() {
+                        return None;
+                    }
+                    Some(ContainerStringRule {
+                        kind: ContainerKind::Map,
+                        key,
+                        value,
+                    })
+                }
+                _ => None,
+            }
+        }
+        LuaType::Array(array) => {
+            let value = extract_string_spec_from_type(array.get_base())?;
+            Some(ContainerStringRule {
+                kind: ContainerKind::ArrayLike,
+                key: None,
+                value: Some(value),
+            })
+        }
+        LuaType::Union(union) => {
+            let mut found: Option<ContainerStringRule> = None;
+            for inner in union.into_vec().iter() {
+                let Some(rule) = resolve_expected_container_rule(inner) else {
+                    continue;
+                };
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(rule);
+            }
+            found
+        }
+        LuaType::MultiLineUnion(multi) => {
+            let union = multi.to_union();
+            resolve_expected_container_rule(&union)
+        }
+        _ => None,
+    }
+}
+
+fn validate_container_table_data(
+    context: &mut DiagnosticContext,
+    db: &DbIndex,
+    infer_cache: &mut crate::LuaInferCache,
+    rule: &ContainerStringRule,
+    table: &LuaTableExpr,
+) {
+    for field in table.get_fields() {
+        if let ContainerKind::Map = rule.kind
+            && let Some(key_spec) = rule.key.as_ref()
+            && let Some(field_key) = field.get_field_key()
+            && let Ok(member_key) = LuaMemberKey::from_index_key(db, infer_cache, &field_key)
+        {
+            let key_value = match &member_key {
+                LuaMemberKey::Name(name) => Some(name.to_string()),
+                LuaMemberKey::ExprType(ty) => extract_string_value(ty),
+                _ => None,
+            };
+
+            if let Some(key_value) = key_value
+                && let Some(violation) = key_spec.check(&key_value)
+            {
+                context.add_diagnostic(
+                    DiagnosticCode::InvalidStringValue,
+                    field.get_range(),
+                    t!(
+                        "Map key: %{reason}",
+                        reason = violation.to_message(&key_value)
+                    )
+                    .to_string(),
+                    None,
+                );
+            }
+        }
+
+        let Some(value_spec) = rule.value.as_ref() else {
+            continue;
+        };
+
+        let Some(value_expr) = field.get_value_expr() else {
+            continue;
+        };
+
+        let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone()) else {
+            continue;
+        };
+
+        let Some(value) = extract_string_value(&value_typ) else {
+            continue;
+        };
+
+        if let Some(violation) = value_spec.check(&value) {
+            context.add_diagnostic(
+                DiagnosticCode::InvalidStringValue,
+                value_expr.get_range(),
+                violation.to_message(&value),
+                None,
+            );
+        }
+    }
+}
+
+fn validate_bean_table_data(
+    context: &mut DiagnosticContext,
+    db: &DbIndex,
+    infer_cache: &mut crate::LuaInferCache,
+    rules: &HashMap<String, StringSpec>,
+    table: &LuaTableExpr,
+) {
+    for field in table.get_fields() {
+        let Some(field_key) = field.get_field_key() else {
+            continue;
+        };
+
+        let Ok(member_key) = LuaMemberKey::from_index_key(db, infer_cache, &field_key) else {
+            continue;
+        };
+
+        let LuaMemberKey::Name(name) = member_key else {
+            continue;
+        };
+
+        let Some(spec) = rules.get(name.as_str()) else {
+            continue;
+        };
+
+        let Some(value_expr) = field.get_value_expr() else {
+            continue;
+        };
+
+        let Ok(value_typ) = infer_expr(db, infer_cache, value_expr.clone()) else {
+            continue;
+        };
+
+        let Some(value) = extract_string_value(&value_typ) else {
+            continue;
+        };
+
+        if let Some(violation) = spec.check(&value) {
+            context.add_diagnostic(
+                DiagnosticCode::InvalidStringValue,
+                value_expr.get_range(),
+                t!(
+                    "Field `%{field}`: %{reason}",
+                    field = name.as_str(),
+                    reason = violation.to_message(&value)
+                )
+                .to_string(),
+                None,
+            );
+        }
+    }
+}
+
+/// 字段上的规则可以写在 `---@field` 前, 也可以写在字段类型上
+fn collect_bean_string_rules(db: &DbIndex, bean_id: &LuaTypeDeclId) -> HashMap<String, StringSpec> {
+    let mut out: HashMap<String, StringSpec> = HashMap::new();
+
+    let Some(bean_members) = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(bean_id.clone()))
+    else {
+        return out;
+    };
+
+    for member in bean_members {
+        let LuaMemberKey::Name(name) = member.get_key() else {
+            continue;
+        };
+
+        let mut spec = db
+            .get_type_index()
+            .get_type_cache(&member.get_id().into())
+            .and_then(|type_cache| extract_string_spec_from_type(type_cache.as_type()))
+            .unwrap_or_default();
+
+        let owner_id = LuaSemanticDeclId::Member(member.get_id());
+        if let Some(property) = db.get_property_index().get_property(&owner_id)
+            && let Some(attribute_uses) = property.attribute_uses()
+        {
+            spec.extend_from_uses(attribute_uses);
+        }
+
+        if spec.is_empty() {
+            continue;
+        }
+
+        out.insert(name.to_string(), spec);
+    }
+
+    out
+}
+
+fn extract_string_spec_from_type(ty: &LuaType) -> Option<StringSpec> {
+    match ty {
+        LuaType::Attributed(attributed) => {
+            let mut spec = extract_string_spec_from_type(attributed.get_base()).unwrap_or_default();
+            spec.extend_from_uses(attributed.get_attributes().as_ref());
+            (!spec.is_empty()).then_some(spec)
+        }
+        LuaType::Union(union) => union
+            .into_vec()
+            .iter()
+            .find_map(extract_string_spec_from_type),
+        LuaType::MultiLineUnion(multi) => {
+            let union = multi.to_union();
+            extract_string_spec_from_type(&union)
+        }
+        _ => None,
+    }
+}
+
+fn extract_string_value(ty: &LuaType) -> Option<String> {
+    match ty {
+        LuaType::Attributed(attributed) => extract_string_value(attributed.get_base()),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => Some(s.as_ref().to_string()),
+        LuaType::Union(union) => {
+            let mut found: Option<String> = None;
+            for inner in union.into_vec().iter() {
+                let Some(v) = extract_string_value(inner) else {
+                    continue;
+                };
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(v);
+            }
+            found
+        }
+        _ => None,
+    }
+}
+
+fn resolve_expected_bean_id(db: &DbIndex, ty: &LuaType) -> Option<LuaTypeDeclId> {
+    let ty = ty.strip_attributed();
+    match ty {
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => {
+            if BEAN.is_bean(db, type_decl_id) {
+                Some(type_decl_id.clone())
+            } else {
+                None
+            }
+        }
+        LuaType::Generic(generic) => {
+            let base_type_id = generic.get_base_type_id();
+            if BEAN.is_bean(db, &base_type_id) {
+                Some(base_type_id)
+            } else {
+                None
+            }
+        }
+        LuaType::Union(union) => {
+            let mut bean_ids: Vec<LuaTypeDeclId> = Vec::new();
+            for inner in union.into_vec().iter() {
+                if let Some(bean_id) = resolve_expected_bean_id(db, inner)
+                    && !bean_ids.contains(&bean_id)
+                {
+                    bean_ids.push(bean_id);
+                }
+            }
+
+            if bean_ids.len() == 1 {
+                bean_ids.into_iter().next()
+            } else {
+                None
+            }
+        }
+        LuaType::MultiLineUnion(multi) => {
+            let union = multi.to_union();
+            resolve_expected_bean_id(db, &union)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{LuaAstNode, LuaTableExpr};
+
+use crate::{
+    DiagnosticCode, LuaTypeDeclId, SemanticModel,
+    attributes::WeightKind,
+    diagnostic::checker::{Checker, DiagnosticContext},
+    infer_table_should_be,
+    semantic::shared::luaconfig::{
+        WeightRule, collect_weight_groups, find_weight_records, format_weight_value,
+        get_weight_rules,
+    },
+};
+
+/// 检查 `v.weight_sum`/`v.prob_sum`: 单条记录的权重或概率是否合法, 同组记录之和是否符合要求
+///
+/// `list<Bean>` 等容器的每个值单独检查, 配置表的记录跨文件整体检查.
+pub struct InvalidWeightSumChecker;
+
+impl Checker for InvalidWeightSumChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::InvalidWeightSum];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let db = semantic_model.get_db();
+        let file_id = semantic_model.get_file_id();
+        let root = semantic_model.get_root().clone();
+
+        let mut infer_cache = semantic_model.get_cache().borrow_mut();
+
+        let mut rules_cache: HashMap<LuaTypeDeclId, Vec<WeightRule>> = HashMap::new();
+
+        for table_expr in root.descendants::<LuaTableExpr>() {
+            let Ok(table_should_be) =
+                infer_table_should_be(db, &mut infer_cache, table_expr.clone())
+            else {
+                continue;
+            };
+
+            let Some((bean_id, rows)) =
+                find_weight_records(db, file_id, &table_expr, &table_should_be)
+            else {
+                continue;
+            };
+            let rules = rules_cache
+                .entry(bean_id.clone())
+                .or_insert_with(|| get_weight_rules(db, &bean_id));
+            if rules.is_empty() {
+                continue;
+            }
+
+            let table_range = table_expr.get_range();
+            for rule in rules.iter() {
+                for group in collect_weight_groups(db, rule, &rows) {
+                    let mut in_table = false;
+                    for entry in &group.entries {
+                        if entry.file_id != file_id || !table_range.contains_range(entry.row_range)
+                        {
+                            continue;
+                        }
+                        in_table = true;
+
+                        if rule.spec.is_valid_value(entry.value) {
+                            continue;
+                        }
+                        let message = match rule.spec.kind {
+                            WeightKind::Weight { .. } => t!(
+                                "Weight `%{field}` must be positive, got %{value}",
+                                field = rule.field,
+                                value = format_weight_value(entry.value)
+                            ),
+                            WeightKind::Prob => t!(
+                                "Probability `%{field}` must be within [0, 1], got %{value}",
+                                field = rule.field,
+                                value = format_weight_value(entry.value)
+                            ),
+                        };
+                        context.add_diagnostic(
+                            DiagnosticCode::InvalidWeightSum,
+                            entry.value_range.unwrap_or(entry.row_range),
+                            message.to_string(),
+                            None,
+                        );
+                    }
+
+                    if !in_table || rule.spec.is_valid_sum(group.sum) {
+                        continue;
+                    }
+                    let message = match &group.key {
+                        Some(key) => t!(
+                            "Sum of `%{field}` in group `%{group}` is %{sum}, expected %{expected}",
+                            field = rule.field,
+                            group = key,
+                            sum = format_weight_value(group.sum),
+                            expected = format_weight_value(rule.spec.expected_sum())
+                        ),
+                        None => t!(
+                            "Sum of `%{field}` is %{sum}, expected %{expected}",
+                            field = rule.field,
+                            sum = format_weight_value(group.sum),
+                            expected = format_weight_value(rule.spec.expected_sum())
+                        ),
+                    };
+                    context.add_diagnostic(
+                        DiagnosticCode::InvalidWeightSum,
+                        table_range,
+                        message.to_string(),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet};
+
+use emmylua_parser::{LuaAstNode, LuaTableExpr};
+use itertools::Itertools;
+
+use crate::{
+    DbIndex, DiagnosticCode, LuaMemberOwner, LuaType, LuaTypeDeclId, SemanticModel,
+    diagnostic::checker::{Checker, DiagnosticContext},
+    infer_table_should_be,
+    semantic::shared::luaconfig::BEAN,
+};
+
+/// 检查 Bean 数据是否缺少必填字段
+///
+/// 类型可为空或带有 `v.default` 的字段可以省略, 其余字段必须出现在数据中.
+pub struct MissingRequiredFieldChecker;
+
+impl Checker for MissingRequiredFieldChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::MissingRequiredField];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let db = semantic_model.get_db();
+        let root = semantic_model.get_root().clone();
+
+        let mut infer_cache = semantic_model.get_cache().borrow_mut();
+
+        let mut required_cache: HashMap<LuaTypeDeclId, HashSet<String>> = HashMap::new();
+
+        for table_expr in root.descendants::<LuaTableExpr>() {
+            let Ok(table_should_be) =
+                infer_table_should_be(db, &mut infer_cache, table_expr.clone())
+            else {
+                continue;
+            };
+
+            let Some(bean_id) = resolve_expected_bean_id(db, &table_should_be) else {
+                continue;
+            };
+
+            let required_fields = required_cache
+                .entry(bean_id.clone())
+                .or_insert_with(|| collect_required_fields(db, &bean_id));
+            if required_fields.is_empty() {
+                continue;
+            }
+
+            // 按位置填写的表不是 Bean 数据
+            if table_expr.get_fields().any(|field| field.is_value_field()) {
+                continue;
+            }
+            let current_fields: HashSet<String> = table_expr
+                .get_fields()
+                .filter_map(|field| field.get_field_key().map(|key| key.get_path_part()))
+                .collect();
+
+            let missing_fields = required_fields
+                .difference(&current_fields)
+                .sorted()
+                .map(|name| format!("`{}`", name))
+                .join(", ");
+            if missing_fields.is_empty() {
+                continue;
+            }
+
+            context.add_diagnostic(
+                DiagnosticCode::MissingRequiredField,
+                table_expr.get_range(),
+                t!(
+                    "Missing required fields in `%{bean}`: %{fields}",
+                    bean = bean_id.get_name(),
+                    fields = missing_fields
+                )
+                .to_string(),
+                None,
+            );
+        }
+    }
+}
+
+/// 子类中的字段优先于父类中的同名字段
+fn collect_required_fields(db: &DbIndex, bean_id: &LuaTypeDeclId) -> HashSet<String> {
+    let mut required_fields = HashSet::new();
+    let mut visited = HashSet::new();
+
+    let types = bean_id.collect_super_types_with_self(db, LuaType::Ref(bean_id.clone()));
+    for typ in types {
+        let LuaType::Ref(type_id) = typ else {
+            continue;
+        };
+        let Some(members) = db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::Type(type_id))
+        else {
+            continue;
+        };
+
+        for member in members {
+            let name = member.get_key().to_path();
+            if name.is_empty() || !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let is_optional = db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())
+                .is_none_or(|type_cache| {
+                    let typ = type_cache.as_type();
+                    typ.is_nullable() || typ.is_any()
+                })
+                || BEAN.get_field_default(db, &member.get_id()).is_some();
+            if !is_optional {
+                required_fields.insert(name);
+            }
+        }
+    }
+
+    required_fields
+}
+
+fn resolve_expected_bean_id(db: &DbIndex, ty: &LuaType) -> Option<LuaTypeDeclId> {
+    let ty = ty.strip_attributed();
+    match ty {
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => {
+            if BEAN.is_bean(db, type_decl_id) {
+                Some(type_decl_id.clone())
+            } else {
+                None
+            }
+        }
+        LuaType::Union(union) => {
+            let mut bean_ids: Vec<LuaTypeDeclId> = Vec::new();
+            for inner in union.into_vec().iter() {
+                if let Some(bean_id) = resolve_expected_bean_id(db, inner)
+                    && !bean_ids.contains(&bean_id)
+                {
+                    bean_ids.push(bean_id);
+                }
+            }
+
+            if bean_ids.len() == 1 {
+                bean_ids.into_iter().next()
+            } else {
+                None
+            }
+        }
+        LuaType::MultiLineUnion(multi) => {
+            let union = multi.to_union();
+            resolve_expected_bean_id(db, &union)
+        }
+        _ => None,
+    }
+}
@@ -1,8 +1,15 @@
 pub mod duplicate_index_value;
 pub mod duplicate_primary_key;
 pub mod duplicate_set_element;
+pub mod duplicate_unique_value;
+pub mod enum_coverage;
+pub mod invalid_graph;
 pub mod invalid_index_field;
 pub mod invalid_range_value;
 pub mod invalid_ref;
 pub mod invalid_set_value;
 pub mod invalid_size_value;
+pub mod invalid_string_value;
+pub mod invalid_weight_sum;
+pub mod missing_required_field;
+pub mod unknown_enum_name;
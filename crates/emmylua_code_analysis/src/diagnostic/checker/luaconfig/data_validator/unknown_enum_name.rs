@@ -0,0 +1,101 @@
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaLiteralToken, LuaTableExpr};
+
+use crate::{
+    DbIndex, DiagnosticCode, LuaMemberKey, LuaType, LuaTypeDeclId, SemanticModel,
+    diagnostic::checker::{Checker, DiagnosticContext},
+    infer_table_should_be,
+    semantic::shared::luaconfig::{
+        BEAN, find_named_enum, get_enum_container_element, resolve_enum_name,
+    },
+};
+
+/// 检查 Bean 数据中按名称书写的枚举值是否为枚举成员
+pub struct UnknownEnumNameChecker;
+
+impl Checker for UnknownEnumNameChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UnknownEnumName];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let db = semantic_model.get_db();
+        let root = semantic_model.get_root().clone();
+
+        for table_expr in root.descendants::<LuaTableExpr>() {
+            let table_should_be = {
+                let mut infer_cache = semantic_model.get_cache().borrow_mut();
+                match infer_table_should_be(db, &mut infer_cache, table_expr.clone()) {
+                    Ok(ty) => ty,
+                    Err(_) => continue,
+                }
+            };
+
+            let (LuaType::Ref(bean_id) | LuaType::Def(bean_id)) =
+                table_should_be.strip_attributed()
+            else {
+                continue;
+            };
+            if !BEAN.is_bean(db, bean_id) {
+                continue;
+            }
+
+            let bean_type = LuaType::Ref(bean_id.clone());
+            for field in table_expr.get_fields() {
+                let Some(field_key) = field.get_field_key() else {
+                    continue;
+                };
+                let Some(member_key @ LuaMemberKey::Name(_)) =
+                    semantic_model.get_member_key(&field_key)
+                else {
+                    continue;
+                };
+                let Some(value_expr) = field.get_value_expr() else {
+                    continue;
+                };
+                let Ok(field_type) = semantic_model.infer_member_type(&bean_type, &member_key)
+                else {
+                    continue;
+                };
+
+                if let Some(enum_id) = find_named_enum(db, &field_type) {
+                    check_enum_name(context, db, &enum_id, &value_expr);
+                } else if let Some(element_type) = get_enum_container_element(db, &field_type)
+                    && let Some(enum_id) = find_named_enum(db, &element_type)
+                    && let LuaExpr::TableExpr(elements) = &value_expr
+                {
+                    for element in elements.get_fields() {
+                        if let Some(element_expr) = element.get_value_expr() {
+                            check_enum_name(context, db, &enum_id, &element_expr);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_enum_name(
+    context: &mut DiagnosticContext,
+    db: &DbIndex,
+    enum_id: &LuaTypeDeclId,
+    value_expr: &LuaExpr,
+) {
+    let LuaExpr::LiteralExpr(literal_expr) = value_expr else {
+        return;
+    };
+    let Some(LuaLiteralToken::String(string_token)) = literal_expr.get_literal() else {
+        return;
+    };
+
+    if let Err(name) = resolve_enum_name(db, enum_id, &string_token.get_value()) {
+        context.add_diagnostic(
+            DiagnosticCode::UnknownEnumName,
+            value_expr.get_range(),
+            t!(
+                "`%{name}` is not a member of enum `%{enum_name}`",
+                name = name,
+                enum_name = enum_id.get_name()
+            )
+            .to_string(),
+            None,
+        );
+    }
+}
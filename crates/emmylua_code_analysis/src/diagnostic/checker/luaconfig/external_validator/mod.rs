@@ -0,0 +1,329 @@
+mod protocol;
+mod runner;
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, LazyLock, Mutex},
+};
+
+use rowan::TextRange;
+use serde_json::Value;
+
+use crate::{
+    ConfigValue, ConfigValueNode, DbIndex, DiagnosticCode, EmmyrcExternalValidator, FileId,
+    LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId, RenderLevel, SemanticModel,
+    diagnostic::checker::{Checker, DiagnosticContext},
+    humanize_type,
+    semantic::shared::luaconfig::CONFIG_TABLE,
+};
+
+use protocol::{
+    PROTOCOL_VERSION, ValidatorField, ValidatorPathSegment, ValidatorRequest, ValidatorRow,
+    ValidatorTable,
+};
+
+/// 调用 `diagnostics.externalValidators` 中配置的外部程序校验配置表数据
+///
+/// 校验器从 stdin 读取所选配置表的结构与全部记录, 向 stdout 输出按记录下标与字段路径定位的诊断,
+/// 诊断代码为 `external-validator/<name>/<rule>`.
+pub struct ExternalValidatorChecker;
+
+impl Checker for ExternalValidatorChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::ExternalValidator];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let validators = context.config.external_validators.clone();
+        if validators.is_empty() || !context.config.external_validators_trusted {
+            return;
+        }
+
+        let file_id = semantic_model.get_file_id();
+        let db = semantic_model.get_db();
+        let Some(occurrences) = db
+            .get_config_index()
+            .get_config_table_data_occurrences(&file_id)
+        else {
+            return;
+        };
+
+        for validator in &validators {
+            let current_data: Vec<TextRange> = occurrences
+                .iter()
+                .filter(|occ| {
+                    validator
+                        .tables
+                        .iter()
+                        .any(|name| name == occ.config_table.get_name())
+                })
+                .map(|occ| occ.syntax_id.get_range())
+                .collect();
+            if current_data.is_empty() {
+                continue;
+            }
+
+            let request = prepare_request(db, validator);
+            let result = match &request.input {
+                Ok(input) => runner::run_validator(validator, input),
+                Err(message) => Err(message.clone()),
+            };
+            let response = match result {
+                Ok(response) => response,
+                Err(message) => {
+                    for range in current_data {
+                        context.add_external_diagnostic(
+                            &validator.name,
+                            range,
+                            message.clone(),
+                            None,
+                        );
+                    }
+                    continue;
+                }
+            };
+
+            for diagnostic in response.diagnostics {
+                let Some(source) = request
+                    .sources
+                    .get(&diagnostic.table)
+                    .and_then(|rows| rows.get(diagnostic.row))
+                else {
+                    continue;
+                };
+                if source.file_id != file_id {
+                    continue;
+                }
+                let Some(row) = db
+                    .get_config_index()
+                    .find_config_table_row_at(&file_id, source.range.start())
+                else {
+                    continue;
+                };
+                let name = match &diagnostic.rule {
+                    Some(rule) => format!("{}/{}", validator.name, rule),
+                    None => validator.name.clone(),
+                };
+                context.add_external_diagnostic(
+                    &name,
+                    resolve_path_range(&row.value, &diagnostic.path),
+                    diagnostic.message,
+                    diagnostic.severity.map(Into::into),
+                );
+            }
+        }
+    }
+}
+
+/// 记录在源码中的位置, 用于将诊断映射回源码
+struct RowSource {
+    file_id: FileId,
+    range: TextRange,
+}
+
+/// 序列化后的请求与各配置表记录的位置
+struct PreparedRequest {
+    generation: u64,
+    tables: Vec<String>,
+    input: Result<String, String>,
+    sources: HashMap<String, Vec<RowSource>>,
+}
+
+/// 以校验器名称为键缓存请求, 同一分析代数内诊断各文件时只构建一次
+static REQUEST_CACHE: LazyLock<Mutex<HashMap<String, Arc<PreparedRequest>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn prepare_request(db: &DbIndex, validator: &EmmyrcExternalValidator) -> Arc<PreparedRequest> {
+    let generation = db.get_generation();
+    // 持有锁构建请求, 并行诊断的其他文件等待并复用同一个请求
+    let mut cache = REQUEST_CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(request) = cache.get(&validator.name)
+        && request.generation == generation
+        && request.tables == validator.tables
+    {
+        return request.clone();
+    }
+
+    let (request, sources) = build_request(db, validator);
+    let request = Arc::new(PreparedRequest {
+        generation,
+        tables: validator.tables.clone(),
+        input: serde_json::to_string(&request).map_err(|err| err.to_string()),
+        sources,
+    });
+    cache.insert(validator.name.clone(), request.clone());
+    request
+}
+
+fn build_request(
+    db: &DbIndex,
+    validator: &EmmyrcExternalValidator,
+) -> (ValidatorRequest, HashMap<String, Vec<RowSource>>) {
+    let vfs = db.get_vfs();
+    let config_index = db.get_config_index();
+    let mut rows = Vec::new();
+    for name in &validator.tables {
+        let config_table = LuaTypeDeclId::new(name);
+        for (file_id, row) in config_index.iter_config_table_rows(&config_table) {
+            if let Some(path) = vfs.get_file_path(&file_id) {
+                rows.push((path, file_id, row));
+            }
+        }
+    }
+    // 保证不同文件的记录顺序稳定, 以便复用上一次的结果
+    rows.sort_by_key(|(path, _, row)| (*path, row.range.start()));
+
+    let mut tables: Vec<ValidatorTable> = Vec::new();
+    let mut sources: HashMap<String, Vec<RowSource>> = HashMap::new();
+    for (path, file_id, row) in rows {
+        let table_name = row.config_table.get_name().to_string();
+        let index = match tables.iter().position(|table| table.name == table_name) {
+            Some(index) => index,
+            None => {
+                tables.push(build_table_schema(db, &row.config_table));
+                tables.len() - 1
+            }
+        };
+
+        let line = vfs
+            .get_document(&file_id)
+            .and_then(|document| document.get_line_col(row.range.start()))
+            .map(|(line, _)| line as u32 + 1)
+            .unwrap_or(0);
+        tables[index].rows.push(ValidatorRow {
+            key: key_to_json(&row.key),
+            file: path.to_string_lossy().to_string(),
+            line,
+            data: value_to_json(&row.value.value),
+        });
+        sources.entry(table_name).or_default().push(RowSource {
+            file_id,
+            range: row.range,
+        });
+    }
+
+    (
+        ValidatorRequest {
+            version: PROTOCOL_VERSION,
+            tables,
+        },
+        sources,
+    )
+}
+
+fn build_table_schema(db: &DbIndex, config_table: &LuaTypeDeclId) -> ValidatorTable {
+    let bean_id = CONFIG_TABLE.get_bean_id(db, config_table);
+    let keys = db
+        .get_config_index()
+        .get_config_table_keys(config_table)
+        .map(|keys| keys.keys().iter().map(|key| key.to_path()).collect())
+        .unwrap_or_default();
+
+    // 子类中的字段优先于父类中的同名字段
+    let mut fields = Vec::new();
+    if let Some(bean_id) = &bean_id {
+        let mut visited = HashSet::new();
+        for typ in bean_id.collect_super_types_with_self(db, LuaType::Ref(bean_id.clone())) {
+            let LuaType::Ref(type_id) = typ else {
+                continue;
+            };
+            let Some(members) = db
+                .get_member_index()
+                .get_members(&LuaMemberOwner::Type(type_id))
+            else {
+                continue;
+            };
+            for member in members {
+                let name = member.get_key().to_path();
+                if name.is_empty() || !visited.insert(name.clone()) {
+                    continue;
+                }
+                let typ = db
+                    .get_type_index()
+                    .get_type_cache(&member.get_id().into())
+                    .map(|type_cache| type_cache.as_type().clone())
+                    .unwrap_or(LuaType::Any);
+                fields.push(ValidatorField {
+                    name,
+                    typ: humanize_type(db, &typ, RenderLevel::Simple),
+                    optional: typ.is_nullable(),
+                });
+            }
+        }
+    }
+
+    ValidatorTable {
+        name: config_table.get_name().to_string(),
+        bean: bean_id.map(|id| id.get_name().to_string()),
+        keys,
+        fields,
+        rows: Vec::new(),
+    }
+}
+
+fn key_to_json(key: &LuaMemberKey) -> Value {
+    match key {
+        LuaMemberKey::Name(name) => Value::from(name.as_str()),
+        LuaMemberKey::Integer(i) => Value::from(*i),
+        LuaMemberKey::ExprType(typ) => ConfigValue::from_type(typ)
+            .map(|value| value_to_json(&value))
+            .unwrap_or(Value::Null),
+        LuaMemberKey::None => Value::Null,
+    }
+}
+
+/// 按位置填写的表转为数组, 其余的表转为对象, 非常量的值转为 `null`
+fn value_to_json(value: &ConfigValue) -> Value {
+    match value {
+        ConfigValue::Boolean(b) => Value::from(*b),
+        ConfigValue::Integer(i) => Value::from(*i),
+        ConfigValue::Float(f) => Value::from(*f),
+        ConfigValue::String(s) => Value::from(s.as_str()),
+        ConfigValue::Table { entries, .. } => {
+            if entries.iter().all(|entry| entry.key_range.is_none()) {
+                return Value::Array(
+                    entries
+                        .iter()
+                        .map(|entry| value_to_json(&entry.value.value))
+                        .collect(),
+                );
+            }
+
+            let mut object = serde_json::Map::new();
+            for entry in entries.iter() {
+                let key = match key_to_json(&entry.key) {
+                    Value::String(key) => key,
+                    key => key.to_string(),
+                };
+                object.insert(key, value_to_json(&entry.value.value));
+            }
+            Value::Object(object)
+        }
+        ConfigValue::Nil | ConfigValue::Unknown => Value::Null,
+    }
+}
+
+/// 沿字段路径查找诊断位置, 路径无法继续时使用已找到的最深节点
+fn resolve_path_range(node: &ConfigValueNode, path: &[ValidatorPathSegment]) -> TextRange {
+    let mut current = node;
+    for segment in path {
+        let Some(entry) = current
+            .value
+            .get_entries()
+            .iter()
+            .find(|entry| is_path_segment(&entry.key, segment))
+        else {
+            break;
+        };
+        current = &entry.value;
+    }
+    current.range
+}
+
+fn is_path_segment(key: &LuaMemberKey, segment: &ValidatorPathSegment) -> bool {
+    match (key, segment) {
+        (LuaMemberKey::Name(name), ValidatorPathSegment::Name(expected)) => name == expected,
+        (LuaMemberKey::Integer(i), ValidatorPathSegment::Index(expected)) => i == expected,
+        _ => false,
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::DiagnosticSeveritySetting;
+
+/// 外部校验器协议版本
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// 发送给外部校验器的数据, 通过 stdin 以 JSON 传递
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorRequest {
+    pub version: u32,
+    pub tables: Vec<ValidatorTable>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorTable {
+    /// 配置表名
+    pub name: String,
+    /// 记录类型名
+    pub bean: Option<String>,
+    /// 主键字段
+    pub keys: Vec<String>,
+    pub fields: Vec<ValidatorField>,
+    /// 所有文件中的记录, 下标即为 `row`
+    pub rows: Vec<ValidatorRow>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub typ: String,
+    pub optional: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorRow {
+    /// 记录在数据表中的键
+    pub key: Value,
+    pub file: String,
+    /// 记录所在行, 从 1 开始
+    pub line: u32,
+    pub data: Value,
+}
+
+/// 外部校验器的输出, 从 stdout 以 JSON 读取
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorResponse {
+    #[serde(default)]
+    pub diagnostics: Vec<ValidatorDiagnostic>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorDiagnostic {
+    /// 配置表名
+    pub table: String,
+    /// 记录下标, 对应请求中的 `rows`
+    pub row: usize,
+    /// 记录内的字段路径, 字段名或从 1 开始的位置
+    #[serde(default)]
+    pub path: Vec<ValidatorPathSegment>,
+    pub message: String,
+    #[serde(default)]
+    pub rule: Option<String>,
+    #[serde(default)]
+    pub severity: Option<DiagnosticSeveritySetting>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ValidatorPathSegment {
+    Index(i64),
+    Name(String),
+}
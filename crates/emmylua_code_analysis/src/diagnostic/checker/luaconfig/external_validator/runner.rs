@@ -0,0 +1,144 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{Read, Write},
+    process::{Command, Stdio},
+    sync::{Arc, LazyLock, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::EmmyrcExternalValidator;
+
+use super::protocol::ValidatorResponse;
+
+type ValidatorResult = Result<ValidatorResponse, String>;
+
+/// 校验器最近一次的输入哈希与结果
+type ValidatorCacheEntry = Arc<Mutex<Option<(u64, ValidatorResult)>>>;
+
+/// 以校验器名称为键缓存最近一次的结果, 输入不变时各文件共享同一次执行
+static VALIDATOR_CACHE: LazyLock<Mutex<HashMap<String, ValidatorCacheEntry>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 执行外部校验器, 输入相同时直接返回缓存的结果
+///
+/// 检查缓存与执行期间持有该校验器的锁, 并行诊断的多个文件只会执行一次, 不同校验器互不阻塞
+pub fn run_validator(validator: &EmmyrcExternalValidator, input: &str) -> ValidatorResult {
+    let mut hasher = DefaultHasher::new();
+    validator.program.hash(&mut hasher);
+    validator.args.hash(&mut hasher);
+    validator.working_dir.hash(&mut hasher);
+    input.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    let entry = VALIDATOR_CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .entry(validator.name.clone())
+        .or_default()
+        .clone();
+    let mut cached = entry
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some((cached_hash, result)) = cached.as_ref()
+        && *cached_hash == hash
+    {
+        return result.clone();
+    }
+
+    let result = execute(validator, input);
+    *cached = Some((hash, result.clone()));
+    result
+}
+
+fn execute(validator: &EmmyrcExternalValidator, input: &str) -> ValidatorResult {
+    let mut command = Command::new(&validator.program);
+    if let Some(working_dir) = &validator.working_dir {
+        command.current_dir(working_dir);
+    }
+    let mut child = command
+        .args(&validator.args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| {
+            t!(
+                "Failed to start external validator `%{name}`: %{err}",
+                name = validator.name,
+                err = err
+            )
+            .to_string()
+        })?;
+
+    // stdin 与 stdout 分别在独立线程中读写, 避免管道写满后互相阻塞
+    let stdin = child.stdin.take();
+    let input = input.to_string();
+    thread::spawn(move || {
+        if let Some(mut stdin) = stdin {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+    });
+    let stdout = child.stdout.take().map(read_in_background);
+    let stderr = child.stderr.take().map(read_in_background);
+
+    let deadline = Instant::now() + Duration::from_millis(validator.timeout);
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(t!(
+                    "External validator `%{name}` timed out after %{timeout}ms",
+                    name = validator.name,
+                    timeout = validator.timeout
+                )
+                .to_string());
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(10)),
+            Err(err) => {
+                return Err(t!(
+                    "External validator `%{name}` failed: %{err}",
+                    name = validator.name,
+                    err = err
+                )
+                .to_string());
+            }
+        }
+    };
+
+    let stdout = stdout
+        .and_then(|handle| handle.join().ok())
+        .unwrap_or_default();
+    if !status.success() {
+        let stderr = stderr
+            .and_then(|handle| handle.join().ok())
+            .unwrap_or_default();
+        return Err(t!(
+            "External validator `%{name}` failed (%{status}): %{stderr}",
+            name = validator.name,
+            status = status,
+            stderr = stderr.trim()
+        )
+        .to_string());
+    }
+
+    serde_json::from_str(&stdout).map_err(|err| {
+        t!(
+            "Invalid output from external validator `%{name}`: %{err}",
+            name = validator.name,
+            err = err
+        )
+        .to_string()
+    })
+}
+
+fn read_in_background<R: Read + Send + 'static>(mut reader: R) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = String::new();
+        let _ = reader.read_to_string(&mut output);
+        output
+    })
+}
@@ -5,21 +5,38 @@ use crate::{
 
 mod attribute;
 mod data_validator;
+mod external_validator;
 
 pub fn check_luaconfig(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
     run_check::<data_validator::duplicate_primary_key::DuplicatePrimaryKeyChecker>(
         context,
         semantic_model,
     );
+    run_check::<data_validator::duplicate_unique_value::DuplicateUniqueValueChecker>(
+        context,
+        semantic_model,
+    );
     run_check::<data_validator::invalid_index_field::InvalidIndexFieldChecker>(
         context,
         semantic_model,
     );
     run_check::<attribute::flags_enum_value::FlagsEnumValueChecker>(context, semantic_model);
     run_check::<attribute::vref_signature::VRefSignatureChecker>(context, semantic_model);
+    run_check::<attribute::vunion_ref_signature::VUnionRefSignatureChecker>(
+        context,
+        semantic_model,
+    );
     run_check::<attribute::vrange_signature::VRangeSignatureChecker>(context, semantic_model);
     run_check::<attribute::vsize_signature::VSizeSignatureChecker>(context, semantic_model);
     run_check::<attribute::vset_signature::VSetSignatureChecker>(context, semantic_model);
+    run_check::<attribute::vdefault_signature::VDefaultSignatureChecker>(context, semantic_model);
+    run_check::<attribute::vstring_signature::VStringSignatureChecker>(context, semantic_model);
+    run_check::<attribute::vweight_signature::VWeightSignatureChecker>(context, semantic_model);
+    run_check::<attribute::graph_signature::GraphSignatureChecker>(context, semantic_model);
+    run_check::<data_validator::missing_required_field::MissingRequiredFieldChecker>(
+        context,
+        semantic_model,
+    );
     run_check::<data_validator::invalid_ref::InvalidRefChecker>(context, semantic_model);
     run_check::<data_validator::invalid_range_value::InvalidRangeValueChecker>(
         context,
@@ -30,6 +47,10 @@ pub fn check_luaconfig(context: &mut DiagnosticContext, semantic_model: &Semanti
         semantic_model,
     );
     run_check::<data_validator::invalid_set_value::InvalidSetValueChecker>(context, semantic_model);
+    run_check::<data_validator::invalid_string_value::InvalidStringValueChecker>(
+        context,
+        semantic_model,
+    );
     run_check::<data_validator::duplicate_index_value::DuplicateIndexValueChecker>(
         context,
         semantic_model,
@@ -38,4 +59,12 @@ pub fn check_luaconfig(context: &mut DiagnosticContext, semantic_model: &Semanti
         context,
         semantic_model,
     );
+    run_check::<data_validator::invalid_graph::InvalidGraphChecker>(context, semantic_model);
+    run_check::<data_validator::enum_coverage::EnumCoverageChecker>(context, semantic_model);
+    run_check::<data_validator::unknown_enum_name::UnknownEnumNameChecker>(context, semantic_model);
+    run_check::<data_validator::invalid_weight_sum::InvalidWeightSumChecker>(
+        context,
+        semantic_model,
+    );
+    run_check::<external_validator::ExternalValidatorChecker>(context, semantic_model);
 }
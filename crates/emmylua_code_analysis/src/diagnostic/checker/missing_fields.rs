@@ -2,7 +2,10 @@ use std::collections::{HashMap, HashSet};
 
 use emmylua_parser::{LuaAstNode, LuaTableExpr};
 
-use crate::{DiagnosticCode, LuaMemberOwner, LuaType, LuaTypeCache, LuaTypeDeclId, SemanticModel};
+use crate::{
+    DiagnosticCode, LuaMemberOwner, LuaType, LuaTypeCache, LuaTypeDeclId, SemanticModel,
+    semantic::shared::luaconfig::BEAN,
+};
 
 use super::{Checker, DiagnosticContext, humanize_lint_type};
 use itertools::Itertools;
@@ -72,6 +75,13 @@ fn check_table_expr(
         table_type => table_type,
     };
 
+    // Bean 数据的必填字段由 luaconfig 的 `missing-required-field` 检查
+    if let LuaType::Ref(type_decl_id) = &table_type
+        && BEAN.is_bean(db, type_decl_id)
+    {
+        return Some(());
+    }
+
     let fields = expr.get_fields().collect::<Vec<_>>();
     if fields.len() > 50 {
         return Some(());
@@ -39,10 +39,14 @@ mod unnecessary_assert;
 mod unnecessary_if;
 mod unused;
 
+pub use luaconfig::check_luaconfig;
+
 use emmylua_parser::{
     LuaAstNode, LuaClosureExpr, LuaComment, LuaReturnStat, LuaStat, LuaSyntaxKind,
 };
-use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString};
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, DiagnosticTag, NumberOrString,
+};
 use rowan::TextRange;
 use std::sync::Arc;
 
@@ -77,6 +81,24 @@ pub fn run_check<T: Checker>(context: &mut DiagnosticContext, semantic_model: &S
 }
 
 pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticModel) -> Option<()> {
+    check_file_without_luaconfig(context, semantic_model);
+    luaconfig::check_luaconfig(context, semantic_model);
+    Some(())
+}
+
+/// 除配置表数据校验以外的检查, 批量诊断时配置表数据校验由 `check_luaconfig` 单独执行
+pub fn check_file_without_luaconfig(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+) -> Option<()> {
+    if context
+        .get_db()
+        .get_config_index()
+        .is_data_mode_file(&context.get_file_id())
+    {
+        return check_data_file(context, semantic_model);
+    }
+
     run_check::<syntax_error::SyntaxErrorChecker>(context, semantic_model);
     run_check::<analyze_error::AnalyzeErrorChecker>(context, semantic_model);
     run_check::<unused::UnusedChecker>(context, semantic_model);
@@ -127,8 +149,17 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     );
     run_check::<readonly_check::ReadOnlyChecker>(context, semantic_model);
     run_check::<global_non_module::GlobalInNonModuleChecker>(context, semantic_model);
+    Some(())
+}
 
-    luaconfig::check_luaconfig(context, semantic_model);
+/// 数据模式的文件只检查数据的类型结构与配置表约束
+fn check_data_file(context: &mut DiagnosticContext, semantic_model: &SemanticModel) -> Option<()> {
+    run_check::<syntax_error::SyntaxErrorChecker>(context, semantic_model);
+    run_check::<analyze_error::AnalyzeErrorChecker>(context, semantic_model);
+    run_check::<missing_fields::MissingFieldsChecker>(context, semantic_model);
+    run_check::<assign_type_mismatch::AssignTypeMismatchChecker>(context, semantic_model);
+    run_check::<duplicate_index::DuplicateIndexChecker>(context, semantic_model);
+    run_check::<enum_value_mismatch::EnumValueMismatchChecker>(context, semantic_model);
     Some(())
 }
 
@@ -164,12 +195,79 @@ impl<'a> DiagnosticContext<'a> {
         message: String,
         data: Option<serde_json::Value>,
     ) {
-        if !self.is_checker_enable_by_code(&code) {
+        if let Some(diagnostic) = self.build_diagnostic(code, range, message, data) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// 添加带有关联位置的诊断, 关联位置可以位于其他文件
+    pub fn add_diagnostic_with_related(
+        &mut self,
+        code: DiagnosticCode,
+        range: TextRange,
+        message: String,
+        related: Vec<(FileId, TextRange, String)>,
+    ) {
+        let Some(mut diagnostic) = self.build_diagnostic(code, range, message, None) else {
             return;
+        };
+
+        let vfs = self.db.get_vfs();
+        let related_information = related
+            .into_iter()
+            .filter_map(|(file_id, range, message)| {
+                let location = vfs.get_document(&file_id)?.to_lsp_location(range)?;
+                Some(DiagnosticRelatedInformation { location, message })
+            })
+            .collect::<Vec<_>>();
+        if !related_information.is_empty() {
+            diagnostic.related_information = Some(related_information);
         }
 
-        if !self.should_report_diagnostic(&code, &range) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// 添加外部校验器产生的诊断, 诊断代码为 `external-validator/<name>`
+    pub fn add_external_diagnostic(
+        &mut self,
+        name: &str,
+        range: TextRange,
+        message: String,
+        severity: Option<DiagnosticSeverity>,
+    ) {
+        let code = DiagnosticCode::ExternalValidator;
+        let Some(mut diagnostic) = self.build_diagnostic(code, range, message, None) else {
             return;
+        };
+
+        diagnostic.code = Some(NumberOrString::String(format!(
+            "{}/{}",
+            code.get_name(),
+            name
+        )));
+        // 用户为该代码配置的严重性优先
+        if !self.config.severity.contains_key(&code)
+            && let Some(severity) = severity
+        {
+            diagnostic.severity = Some(severity);
+        }
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn build_diagnostic(
+        &self,
+        code: DiagnosticCode,
+        range: TextRange,
+        message: String,
+        data: Option<serde_json::Value>,
+    ) -> Option<Diagnostic> {
+        if !self.is_checker_enable_by_code(&code) {
+            return None;
+        }
+
+        if !self.should_report_diagnostic(&code, &range) {
+            return None;
         }
 
         let diagnostic = Diagnostic {
@@ -192,7 +290,7 @@ impl<'a> DiagnosticContext<'a> {
             ..Default::default()
         };
 
-        self.diagnostics.push(diagnostic);
+        Some(diagnostic)
     }
 
     fn should_report_diagnostic(&self, code: &DiagnosticCode, range: &TextRange) -> bool {
@@ -1,14 +1,25 @@
-use std::sync::Arc;
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
 
 pub use super::checker::DiagnosticContext;
-use super::{checker::check_file, lua_diagnostic_config::LuaDiagnosticConfig};
+use super::{
+    checker::{check_file, check_file_without_luaconfig, check_luaconfig},
+    lua_diagnostic_config::LuaDiagnosticConfig,
+};
 use crate::{DiagnosticCode, Emmyrc, FileId, LuaCompilation};
-use lsp_types::Diagnostic;
+use lsp_types::{Diagnostic, NumberOrString};
 use tokio_util::sync::CancellationToken;
 
 #[derive(Debug)]
 pub struct LuaDiagnostic {
     enable: bool,
+    external_validators_trusted: bool,
     config: Arc<LuaDiagnosticConfig>,
 }
 
@@ -22,13 +33,28 @@ impl LuaDiagnostic {
     pub fn new() -> Self {
         Self {
             enable: true,
+            external_validators_trusted: false,
             config: Arc::new(LuaDiagnosticConfig::default()),
         }
     }
 
     pub fn update_config(&mut self, emmyrc: Arc<Emmyrc>) {
         self.enable = emmyrc.diagnostics.enable;
-        self.config = LuaDiagnosticConfig::new(&emmyrc).into();
+        if !self.external_validators_trusted && !emmyrc.diagnostics.external_validators.is_empty() {
+            log::warn!("external validators are configured but not allowed to run");
+        }
+        self.set_config(LuaDiagnosticConfig::new(&emmyrc));
+    }
+
+    /// 允许执行 `diagnostics.externalValidators` 中配置的外部程序, 默认不执行
+    pub fn set_external_validators_trusted(&mut self, trusted: bool) {
+        self.external_validators_trusted = trusted;
+        Arc::make_mut(&mut self.config).external_validators_trusted = trusted;
+    }
+
+    fn set_config(&mut self, mut config: LuaDiagnosticConfig) {
+        config.external_validators_trusted = self.external_validators_trusted;
+        self.config = config.into();
     }
 
     // 只开启指定的诊断
@@ -40,7 +66,7 @@ impl LuaDiagnostic {
                 emmyrc.diagnostics.disable.push(*diagnostic_code);
             }
         }
-        self.config = LuaDiagnosticConfig::new(&emmyrc).into();
+        self.set_config(LuaDiagnosticConfig::new(&emmyrc));
     }
 
     pub fn diagnose_file(
@@ -48,6 +74,26 @@ impl LuaDiagnostic {
         compilation: &LuaCompilation,
         file_id: FileId,
         cancel_token: CancellationToken,
+    ) -> Option<Vec<Diagnostic>> {
+        self.diagnose(compilation, file_id, cancel_token, true)
+    }
+
+    /// 诊断文件但不执行配置表数据校验, 与 `diagnose_luaconfig_files` 配合批量诊断
+    pub fn diagnose_file_without_luaconfig(
+        &self,
+        compilation: &LuaCompilation,
+        file_id: FileId,
+        cancel_token: CancellationToken,
+    ) -> Option<Vec<Diagnostic>> {
+        self.diagnose(compilation, file_id, cancel_token, false)
+    }
+
+    fn diagnose(
+        &self,
+        compilation: &LuaCompilation,
+        file_id: FileId,
+        cancel_token: CancellationToken,
+        luaconfig: bool,
     ) -> Option<Vec<Diagnostic>> {
         if !self.enable {
             return None;
@@ -67,8 +113,115 @@ impl LuaDiagnostic {
         let semantic_model = compilation.get_semantic_model(file_id)?;
         let mut context = DiagnosticContext::new(file_id, db, self.config.clone());
 
-        check_file(&mut context, &semantic_model);
+        if luaconfig {
+            check_file(&mut context, &semantic_model);
+        } else {
+            check_file_without_luaconfig(&mut context, &semantic_model);
+        }
 
         Some(context.get_diagnostics())
     }
+
+    /// 批量执行多个文件的配置表数据校验, 各文件在多个线程中并行检查
+    ///
+    /// 每个文件使用独立的 `SemanticModel` 与推断缓存, 结果按传入的文件顺序排列,
+    /// 文件内的诊断按位置排序, 与线程数无关
+    pub fn diagnose_luaconfig_files(
+        &self,
+        compilation: &LuaCompilation,
+        file_ids: &[FileId],
+        cancel_token: CancellationToken,
+    ) -> Vec<(FileId, Vec<Diagnostic>)> {
+        if !self.enable || file_ids.is_empty() {
+            return Vec::new();
+        }
+
+        let thread_count = match self.config.luaconfig_threads {
+            0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            count => count,
+        }
+        .min(file_ids.len());
+
+        let next = AtomicUsize::new(0);
+        let mut results = thread::scope(|scope| {
+            let handles = (0..thread_count)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut results = Vec::new();
+                        loop {
+                            if cancel_token.is_cancelled() {
+                                break;
+                            }
+                            let idx = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(file_id) = file_ids.get(idx) else {
+                                break;
+                            };
+                            if let Some(diagnostics) =
+                                self.diagnose_luaconfig_file(compilation, *file_id)
+                            {
+                                results.push((idx, *file_id, diagnostics));
+                            }
+                        }
+                        results
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .filter_map(|handle| match handle.join() {
+                    Ok(results) => Some(results),
+                    Err(_) => {
+                        log::error!("luaconfig validation thread panicked");
+                        None
+                    }
+                })
+                .flatten()
+                .collect::<Vec<_>>()
+        });
+
+        if cancel_token.is_cancelled() {
+            return Vec::new();
+        }
+
+        results.sort_by_key(|(idx, _, _)| *idx);
+        results
+            .into_iter()
+            .map(|(_, file_id, diagnostics)| (file_id, diagnostics))
+            .collect()
+    }
+
+    fn diagnose_luaconfig_file(
+        &self,
+        compilation: &LuaCompilation,
+        file_id: FileId,
+    ) -> Option<Vec<Diagnostic>> {
+        let db = compilation.get_db();
+        if let Some(module_info) = db.get_module_index().get_workspace_id(file_id)
+            && !module_info.is_main()
+        {
+            return None;
+        }
+
+        let semantic_model = compilation.get_semantic_model(file_id)?;
+        let mut context = DiagnosticContext::new(file_id, db, self.config.clone());
+
+        check_luaconfig(&mut context, &semantic_model);
+
+        let mut diagnostics = context.get_diagnostics();
+        diagnostics.sort_by(|a, b| {
+            (a.range.start, a.range.end)
+                .cmp(&(b.range.start, b.range.end))
+                .then_with(|| code_name(a).cmp(code_name(b)))
+                .then_with(|| a.message.cmp(&b.message))
+        });
+        Some(diagnostics)
+    }
+}
+
+fn code_name(diagnostic: &Diagnostic) -> &str {
+    match &diagnostic.code {
+        Some(NumberOrString::String(code)) => code,
+        _ => "",
+    }
 }
@@ -129,6 +129,8 @@ pub enum DiagnosticCode {
     InvalidSizeSignature,
     /// invalid-set-signature
     InvalidSetSignature,
+    /// invalid-string-signature
+    InvalidStringSignature,
     /// invalid-flags-enum-value
     InvalidFlagsEnumValue,
     /// invalid-range-value
@@ -137,10 +139,36 @@ pub enum DiagnosticCode {
     InvalidSizeValue,
     /// invalid-set-value
     InvalidSetValue,
+    /// invalid-string-value
+    InvalidStringValue,
     /// duplicate-set-element
     DuplicateSetElement,
     /// duplicate-index-value
     DuplicateIndexValue,
+    /// duplicate-unique-value
+    DuplicateUniqueValue,
+    /// ref-cycle
+    RefCycle,
+    /// unreachable-row
+    UnreachableRow,
+    /// tree-depth-exceeded
+    TreeDepthExceeded,
+    /// enum-coverage-mismatch
+    EnumCoverageMismatch,
+    /// missing-required-field
+    MissingRequiredField,
+    /// invalid-default-signature
+    InvalidDefaultSignature,
+    /// unknown-enum-name
+    UnknownEnumName,
+    /// external-validator
+    ExternalValidator,
+    /// invalid-weight-signature
+    InvalidWeightSignature,
+    /// invalid-weight-sum
+    InvalidWeightSum,
+    /// invalid-graph-signature
+    InvalidGraphSignature,
 
     #[serde(other)]
     None,
@@ -169,6 +197,8 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
         DiagnosticCode::DuplicateRequire => DiagnosticSeverity::HINT,
         DiagnosticCode::IterVariableReassign => DiagnosticSeverity::ERROR,
         DiagnosticCode::PreferredLocalAlias => DiagnosticSeverity::HINT,
+        DiagnosticCode::MissingRequiredField => DiagnosticSeverity::ERROR,
+        DiagnosticCode::UnknownEnumName => DiagnosticSeverity::ERROR,
         _ => DiagnosticSeverity::WARNING,
     }
 }
@@ -5,7 +5,7 @@ use lsp_types::DiagnosticSeverity;
 use regex::Regex;
 use smol_str::SmolStr;
 
-use crate::Emmyrc;
+use crate::{Emmyrc, EmmyrcExternalValidator};
 
 use super::DiagnosticCode;
 
@@ -17,6 +17,10 @@ pub struct LuaDiagnosticConfig {
     pub global_disable_glob: Vec<Regex>,
     pub severity: HashMap<DiagnosticCode, DiagnosticSeverity>,
     pub level: LuaLanguageLevel,
+    pub external_validators: Vec<EmmyrcExternalValidator>,
+    /// 是否允许执行外部校验器, 由使用者显式开启, 不受项目配置控制
+    pub external_validators_trusted: bool,
+    pub luaconfig_threads: usize,
 }
 
 impl LuaDiagnosticConfig {
@@ -54,6 +58,9 @@ impl LuaDiagnosticConfig {
             global_disable_glob,
             severity,
             level: emmyrc.get_language_level(),
+            external_validators: emmyrc.diagnostics.external_validators.clone(),
+            external_validators_trusted: false,
+            luaconfig_threads: emmyrc.diagnostics.luaconfig_threads,
         }
     }
 }
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_vdefault_type_mismatch() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidDefaultSignature,
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.default("one")]
+            ---@field stack int
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vdefault_must_apply_to_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidDefaultSignature,
+            r#"
+            ---@[v.default(1)]
+            ---@class TestItem: Bean
+            ---@field stack int
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vdefault_ok() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidDefaultSignature,
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.default(1)]
+            ---@field stack int
+            ---@[v.default(0.5)]
+            ---@field rate float
+            ---@[v.default("none")]
+            ---@field icon string
+            ---@[v.default(false)]
+            ---@field hidden boolean
+            ---@[v.default(2)]
+            ---@field level ([v.range("[1,10]")] int)
+            "#,
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_graph_signature_ok() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidGraphSignature,
+            r#"
+            ---@class Skill: Bean
+            ---@field id int
+            ---@[v.acyclic]
+            ---@field parentId int?
+
+            ---@[t.tree("parentId", 3)]
+            ---@class TbSkill: ConfigTable
+            ---@field [int] Skill
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_tree_unknown_parent() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidGraphSignature,
+            r#"
+            ---@class Skill: Bean
+            ---@field id int
+            ---@field parentId int?
+
+            ---@[t.tree("parent")]
+            ---@class TbSkill: ConfigTable
+            ---@field [int] Skill
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_tree_multi_key_table() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidGraphSignature,
+            r#"
+            ---@class Skill: Bean
+            ---@field id int
+            ---@field level int
+            ---@field parentId int?
+
+            ---@[t.index(["id", "level"])]
+            ---@[t.tree("parentId")]
+            ---@class TbSkill: ConfigTable
+            ---@field [int] Skill
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_acyclic_multi_key_table() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidGraphSignature,
+            r#"
+            ---@class Quest: Bean
+            ---@field id int
+            ---@field stage int
+            ---@[v.acyclic]
+            ---@field prerequisites list<int>
+
+            ---@[t.index(["id", "stage"])]
+            ---@class TbQuest: ConfigTable
+            ---@field [int] Quest
+            "#,
+        ));
+    }
+}
@@ -1,5 +1,9 @@
+mod default_signature_test;
 mod flags_enum_value_test;
+mod graph_signature_test;
 mod range_signature_test;
 mod set_param_test;
 mod set_signature_test;
 mod size_signature_test;
+mod string_signature_test;
+mod weight_signature_test;
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_vregex_invalid_pattern() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringSignature,
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.regex("^icon_[a-z")]
+            ---@field icon string
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vlen_invalid_range() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringSignature,
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.len("[1.5,10]")]
+            ---@field desc string
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vformat_unknown_format() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringSignature,
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.format("email")]
+            ---@field mail string
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_string_validator_not_on_container() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringSignature,
+            r#"
+            ---@type ([v.len(3)] list<string>)
+            local xs = { "abc" }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_string_validators_ok() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidStringSignature,
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.regex("^icon_[a-z0-9_]+$")]
+            ---@field icon string
+            ---@[v.len("[,200]")]
+            ---@field desc string
+            ---@[v.format("color")]
+            ---@field color string
+            ---@field tags list<[v.len(4)] string>
+            "#,
+        ));
+    }
+}
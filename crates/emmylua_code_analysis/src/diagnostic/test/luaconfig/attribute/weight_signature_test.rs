@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_vweight_sum_ok() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidWeightSignature,
+            r#"
+            ---@class DropItem: Bean
+            ---@field group int
+            ---@[v.weight_sum(100, "group")]
+            ---@field weight int
+            ---@[v.prob_sum]
+            ---@field prob float
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vweight_sum_invalid_total() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidWeightSignature,
+            r#"
+            ---@class DropItem: Bean
+            ---@[v.weight_sum(0)]
+            ---@field weight int
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vweight_sum_non_number_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidWeightSignature,
+            r#"
+            ---@class DropItem: Bean
+            ---@[v.weight_sum(100)]
+            ---@field weight string
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vprob_sum_unknown_group_by() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidWeightSignature,
+            r#"
+            ---@class GachaItem: Bean
+            ---@field poolId int
+            ---@[v.prob_sum("pool")]
+            ---@field prob float
+            "#,
+        ));
+    }
+}
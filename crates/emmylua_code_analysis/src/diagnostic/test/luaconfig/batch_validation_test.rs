@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::{Diagnostic, NumberOrString};
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, FileId, VirtualWorkspace};
+
+    const ITEM_DEF: &str = r#"
+        ---@class Item: Bean
+        ---@field id int
+        ---@field name string
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+    "#;
+
+    fn def_data_files(ws: &mut VirtualWorkspace, count: usize) -> Vec<FileId> {
+        (0..count)
+            .map(|i| {
+                // 相邻文件的主键重复, 每个文件也缺少一个必填字段
+                let source = format!(
+                    r#"
+                    ---@type TbItem
+                    local items = {{
+                        [{}] = {{ id = {}, name = "a" }},
+                        [{}] = {{ id = {} }},
+                    }}
+                    "#,
+                    i,
+                    i,
+                    i + 1,
+                    i + 1,
+                );
+                ws.def_file(&format!("data/item_{i}.lua"), &source)
+            })
+            .collect()
+    }
+
+    fn validate(
+        ws: &mut VirtualWorkspace,
+        threads: usize,
+        file_ids: &[FileId],
+    ) -> Vec<(FileId, Vec<Diagnostic>)> {
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.diagnostics.luaconfig_threads = threads;
+        ws.update_emmyrc(emmyrc);
+        ws.analysis
+            .diagnose_luaconfig_files(file_ids, CancellationToken::new())
+    }
+
+    fn has_code(diagnostics: &[Diagnostic], code: DiagnosticCode) -> bool {
+        let code = NumberOrString::String(code.get_name().to_string());
+        diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.code.as_ref() == Some(&code))
+    }
+
+    #[test]
+    fn test_parallel_matches_single_thread() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        let mut file_ids = def_data_files(&mut ws, 16);
+        file_ids.reverse();
+
+        let single = validate(&mut ws, 1, &file_ids);
+        let parallel = validate(&mut ws, 4, &file_ids);
+        assert_eq!(single, parallel);
+        assert_eq!(
+            parallel
+                .iter()
+                .map(|(file_id, _)| *file_id)
+                .collect::<Vec<_>>(),
+            file_ids
+        );
+
+        for (_, diagnostics) in &parallel {
+            assert!(has_code(diagnostics, DiagnosticCode::DuplicatePrimaryKey));
+            assert!(has_code(diagnostics, DiagnosticCode::MissingRequiredField));
+            // 只执行配置表校验
+            assert!(!has_code(diagnostics, DiagnosticCode::Unused));
+        }
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        let file_ids = def_data_files(&mut ws, 4);
+
+        let cancel_token = CancellationToken::new();
+        cancel_token.cancel();
+        assert!(
+            ws.analysis
+                .diagnose_luaconfig_files(&file_ids, cancel_token)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_split_matches_diagnose_file() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        let file_ids = def_data_files(&mut ws, 4);
+
+        // 工作区诊断的常规检查与配置表数据校验分开执行, 合并后与单文件诊断一致
+        let luaconfig = validate(&mut ws, 2, &file_ids);
+        for (file_id, luaconfig_diagnostics) in luaconfig {
+            let mut split = ws
+                .analysis
+                .diagnose_file_without_luaconfig(file_id, CancellationToken::new())
+                .unwrap_or_default();
+            assert!(!has_code(&split, DiagnosticCode::DuplicatePrimaryKey));
+            split.extend(luaconfig_diagnostics);
+
+            let mut full = ws
+                .analysis
+                .diagnose_file(file_id, CancellationToken::new())
+                .unwrap_or_default();
+            let key = |diagnostic: &Diagnostic| {
+                (
+                    diagnostic.range.start,
+                    diagnostic.range.end,
+                    diagnostic.message.clone(),
+                )
+            };
+            split.sort_by_key(key);
+            full.sort_by_key(key);
+            assert_eq!(split, full);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, FileId, VirtualWorkspace};
+
+    const ITEM_DEF: &str = r#"
+        ---@class Item: Bean
+        ---@field id int
+        ---@field name string
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+    "#;
+
+    const ITEM_DATA: &str = r#"
+        ---@type TbItem
+        local items = {
+            [1] = { id = 1, name = "Sword" },
+            [2] = { id = 1, name = "Shield" },
+        }
+        return items
+    "#;
+
+    fn is_data_mode_file(ws: &VirtualWorkspace, file_id: FileId) -> bool {
+        ws.analysis
+            .compilation
+            .get_db()
+            .get_config_index()
+            .is_data_mode_file(&file_id)
+    }
+
+    fn diagnostic_codes(ws: &VirtualWorkspace, file_id: FileId) -> Vec<String> {
+        ws.analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|diagnostic| match diagnostic.code {
+                Some(NumberOrString::String(code)) => Some(code),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_data_mode_globs() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.workspace.data_mode_globs = vec!["data/**/*.lua".to_string()];
+        emmyrc.workspace.data_mode_min_size = 0;
+        ws.update_emmyrc(emmyrc);
+        ws.def(ITEM_DEF);
+
+        let data = ws.def_file("data/item.lua", ITEM_DATA);
+        let logic = ws.def_file("logic/item.lua", ITEM_DATA);
+        assert!(is_data_mode_file(&ws, data));
+        assert!(!is_data_mode_file(&ws, logic));
+
+        let db = ws.analysis.compilation.get_db();
+        assert!(db.get_flow_index().get_flow_tree(&data).is_none());
+        assert!(db.get_flow_index().get_flow_tree(&logic).is_some());
+    }
+
+    #[test]
+    fn test_auto_detect() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.workspace.data_mode_min_size = 1;
+        ws.update_emmyrc(emmyrc);
+        ws.def(ITEM_DEF);
+
+        let data = ws.def_file("item.lua", ITEM_DATA);
+        assert!(is_data_mode_file(&ws, data));
+
+        let untyped = ws.def_file("untyped.lua", "local items = { { id = 1 } }");
+        assert!(!is_data_mode_file(&ws, untyped));
+
+        let with_call = ws.def_file(
+            "with_call.lua",
+            r#"
+            ---@type TbItem
+            local items = { [1] = { id = 1, name = "Sword" } }
+            print(items)
+            "#,
+        );
+        assert!(!is_data_mode_file(&ws, with_call));
+
+        let with_closure = ws.def_file(
+            "with_closure.lua",
+            r#"
+            ---@type TbItem
+            local items = { [1] = { id = 1, name = "Sword", get = function() end } }
+            "#,
+        );
+        assert!(!is_data_mode_file(&ws, with_closure));
+
+        // 文件变化后不再是数据文件
+        ws.def_file("item.lua", "local items = {}");
+        assert!(!is_data_mode_file(&ws, data));
+    }
+
+    #[test]
+    fn test_auto_detect_min_size() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        let data = ws.def_file("item.lua", ITEM_DATA);
+        assert!(!is_data_mode_file(&ws, data));
+    }
+
+    #[test]
+    fn test_data_mode_diagnostics() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.workspace.data_mode_globs = vec!["data/**/*.lua".to_string()];
+        ws.update_emmyrc(emmyrc);
+        ws.def(ITEM_DEF);
+
+        let source = r#"
+            ---@type TbItem
+            local items = {
+                [1] = { id = 1, name = "Sword" },
+                [2] = { id = 1, name = "Shield" },
+            }
+        "#;
+        let data = ws.def_file("data/item.lua", source);
+        let logic = ws.def_file("logic/item.lua", source);
+
+        let unused = DiagnosticCode::Unused.get_name().to_string();
+        let duplicate_pk = DiagnosticCode::DuplicatePrimaryKey.get_name().to_string();
+
+        let data_codes = diagnostic_codes(&ws, data);
+        assert!(!data_codes.contains(&unused));
+        assert!(data_codes.contains(&duplicate_pk));
+
+        let logic_codes = diagnostic_codes(&ws, logic);
+        assert!(logic_codes.contains(&unused));
+        assert!(logic_codes.contains(&duplicate_pk));
+    }
+}
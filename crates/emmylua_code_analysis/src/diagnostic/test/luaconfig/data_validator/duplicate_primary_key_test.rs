@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use crate::DiagnosticCode;
+    use crate::{DiagnosticCode, LuaMemberKey, LuaType, LuaTypeDeclId};
 
     #[test]
     fn test_1() {
@@ -163,4 +163,61 @@ mod test {
             "#,
         ));
     }
+
+    #[test]
+    fn test_cross_file_updated() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        ws.def_file(
+            "item_a.lua",
+            r#"
+            ---@type TbItem
+            local items = { { id = 1 } }
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DuplicatePrimaryKey,
+            r#"
+            ---@type TbItem
+            local items = { { id = 1 } }
+            "#,
+        ));
+
+        ws.def_file(
+            "item_a.lua",
+            r#"
+            ---@type TbItem
+            local items = { { id = 2 } }
+            "#,
+        );
+        let pk_index = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_config_index()
+            .get_pk_value_index();
+        let table = LuaTypeDeclId::new("TbItem");
+        let key = LuaMemberKey::Name("id".into());
+        assert_eq!(
+            pk_index
+                .get_locations(&table, &key, &LuaType::IntegerConst(1))
+                .len(),
+            1
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DuplicatePrimaryKey,
+            r#"
+            ---@type TbItem
+            local items = { { id = 2 } }
+            "#,
+        ));
+    }
 }
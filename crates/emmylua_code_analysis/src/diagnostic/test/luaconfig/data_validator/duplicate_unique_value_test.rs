@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::DiagnosticCode;
+
+    const ITEM_DEF: &str = r#"
+        ---@class Item: Bean
+        ---@field id int
+        ---@field name string
+        ---@field type int
+        ---@field level int
+
+        ---@[t.unique("name")]
+        ---@[t.unique(["type", "level"])]
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+    "#;
+
+    #[test]
+    fn test_unique_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DuplicateUniqueValue,
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 1, name = "A", type = 1, level = 1 },
+                { id = 2, name = "A", type = 1, level = 2 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_unique_fields_combination() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        assert!(ws.check_code_for(
+            DiagnosticCode::DuplicateUniqueValue,
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 1, name = "A", type = 1, level = 1 },
+                { id = 2, name = "B", type = 1, level = 2 },
+                { id = 3, name = "C", type = 2, level = 1 },
+            }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DuplicateUniqueValue,
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 11, name = "D", type = 3, level = 1 },
+                { id = 12, name = "E", type = 3, level = 1 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_unique_across_files() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        let other_file_id = ws.def_file(
+            "items_a.lua",
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 1, name = "Sword", type = 1, level = 1 },
+            }
+            "#,
+        );
+
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::DuplicateUniqueValue);
+        let file_id = ws.def_file(
+            "items_b.lua",
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 2, name = "Sword", type = 1, level = 2 },
+            }
+            "#,
+        );
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::DuplicateUniqueValue.get_name().to_string(),
+        ));
+        let diagnostic = diagnostics
+            .iter()
+            .find(|diagnostic| diagnostic.code == code)
+            .expect("expected duplicate unique value");
+        let related = diagnostic
+            .related_information
+            .as_ref()
+            .expect("expected related information");
+        assert_eq!(related.len(), 1);
+
+        let other_uri = ws
+            .analysis
+            .get_uri(other_file_id)
+            .expect("expected uri of other file");
+        assert_eq!(related[0].location.uri, other_uri);
+    }
+
+    #[test]
+    fn test_unique_after_remove_file() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        ws.def_file(
+            "items_a.lua",
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 1, name = "Sword", type = 1, level = 1 },
+            }
+            "#,
+        );
+        let uri = ws.virtual_url_generator.new_uri("items_a.lua");
+        ws.analysis.remove_file_by_uri(&uri);
+        assert!(ws.check_code_for(
+            DiagnosticCode::DuplicateUniqueValue,
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 2, name = "Sword", type = 1, level = 1 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_unique_skip_non_literal() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(ITEM_DEF);
+        assert!(ws.check_code_for(
+            DiagnosticCode::DuplicateUniqueValue,
+            r#"
+            local name ---@type string
+
+            ---@type TbItem
+            local items = {
+                { id = 1, name = name, type = 1, level = 1 },
+                { id = 2, name = name, type = 1, level = 2 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_unique_invalid_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidIndexField,
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+
+            ---@[t.unique("missing")]
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        ));
+    }
+}
@@ -0,0 +1,248 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, FileId, VirtualWorkspace};
+
+    /// 先声明配置表, 再添加数据文件, 最后检查配置表声明所在文件
+    fn check_table_decl(decl: &str, data: &[&str]) -> bool {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::EnumCoverageMismatch);
+        let decl_file_id = ws.def(decl);
+        for content in data {
+            ws.def(content);
+        }
+        check_decl_file(&mut ws, decl_file_id)
+    }
+
+    fn check_decl_file(ws: &mut VirtualWorkspace, decl_file_id: FileId) -> bool {
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::EnumCoverageMismatch.get_name().to_string(),
+        ));
+        ws.analysis
+            .diagnose_file(decl_file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .iter()
+            .all(|diagnostic| diagnostic.code != code)
+    }
+
+    const QUALITY_DEF: &str = r#"
+        ---@enum Quality
+        Quality = {
+            Common = 1,
+            Rare = 2,
+            Epic = 3,
+        }
+
+        ---@class QualityColor: Bean
+        ---@field quality int
+        ---@field color string
+
+        ---@[t.cover_enum("quality", "Quality")]
+        ---@class TbQualityColor: ConfigTable
+        ---@field [int] QualityColor
+    "#;
+
+    #[test]
+    fn test_cover_enum_ok() {
+        assert!(check_table_decl(
+            QUALITY_DEF,
+            &[r#"
+            ---@type TbQualityColor
+            local colors = {
+                { quality = 1, color = "white" },
+                { quality = 2, color = "blue" },
+                { quality = 3, color = "purple" },
+            }
+            "#]
+        ));
+    }
+
+    #[test]
+    fn test_cover_enum_missing() {
+        assert!(!check_table_decl(
+            QUALITY_DEF,
+            &[r#"
+            ---@type TbQualityColor
+            local colors = {
+                { quality = 1, color = "white" },
+                { quality = 2, color = "blue" },
+            }
+            "#]
+        ));
+    }
+
+    #[test]
+    fn test_cover_enum_extra() {
+        assert!(!check_table_decl(
+            QUALITY_DEF,
+            &[r#"
+            ---@type TbQualityColor
+            local colors = {
+                { quality = 1, color = "white" },
+                { quality = 2, color = "blue" },
+                { quality = 3, color = "purple" },
+                { quality = 4, color = "orange" },
+            }
+            "#]
+        ));
+    }
+
+    #[test]
+    fn test_cover_enum_across_files() {
+        assert!(check_table_decl(
+            QUALITY_DEF,
+            &[
+                r#"
+                ---@type TbQualityColor
+                local colors = {
+                    { quality = 1, color = "white" },
+                }
+                "#,
+                r#"
+                ---@type TbQualityColor
+                local colors = {
+                    { quality = 2, color = "blue" },
+                    { quality = 3, color = "purple" },
+                }
+                "#,
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_cover_enum_removed_file() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::EnumCoverageMismatch);
+        let decl_file_id = ws.def(QUALITY_DEF);
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@type TbQualityColor
+            local colors = {
+                { quality = 2, color = "blue" },
+                { quality = 3, color = "purple" },
+            }
+            "#,
+        );
+        ws.def_file(
+            "b.lua",
+            r#"
+            ---@type TbQualityColor
+            local colors = {
+                { quality = 1, color = "white" },
+            }
+            "#,
+        );
+        assert!(check_decl_file(&mut ws, decl_file_id));
+
+        let uri = ws.virtual_url_generator.new_uri("a.lua");
+        ws.analysis.remove_file_by_uri(&uri);
+        assert!(!check_decl_file(&mut ws, decl_file_id));
+    }
+
+    #[test]
+    fn test_cover_enum_names() {
+        let decl = r#"
+            ---@enum Quality
+            Quality = {
+                Common = 1,
+                ---@[alias("稀有")]
+                Rare = 2,
+                Epic = 3,
+            }
+
+            ---@class QualityColor: Bean
+            ---@field quality Quality
+            ---@field color string
+
+            ---@[t.cover_enum("quality", "Quality")]
+            ---@class TbQualityColor: ConfigTable
+            ---@field [int] QualityColor
+        "#;
+        assert!(check_table_decl(
+            decl,
+            &[r#"
+            ---@type TbQualityColor
+            local colors = {
+                { quality = "Common", color = "white" },
+                { quality = "稀有", color = "blue" },
+                { quality = 3, color = "purple" },
+            }
+            "#]
+        ));
+        assert!(!check_table_decl(
+            decl,
+            &[r#"
+            ---@type TbQualityColor
+            local colors = {
+                { quality = "Common", color = "white" },
+                { quality = "Rare", color = "blue" },
+            }
+            "#]
+        ));
+    }
+
+    #[test]
+    fn test_cover_flags_enum() {
+        let decl = r#"
+            ---@[flags]
+            ---@enum Element
+            Element = {
+                None = 0,
+                Fire = 1,
+                Water = 2,
+            }
+
+            ---@class ElementIcon: Bean
+            ---@field element int
+            ---@field icon string
+
+            ---@[t.cover_enum("element", "Element")]
+            ---@class TbElementIcon: ConfigTable
+            ---@field [int] ElementIcon
+        "#;
+        assert!(check_table_decl(
+            decl,
+            &[r#"
+            ---@type TbElementIcon
+            local icons = {
+                { element = 1, icon = "fire" },
+                { element = 2, icon = "water" },
+                { element = 3, icon = "steam" },
+            }
+            "#]
+        ));
+        assert!(!check_table_decl(
+            decl,
+            &[r#"
+            ---@type TbElementIcon
+            local icons = {
+                { element = 1, icon = "fire" },
+                { element = 5, icon = "unknown" },
+            }
+            "#]
+        ));
+    }
+
+    #[test]
+    fn test_cover_enum_invalid_signature() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::EnumCoverageMismatch,
+            r#"
+            ---@class QualityColor: Bean
+            ---@field quality int
+
+            ---@[t.cover_enum("quality", "QualityColor")]
+            ---@class TbQualityColor: ConfigTable
+            ---@field [int] QualityColor
+            "#,
+        ));
+    }
+}
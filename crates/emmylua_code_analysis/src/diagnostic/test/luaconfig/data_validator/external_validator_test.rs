@@ -0,0 +1,234 @@
+#[cfg(all(test, unix))]
+mod test {
+    use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, EmmyrcExternalValidator, VirtualWorkspace};
+
+    const ITEM_DEF: &str = r#"
+        ---@class Item: Bean
+        ---@field id int
+        ---@field name string
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+    "#;
+
+    const ITEM_DATA: &str = r#"---@type TbItem
+local items = {
+    { id = 1, name = "Sword" },
+    { id = 2, name = "" },
+}
+"#;
+
+    /// 使用 shell 脚本模拟外部校验器
+    fn new_workspace(name: &str, script: &str, trusted: bool) -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.analysis.set_external_validators_trusted(trusted);
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc
+            .diagnostics
+            .external_validators
+            .push(EmmyrcExternalValidator {
+                name: name.to_string(),
+                program: "sh".to_string(),
+                args: vec!["-c".to_string(), script.to_string()],
+                tables: vec!["TbItem".to_string()],
+                timeout: 10000,
+                working_dir: None,
+            });
+        ws.update_emmyrc(emmyrc);
+        ws.def(ITEM_DEF);
+        ws
+    }
+
+    /// 只返回外部校验器的诊断
+    fn external_diagnostics(diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        let prefix = DiagnosticCode::ExternalValidator.get_name();
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                matches!(&diagnostic.code, Some(NumberOrString::String(code)) if code.starts_with(prefix))
+            })
+            .collect()
+    }
+
+    fn check_with_script(name: &str, script: &str) -> Vec<Diagnostic> {
+        let mut ws = new_workspace(name, script, true);
+        let file_id = ws.def_file("items.lua", ITEM_DATA);
+        external_diagnostics(
+            ws.analysis
+                .diagnose_file(file_id, CancellationToken::new())
+                .unwrap_or_default(),
+        )
+    }
+
+    #[test]
+    fn test_external_diagnostic_path() {
+        let diagnostics = check_with_script(
+            "item_rules",
+            r#"cat >/dev/null; echo '{"diagnostics":[{"table":"TbItem","row":1,"path":["name"],"message":"name is empty","rule":"non-empty","severity":"error"}]}'"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(
+            diagnostic.code,
+            Some(NumberOrString::String(
+                "external-validator/item_rules/non-empty".to_string()
+            ))
+        );
+        assert_eq!(diagnostic.message, "name is empty");
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diagnostic.range.start.line, 3);
+        assert_eq!(diagnostic.range.start.character, 21);
+        assert_eq!(diagnostic.range.end.character, 23);
+    }
+
+    #[test]
+    fn test_external_diagnostic_row() {
+        let diagnostics = check_with_script(
+            "item_rows",
+            r#"cat >/dev/null; echo '{"diagnostics":[{"table":"TbItem","row":0,"path":["missing"],"message":"bad row"}]}'"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(
+            diagnostic.code,
+            Some(NumberOrString::String(
+                "external-validator/item_rows".to_string()
+            ))
+        );
+        assert_eq!(diagnostic.range.start.line, 2);
+        assert_eq!(diagnostic.range.start.character, 4);
+    }
+
+    #[test]
+    fn test_external_validator_failure() {
+        let diagnostics =
+            check_with_script("item_broken", "cat >/dev/null; echo broken >&2; exit 1");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code,
+            Some(NumberOrString::String(
+                "external-validator/item_broken".to_string()
+            ))
+        );
+        assert!(diagnostics[0].message.contains("broken"));
+    }
+
+    #[test]
+    fn test_external_request() {
+        let diagnostics = check_with_script(
+            "item_request",
+            r#"case "$(cat)" in
+                *'"keys":["id"]'*'"key":2,'*'"data":{"id":2,"name":""}'*)
+                    echo '{"diagnostics":[{"table":"TbItem","row":1,"message":"matched"}]}' ;;
+                *) echo '{}' ;;
+            esac"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "matched");
+    }
+
+    #[test]
+    fn test_external_validator_untrusted() {
+        // 未显式允许时不执行项目配置中的外部程序
+        let mut ws = new_workspace(
+            "item_untrusted",
+            r#"cat >/dev/null; echo '{"diagnostics":[{"table":"TbItem","row":0,"message":"ran"}]}'"#,
+            false,
+        );
+        let file_id = ws.def_file("items.lua", ITEM_DATA);
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        assert!(external_diagnostics(diagnostics).is_empty());
+    }
+
+    #[test]
+    fn test_external_validator_runs_once() {
+        let log_path = std::env::temp_dir().join(format!(
+            "emmylua_external_validator_{}.log",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&log_path);
+        let script = format!(
+            "cat >/dev/null; echo run >> '{}'; echo '{{}}'",
+            log_path.display()
+        );
+        let mut ws = new_workspace("item_once", &script, true);
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.diagnostics.luaconfig_threads = 4;
+        ws.update_emmyrc(emmyrc);
+        let file_ids = (0..8)
+            .map(|i| {
+                ws.def_file(
+                    &format!("items_{i}.lua"),
+                    &format!("---@type TbItem\nlocal items = {{ {{ id = {i}, name = \"a\" }} }}\n"),
+                )
+            })
+            .collect::<Vec<_>>();
+        let run_count = || {
+            std::fs::read_to_string(&log_path)
+                .map(|log| log.lines().count())
+                .unwrap_or_default()
+        };
+
+        // 并行诊断多个文件时, 输入相同的校验器只执行一次
+        ws.analysis
+            .diagnose_luaconfig_files(&file_ids, CancellationToken::new());
+        assert_eq!(run_count(), 1);
+
+        // 数据变化后重新构建请求并执行
+        ws.def_file(
+            "items_0.lua",
+            "---@type TbItem\nlocal items = { { id = 100, name = \"b\" } }\n",
+        );
+        ws.analysis
+            .diagnose_luaconfig_files(&file_ids, CancellationToken::new());
+        assert_eq!(run_count(), 2);
+        let _ = std::fs::remove_file(&log_path);
+    }
+
+    #[test]
+    fn test_external_validator_workspace_paths() {
+        let root = std::env::temp_dir().join(format!(
+            "emmylua_external_validator_root_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::create_dir_all(&root);
+        let _ = std::fs::write(root.join("marker"), "");
+        let _ = std::fs::write(
+            root.join("check.sh"),
+            r#"cat >/dev/null; [ -f marker ] && echo '{"diagnostics":[{"table":"TbItem","row":0,"message":"'"$1"'"}]}'"#,
+        );
+
+        let mut ws = new_workspace("item_paths", "", true);
+        let mut emmyrc = ws.get_emmyrc();
+        let validator = &mut emmyrc.diagnostics.external_validators[0];
+        validator.args = vec![
+            "./check.sh".to_string(),
+            "${workspaceFolder}/marker".to_string(),
+        ];
+        emmyrc.pre_process_emmyrc(&root);
+        let validator = &emmyrc.diagnostics.external_validators[0];
+        // 命令名保持不变, 以便在 PATH 中查找
+        assert_eq!(validator.program, "sh");
+        ws.update_emmyrc(emmyrc);
+
+        // 相对路径与变量按工作区展开, 校验器在工作区根目录下运行
+        let file_id = ws.def_file("items.lua", ITEM_DATA);
+        let diagnostics = external_diagnostics(
+            ws.analysis
+                .diagnose_file(file_id, CancellationToken::new())
+                .unwrap_or_default(),
+        );
+        let _ = std::fs::remove_dir_all(&root);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            root.join("marker").to_string_lossy()
+        );
+    }
+}
@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    const QUEST_DEF: &str = r#"
+        ---@class Quest: Bean
+        ---@field id int
+        ---@[v.acyclic]
+        ---@field prerequisites list<int>
+
+        ---@class TbQuest: ConfigTable
+        ---@field [int] Quest
+    "#;
+
+    const SKILL_DEF: &str = r#"
+        ---@class Skill: Bean
+        ---@field id int
+        ---@field parentId int?
+
+        ---@[t.tree("parentId", 3)]
+        ---@class TbSkill: ConfigTable
+        ---@field [int] Skill
+    "#;
+
+    #[test]
+    fn test_acyclic_ok() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(QUEST_DEF);
+        assert!(ws.check_code_for(
+            DiagnosticCode::RefCycle,
+            r#"
+            ---@type TbQuest
+            local quests = {
+                { id = 1, prerequisites = {} },
+                { id = 2, prerequisites = { 1 } },
+                { id = 3, prerequisites = { 1, 2 } },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_acyclic_cycle() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(QUEST_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RefCycle,
+            r#"
+            ---@type TbQuest
+            local quests = {
+                { id = 1, prerequisites = { 3 } },
+                { id = 2, prerequisites = { 1 } },
+                { id = 3, prerequisites = { 2 } },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_acyclic_cycle_across_files() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(QUEST_DEF);
+        ws.def(
+            r#"
+            ---@type TbQuest
+            local quests = {
+                { id = 1, prerequisites = { 2 } },
+            }
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RefCycle,
+            r#"
+            ---@type TbQuest
+            local quests = {
+                { id = 2, prerequisites = { 1 } },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_acyclic_cycle_removed_file() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(QUEST_DEF);
+        ws.def_file(
+            "quest_a.lua",
+            r#"
+            ---@type TbQuest
+            local quests = {
+                { id = 1, prerequisites = { 2 } },
+            }
+            "#,
+        );
+        let quests = r#"
+            ---@type TbQuest
+            local quests = {
+                { id = 2, prerequisites = { 1 } },
+            }
+            "#;
+        assert!(!ws.check_code_for(DiagnosticCode::RefCycle, quests));
+
+        let uri = ws.virtual_url_generator.new_uri("quest_a.lua");
+        ws.analysis.remove_file_by_uri(&uri);
+        assert!(ws.check_code_for(DiagnosticCode::RefCycle, quests));
+    }
+
+    #[test]
+    fn test_acyclic_self_reference() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RefCycle,
+            r#"
+            ---@class Dialogue: Bean
+            ---@field id int
+            ---@[v.acyclic]
+            ---@field next int?
+
+            ---@class TbDialogue: ConfigTable
+            ---@field [int] Dialogue
+
+            ---@type TbDialogue
+            local dialogues = {
+                { id = 1, next = 1 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_tree_ok() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(SKILL_DEF);
+        let code = r#"
+            ---@type TbSkill
+            local skills = {
+                { id = 1 },
+                { id = 2, parentId = 1 },
+                { id = 3, parentId = 2 },
+                { id = 4, parentId = 1 },
+            }
+        "#;
+        assert!(ws.check_code_for(DiagnosticCode::RefCycle, code));
+        assert!(ws.check_code_for(DiagnosticCode::UnreachableRow, code));
+        assert!(ws.check_code_for(DiagnosticCode::TreeDepthExceeded, code));
+    }
+
+    #[test]
+    fn test_tree_unreachable() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(SKILL_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableRow,
+            r#"
+            ---@type TbSkill
+            local skills = {
+                { id = 1 },
+                { id = 2, parentId = 99 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_tree_cycle() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(SKILL_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RefCycle,
+            r#"
+            ---@type TbSkill
+            local skills = {
+                { id = 1, parentId = 2 },
+                { id = 2, parentId = 1 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_tree_depth() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(SKILL_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::TreeDepthExceeded,
+            r#"
+            ---@type TbSkill
+            local skills = {
+                { id = 1 },
+                { id = 2, parentId = 1 },
+                { id = 3, parentId = 2 },
+                { id = 4, parentId = 3 },
+            }
+            "#,
+        ));
+    }
+}
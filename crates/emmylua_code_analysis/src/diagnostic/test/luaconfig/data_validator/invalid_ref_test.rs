@@ -433,4 +433,100 @@ mod test {
             "#,
         ));
     }
+
+    #[test]
+    fn test_ref_target_file_removed() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+
+            ---@class User: Bean
+            ---@field id int
+            ---@[v.ref("TbItem")]
+            ---@field itemId int
+
+            ---@class TbUser: ConfigTable
+            ---@field [int] User
+            "#,
+        );
+        ws.def_file(
+            "item.lua",
+            r#"
+            ---@type TbItem
+            local items = { { id = 1 } }
+            "#,
+        );
+        let users = r#"
+            ---@type TbUser
+            local users = { { id = 1, itemId = 1 } }
+            "#;
+        assert!(ws.check_code_for(DiagnosticCode::InvalidRef, users));
+
+        let uri = ws.virtual_url_generator.new_uri("item.lua");
+        ws.analysis.remove_file_by_uri(&uri);
+        assert!(!ws.check_code_for(DiagnosticCode::InvalidRef, users));
+    }
+
+    #[test]
+    fn test_ref_enum_name() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@enum Quality
+            Quality = {
+                Common = 1,
+                Rare = 2,
+                Epic = 3,
+            }
+
+            ---@class QualityColor: Bean
+            ---@field quality Quality
+            ---@field color string
+
+            ---@class TbQualityColor: ConfigTable
+            ---@field [int] QualityColor
+
+            ---@class Item: Bean
+            ---@field id int
+            ---@[v.ref("TbQualityColor")]
+            ---@field quality Quality
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        ws.def(
+            r#"
+            ---@type TbQualityColor
+            local colors = {
+                { quality = "Common", color = "white" },
+                { quality = 2, color = "blue" },
+            }
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidRef,
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 1, quality = 1 },
+                { id = 2, quality = "Rare" },
+            }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidRef,
+            r#"
+            ---@type TbItem
+            local items = {
+                { id = 3, quality = "Epic" },
+            }
+            "#,
+        ));
+    }
 }
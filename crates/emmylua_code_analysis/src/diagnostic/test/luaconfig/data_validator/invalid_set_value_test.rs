@@ -77,4 +77,36 @@ mod test {
             "#,
         ));
     }
+
+    #[test]
+    fn test_vset_enum_name() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@enum Quality
+            Quality = {
+                Common = 1,
+                Rare = 2,
+                Epic = 3,
+            }
+
+            ---@class Drop: Bean
+            ---@field quality ([v.set([1, 2])] Quality)
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidSetValue,
+            r#"
+            ---@type Drop
+            local t = { quality = "Rare" }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidSetValue,
+            r#"
+            ---@type Drop
+            local t = { quality = "Epic" }
+            "#,
+        ));
+    }
 }
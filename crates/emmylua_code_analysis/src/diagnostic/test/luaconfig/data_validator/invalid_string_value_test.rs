@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_vregex_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.regex("^icon_[a-z0-9_]+$")]
+            ---@field icon string
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@type TestItem
+            local t = { icon = "Icon_Sword" }
+            "#,
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@type TestItem
+            local t = { icon = "icon_sword_01" }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vlen_counts_chars() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.len("[1,4]")]
+            ---@field name string
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@type TestItem
+            local t = { name = "火焰之剑" }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@type TestItem
+            local t = { name = "" }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vformat_presets() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.format("color")]
+            ---@field color string
+            ---@[v.format("uuid")]
+            ---@field guid string
+            ---@[v.format("int_list")]
+            ---@field ids string
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r##"
+            ---@type TestItem
+            local t = {
+                color = "#FF00aa",
+                guid = "123e4567-e89b-12d3-a456-426614174000",
+                ids = "1, 2,-3",
+            }
+            "##,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r##"
+            ---@type TestItem
+            local t = { color = "#FF00A" }
+            "##,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@type TestItem
+            local t = { guid = "123e4567e89b12d3a456426614174000" }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@type TestItem
+            local t = { ids = "1,2,x" }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_container_element_string_rules() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@type list<[v.regex("^[a-z]+$")] string>
+            local xs = { "abc", "Abc" }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@type map<[v.len(2)] string, int>
+            local m = { ab = 1, abc = 2 }
+            "#,
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r##"
+            ---@type map<[v.len(2)] string, [v.format("color")] string>
+            local m = { ab = "#000000" }
+            "##,
+        ));
+    }
+
+    #[test]
+    fn test_invalid_signature_is_ignored() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidStringValue,
+            r#"
+            ---@class TestItem: Bean
+            ---@[v.regex("^icon_[a-z")]
+            ---@field icon string
+
+            ---@type TestItem
+            local t = { icon = "whatever" }
+            "#,
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    const STAGE_DEF: &str = r#"
+        ---@class Stage: Bean
+        ---@field chapterId int
+        ---@field stageId int
+        ---@field name string
+
+        ---@[t.index(["chapterId", "stageId"])]
+        ---@class TbStage: ConfigTable
+        ---@field [int] Stage
+
+        ---@type TbStage
+        local stages = {
+            { chapterId = 1, stageId = 1, name = "A" },
+            { chapterId = 1, stageId = 2, name = "B" },
+            { chapterId = 2, stageId = 1, name = "C" },
+        }
+    "#;
+
+    #[test]
+    fn test_union_ref_value() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(STAGE_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidRef,
+            r#"
+            ---@[v.union_ref("TbStage", ["chapter", "stage"])]
+            ---@class Drop: Bean
+            ---@field id int
+            ---@field chapter int
+            ---@field stage int
+
+            ---@class TbDrop: ConfigTable
+            ---@field [int] Drop
+
+            ---@type TbDrop
+            local drops = {
+                { id = 1, chapter = 1, stage = 2 },
+                { id = 2, chapter = 2, stage = 2 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_union_ref_ok() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(STAGE_DEF);
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidRef,
+            r#"
+            ---@[v.union_ref("TbStage", ["stage", "chapter"], ["stageId", "chapterId"])]
+            ---@class Drop: Bean
+            ---@field id int
+            ---@field chapter int
+            ---@field stage int
+
+            ---@class TbDrop: ConfigTable
+            ---@field [int] Drop
+
+            ---@type TbDrop
+            local drops = {
+                { id = 1, chapter = 1, stage = 2 },
+                { id = 2, chapter = 2, stage = 1 },
+                { id = 3, chapter = 9 }, -- 字段缺失时不校验
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_union_ref_requires_union_keys() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidRefSignature,
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+
+            ---@[v.union_ref("TbItem", ["itemId"])]
+            ---@class Reward: Bean
+            ---@field itemId int
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_union_ref_field_count_mismatch() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(STAGE_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidRefSignature,
+            r#"
+            ---@[v.union_ref("TbStage", ["chapter"])]
+            ---@class Drop: Bean
+            ---@field chapter int
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_union_ref_unknown_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(STAGE_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidRefSignature,
+            r#"
+            ---@[v.union_ref("TbStage", ["chapter", "missing"])]
+            ---@class Drop: Bean
+            ---@field chapter int
+            "#,
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidRefSignature,
+            r#"
+            ---@[v.union_ref("TbStage", ["chapter", "stage"])]
+            ---@class Drop2: Bean
+            ---@field chapter int
+            ---@field stage int
+            "#,
+        ));
+    }
+}
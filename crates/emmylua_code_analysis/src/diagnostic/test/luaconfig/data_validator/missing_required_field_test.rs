@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_missing_required_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class TestItem: Bean
+            ---@field id int
+            ---@field name string
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingRequiredField,
+            r#"
+            ---@type TestItem
+            local t = { id = 1 }
+            "#,
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingRequiredField,
+            r#"
+            ---@type TestItem
+            local t = { id = 1, name = "sword" }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_optional_and_default_fields() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class TestItem: Bean
+            ---@field id int
+            ---@field icon string?
+            ---@[v.default(1)]
+            ---@field stack int
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingRequiredField,
+            r#"
+            ---@type TestItem
+            local t = { id = 1 }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_inherited_required_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class TestBase: Bean
+            ---@field id int
+
+            ---@class TestItem: TestBase
+            ---@field name string
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingRequiredField,
+            r#"
+            ---@type TestItem
+            local t = { name = "sword" }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_config_table_rows() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class TestItem: Bean
+            ---@field id int
+            ---@field name string
+            ---@[v.default(1)]
+            ---@field stack int
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] TestItem
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingRequiredField,
+            r#"
+            ---@type TbItem
+            local t = {
+                [1] = { id = 1, name = "sword" },
+                [2] = { id = 2 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_missing_fields_skips_beans() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingFields,
+            r#"
+            ---@class TestItem: Bean
+            ---@field id int
+            ---@[v.default(1)]
+            ---@field stack int
+
+            ---@type TestItem
+            local t = { id = 1 }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_default_field_in_container_type_check() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@class TestItem: Bean
+            ---@field id int
+            ---@[v.default(1)]
+            ---@field stack int
+
+            ---@type TestItem[]
+            local items = { { id = 1 } }
+            "#,
+        ));
+    }
+}
@@ -1,8 +1,18 @@
 mod duplicate_index_value_test;
 mod duplicate_primary_key_test;
 mod duplicate_set_element_test;
+mod duplicate_unique_value_test;
+mod enum_coverage_test;
+mod external_validator_test;
+mod invalid_graph_test;
 mod invalid_index_field_test;
 mod invalid_range_value_test;
 mod invalid_ref_test;
 mod invalid_set_value_test;
 mod invalid_size_value_test;
+mod invalid_string_value_test;
+mod invalid_union_ref_test;
+mod missing_required_field_test;
+mod unknown_enum_name_test;
+mod vector_type_test;
+mod weight_sum_test;
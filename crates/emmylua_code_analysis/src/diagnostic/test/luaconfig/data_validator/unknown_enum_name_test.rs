@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    const DEFS: &str = r#"
+        ---@enum Quality
+        Quality = {
+            White = 1,
+            Green = 2,
+            ---@[alias("红")]
+            Red = 3,
+        }
+
+        ---@[flags]
+        ---@enum Element
+        Element = {
+            None = 0,
+            Fire = 1,
+            Ice = 2,
+        }
+
+        ---@class TestItem: Bean
+        ---@field id int
+        ---@field quality Quality
+        ---@field element Element?
+        ---@field tags list<Quality>
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] TestItem
+    "#;
+
+    #[test]
+    fn test_enum_name_accepted() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DEFS);
+        let code = r#"
+            ---@type TbItem
+            local t = {
+                [1] = { id = 1, quality = "Red", element = "Fire|Ice", tags = { "White", 2 } },
+                [2] = { id = 2, quality = "红", tags = {} },
+                [3] = { id = 3, quality = Quality.Green, element = 1, tags = {} },
+            }
+        "#;
+        assert!(ws.check_code_for(DiagnosticCode::UnknownEnumName, code));
+        assert!(ws.check_code_for(DiagnosticCode::AssignTypeMismatch, code));
+    }
+
+    #[test]
+    fn test_whole_table_type_check() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DEFS);
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type TestItem[]
+            local items = { { id = 1, quality = "Red", element = "Fire | Ice", tags = { "Green" } } }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_unknown_enum_name() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DEFS);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnknownEnumName,
+            r#"
+            ---@type TbItem
+            local t = { [1] = { id = 1, quality = "Purple", tags = {} } }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnknownEnumName,
+            r#"
+            ---@type TbItem
+            local t = { [1] = { id = 1, quality = "Red", element = "Fire|Wind", tags = {} } }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnknownEnumName,
+            r#"
+            ---@type TbItem
+            local t = { [1] = { id = 1, quality = "Red", tags = { "Blue" } } }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_enum_name_only_in_bean_data() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DEFS);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeMismatch,
+            r#"
+            ---@param q Quality
+            local function f(q) end
+            f("Red")
+            "#,
+        ));
+        // 非 flags 枚举不支持 `|` 组合
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnknownEnumName,
+            r#"
+            ---@type TbItem
+            local t = { [1] = { id = 1, quality = "Red|Green", tags = {} } }
+            "#,
+        ));
+    }
+}
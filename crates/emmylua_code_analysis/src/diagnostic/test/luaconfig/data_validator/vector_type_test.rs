@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    const DEFS: &str = r#"
+        ---@class TestUnit: Bean
+        ---@field id int
+        ---@field pos vector3
+        ---@field size vector2?
+        ---@field tint color?
+        ---@[v.range("[0,10]")]
+        ---@field offset vector2?
+        ---@field path list<vector2>?
+
+        ---@class TbUnit: ConfigTable
+        ---@field [int] TestUnit
+    "#;
+
+    #[test]
+    fn test_vector_literal_forms() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DEFS);
+        let code = r#"
+            ---@type TbUnit
+            local t = {
+                [1] = { id = 1, pos = { x = 1, y = 2, z = 3 }, size = { 1, 2 } },
+                [2] = { id = 2, pos = { 1, 2.5, 3 }, tint = { 1, 0.5, 0 }, path = { { 0, 0 }, { x = 1, y = 1 } } },
+            }
+        "#;
+        assert!(ws.check_code_for(DiagnosticCode::AssignTypeMismatch, code));
+        assert!(ws.check_code_for(DiagnosticCode::MissingRequiredField, code));
+    }
+
+    #[test]
+    fn test_vector_component_mismatch() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DEFS);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type TestUnit[]
+            local units = { { id = 1, pos = { 1, "2", 3 } } }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type TestUnit[]
+            local units = { { id = 1, pos = { 1, 2 } } }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type TestUnit[]
+            local units = { { id = 1, pos = { 1, 2, 3, 4 } } }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_vector_component_range() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DEFS);
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidRangeValue,
+            r#"
+            ---@type TbUnit
+            local t = { [1] = { id = 1, pos = { 1, 2, 3 }, offset = { 0, 10 } } }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidRangeValue,
+            r#"
+            ---@type TbUnit
+            local t = { [1] = { id = 1, pos = { 1, 2, 3 }, offset = { 1, 11 } } }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidRangeValue,
+            r#"
+            ---@type TbUnit
+            local t = { [1] = { id = 1, pos = { 1, 2, 3 }, offset = { x = -1, y = 0 } } }
+            "#,
+        ));
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::DiagnosticCode;
+
+    const DROP_DEF: &str = r#"
+        ---@class DropItem: Bean
+        ---@field itemId int
+        ---@[v.weight_sum(100)]
+        ---@field weight int
+
+        ---@class Drop: Bean
+        ---@field id int
+        ---@field items list<DropItem>
+
+        ---@class TbDrop: ConfigTable
+        ---@field [int] Drop
+    "#;
+
+    const GACHA_DEF: &str = r#"
+        ---@class Gacha: Bean
+        ---@field id int
+        ---@field poolId int
+        ---@[v.prob_sum("poolId")]
+        ---@field prob float
+
+        ---@class TbGacha: ConfigTable
+        ---@field [int] Gacha
+    "#;
+
+    #[test]
+    fn test_weight_sum_in_list() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DROP_DEF);
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidWeightSum,
+            r#"
+            ---@type TbDrop
+            local drops = {
+                { id = 1, items = { { itemId = 1, weight = 30 }, { itemId = 2, weight = 70 } } },
+                { id = 2, items = { { itemId = 3, weight = 100 } } },
+            }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidWeightSum,
+            r#"
+            ---@type TbDrop
+            local drops = {
+                { id = 1, items = { { itemId = 1, weight = 30 }, { itemId = 2, weight = 60 } } },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_weight_must_be_positive() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(DROP_DEF);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidWeightSum,
+            r#"
+            ---@type TbDrop
+            local drops = {
+                { id = 1, items = { { itemId = 1, weight = 0 }, { itemId = 2, weight = 100 } } },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_prob_sum_group_by() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(GACHA_DEF);
+        assert!(ws.check_code_for(
+            DiagnosticCode::InvalidWeightSum,
+            r#"
+            ---@type TbGacha
+            local gacha = {
+                { id = 1, poolId = 1, prob = 0.1 },
+                { id = 2, poolId = 1, prob = 0.2 },
+                { id = 3, poolId = 1, prob = 0.7 },
+                { id = 4, poolId = 2, prob = 1 },
+            }
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InvalidWeightSum,
+            r#"
+            ---@type TbGacha
+            local gacha = {
+                { id = 1, poolId = 1, prob = 0.5 },
+                { id = 2, poolId = 2, prob = 0.5 },
+            }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_prob_sum_across_files() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::InvalidWeightSum);
+        ws.def(GACHA_DEF);
+        ws.def_file(
+            "gacha_a.lua",
+            r#"
+            ---@type TbGacha
+            local gacha = {
+                { id = 1, poolId = 1, prob = 0.4 },
+            }
+            "#,
+        );
+        let file_id = ws.def_file(
+            "gacha_b.lua",
+            r#"
+            ---@type TbGacha
+            local gacha = {
+                { id = 2, poolId = 1, prob = 0.6 },
+                { id = 3, poolId = 2, prob = 0.9 },
+            }
+            "#,
+        );
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::InvalidWeightSum.get_name().to_string(),
+        ));
+        let messages: Vec<String> = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code == code)
+            .map(|diagnostic| diagnostic.message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec!["Sum of `prob` in group `2` is 0.9, expected 1".to_string()]
+        );
+    }
+}
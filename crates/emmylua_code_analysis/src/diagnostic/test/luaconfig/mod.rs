@@ -1,2 +1,4 @@
 mod attribute;
+mod batch_validation_test;
+mod data_mode_test;
 mod data_validator;
@@ -199,7 +199,10 @@ impl EmmyLuaAnalysis {
                 Some((uri, text))
             })
             .collect();
-        self.update_files_by_uri(files)
+        let file_ids = self.update_files_by_uri(files);
+        // 批量加载后会诊断整个工作区, 不需要追踪配置表的变化
+        self.discard_config_table_changes();
+        file_ids
     }
 
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
@@ -221,6 +224,32 @@ impl EmmyLuaAnalysis {
             .diagnose_file(&self.compilation, file_id, cancel_token)
     }
 
+    /// 允许执行 `diagnostics.externalValidators` 中配置的外部程序. 项目配置可能来自不受信任的来源,
+    /// 因此需要使用者显式开启
+    pub fn set_external_validators_trusted(&mut self, trusted: bool) {
+        self.diagnostic.set_external_validators_trusted(trusted);
+    }
+
+    /// 诊断文件但不执行配置表数据校验, 配置表数据由 `diagnose_luaconfig_files` 批量校验
+    pub fn diagnose_file_without_luaconfig(
+        &self,
+        file_id: FileId,
+        cancel_token: CancellationToken,
+    ) -> Option<Vec<lsp_types::Diagnostic>> {
+        self.diagnostic
+            .diagnose_file_without_luaconfig(&self.compilation, file_id, cancel_token)
+    }
+
+    /// 并行校验多个文件的配置表数据, 线程数由 `diagnostics.luaconfigThreads` 指定
+    pub fn diagnose_luaconfig_files(
+        &self,
+        file_ids: &[FileId],
+        cancel_token: CancellationToken,
+    ) -> Vec<(FileId, Vec<lsp_types::Diagnostic>)> {
+        self.diagnostic
+            .diagnose_luaconfig_files(&self.compilation, file_ids, cancel_token)
+    }
+
     pub fn reindex(&mut self) {
         let module = self.compilation.get_db().get_module_index();
         let std_file_ids = module.get_std_file_ids();
@@ -231,6 +260,21 @@ impl EmmyLuaAnalysis {
         self.compilation.update_index(std_file_ids);
         self.compilation.update_index(lib_file_ids);
         self.compilation.update_index(main_file_ids);
+        self.discard_config_table_changes();
+    }
+
+    /// 取出自上次调用以来主键值集合发生变化的配置表, 返回数据引用了这些配置表的文件
+    pub fn take_config_table_dependents(&mut self) -> Vec<FileId> {
+        let db = self.compilation.get_db_mut();
+        let tables = db.get_config_index_mut().take_changed_config_tables();
+        shared::luaconfig::find_config_table_dependents(db, &tables)
+    }
+
+    fn discard_config_table_changes(&mut self) {
+        self.compilation
+            .get_db_mut()
+            .get_config_index_mut()
+            .take_changed_config_tables();
     }
 
     /// 清理文件系统中不再存在的文件
@@ -1,13 +1,30 @@
 use crate::{LuaAttributeUse, LuaCommonProperty, LuaType};
 
 mod v_range;
+mod v_ref;
 mod v_set;
 mod v_size;
+mod v_string;
+mod v_union_ref;
+mod v_weight;
 
 pub use v_range::{RangeEnd, RangeParseError, RangeSpec, VRangeAttribute, parse_range_spec};
+pub use v_ref::{VRefAttribute, VRefSignatureError, parse_vref_signature};
 pub(crate) use v_set::parse_set_spec_type;
 pub use v_set::{SetParseError, SetSpec, SetValue, VSetAttribute};
+pub(crate) use v_size::parse_size_type;
 pub use v_size::{SizeSpec, VSizeAttribute};
+pub use v_string::{
+    StringFormat, StringSpec, StringViolation, VFormatAttribute, VLenAttribute, VRegexAttribute,
+    compile_pattern,
+};
+pub(crate) use v_union_ref::collect_string_names;
+pub use v_union_ref::{
+    VUnionRefAttribute, VUnionRefSignatureError, VUnionRefTarget, parse_vunion_ref_signature,
+};
+pub use v_weight::{
+    VProbSumAttribute, VWeightSumAttribute, WEIGHT_SUM_EPSILON, WeightKind, WeightSpec,
+};
 
 /// 配置表索引模式
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -56,6 +73,219 @@ impl<'a> TIndexAttribute<'a> {
     }
 }
 
+/// 定义配置表非主键字段的唯一性约束
+pub struct TUniqueAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> TUniqueAttribute<'a> {
+    pub const NAME: &'static str = "t.unique";
+
+    pub fn find_all_in(property: &'a LuaCommonProperty) -> Vec<Self> {
+        property
+            .attribute_uses
+            .as_deref()
+            .map(|uses| {
+                uses.iter()
+                    .filter(|attribute_use| attribute_use.id.get_name() == Self::NAME)
+                    .map(|inner| Self { inner })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 获取唯一字段列表
+    pub fn get_fields(&self) -> Vec<smol_str::SmolStr> {
+        self.inner
+            .get_param_by_name("fields")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))
+            .map(collect_string_names)
+            .unwrap_or_default()
+    }
+}
+
+/// 将配置表视为以父字段连接的树
+pub struct TTreeAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> TTreeAttribute<'a> {
+    pub const NAME: &'static str = "t.tree";
+
+    pub fn find_in(property: &'a LuaCommonProperty) -> Option<Self> {
+        property
+            .find_attribute_use(Self::NAME)
+            .map(|inner| Self { inner })
+    }
+
+    /// 获取父字段名称
+    pub fn get_parent(&self) -> Option<&str> {
+        let ty = self
+            .inner
+            .get_param_by_name("parent")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
+
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+            _ => None,
+        }
+    }
+
+    /// 获取树的最大层数
+    pub fn get_max_depth(&self) -> Option<usize> {
+        let ty = self
+            .inner
+            .get_param_by_name("maxDepth")
+            .or_else(|| self.inner.args.get(1).and_then(|(_, t)| t.as_ref()))?;
+
+        match ty {
+            LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => usize::try_from(*i).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// 检查配置表字段值覆盖枚举的所有成员
+pub struct TCoverEnumAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> TCoverEnumAttribute<'a> {
+    pub const NAME: &'static str = "t.cover_enum";
+
+    pub fn find_all_in(property: &'a LuaCommonProperty) -> Vec<Self> {
+        property
+            .attribute_uses
+            .as_deref()
+            .map(|uses| {
+                uses.iter()
+                    .filter(|attribute_use| attribute_use.id.get_name() == Self::NAME)
+                    .map(|inner| Self { inner })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_field(&self) -> Option<&str> {
+        self.get_string_param("field", 0)
+    }
+
+    pub fn get_enum_name(&self) -> Option<&str> {
+        self.get_string_param("enumName", 1)
+    }
+
+    fn get_string_param(&self, name: &str, idx: usize) -> Option<&str> {
+        let ty = self
+            .inner
+            .get_param_by_name(name)
+            .or_else(|| self.inner.args.get(idx).and_then(|(_, t)| t.as_ref()))?;
+
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// 检查字段对同表记录的引用不形成环
+pub struct VAcyclicAttribute;
+
+impl VAcyclicAttribute {
+    pub const NAME: &'static str = "v.acyclic";
+
+    pub fn is_in(property: &LuaCommonProperty) -> bool {
+        property.find_attribute_use(Self::NAME).is_some()
+    }
+}
+
+/// 字段的默认值, 数据中省略该字段时使用
+pub struct VDefaultAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> VDefaultAttribute<'a> {
+    pub const NAME: &'static str = "v.default";
+
+    pub fn find_in(property: &'a LuaCommonProperty) -> Option<Self> {
+        property
+            .find_attribute_use(Self::NAME)
+            .map(|inner| Self { inner })
+    }
+
+    /// 获取默认值, 仅支持字面量
+    pub fn get_value(&self) -> Option<&LuaType> {
+        let ty = self
+            .inner
+            .get_param_by_name("value")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
+
+        match ty {
+            LuaType::IntegerConst(_)
+            | LuaType::DocIntegerConst(_)
+            | LuaType::FloatConst(_)
+            | LuaType::StringConst(_)
+            | LuaType::DocStringConst(_)
+            | LuaType::BooleanConst(_)
+            | LuaType::DocBooleanConst(_) => Some(ty),
+            _ => None,
+        }
+    }
+}
+
+/// 枚举成员的别名, 配置数据中可以使用别名代替成员名
+pub struct AliasAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> AliasAttribute<'a> {
+    pub const NAME: &'static str = "alias";
+
+    pub fn find_in(property: &'a LuaCommonProperty) -> Option<Self> {
+        property
+            .find_attribute_use(Self::NAME)
+            .map(|inner| Self { inner })
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        let ty = self
+            .inner
+            .get_param_by_name("name")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
+
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// 从 CSV 导入时容器字段单元格中元素的分隔符
+pub struct SepAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> SepAttribute<'a> {
+    pub const NAME: &'static str = "sep";
+
+    pub fn find_in(property: &'a LuaCommonProperty) -> Option<Self> {
+        property
+            .find_attribute_use(Self::NAME)
+            .map(|inner| Self { inner })
+    }
+
+    pub fn get_separator(&self) -> Option<&str> {
+        let ty = self
+            .inner
+            .get_param_by_name("separator")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
+
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+            _ => None,
+        }
+    }
+}
+
 /// 配置表模式
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ConfigTableMode {
@@ -135,52 +365,6 @@ impl<'a> VIndexAttribute<'a> {
     }
 }
 
-/// 检查字段值是否为配置表合法 key
-pub struct VRefAttribute<'a> {
-    inner: &'a LuaAttributeUse,
-}
-
-impl<'a> VRefAttribute<'a> {
-    pub const NAME: &'static str = "v.ref";
-
-    pub fn find_in(property: &'a LuaCommonProperty) -> Option<Self> {
-        property
-            .find_attribute_use(Self::NAME)
-            .map(|inner| Self { inner })
-    }
-
-    pub fn find_in_uses(attribute_uses: &'a [LuaAttributeUse]) -> Option<Self> {
-        attribute_uses
-            .iter()
-            .find(|attribute_use| attribute_use.id.get_name() == Self::NAME)
-            .map(|inner| Self { inner })
-    }
-
-    pub fn get_table_name(&self) -> Option<&str> {
-        let ty = self
-            .inner
-            .get_param_by_name("tableName")
-            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
-
-        match ty {
-            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
-            _ => None,
-        }
-    }
-
-    pub fn get_field_name(&self) -> Option<&str> {
-        let ty = self
-            .inner
-            .get_param_by_name("field")
-            .or_else(|| self.inner.args.get(1).and_then(|(_, t)| t.as_ref()))?;
-
-        match ty {
-            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
-            _ => None,
-        }
-    }
-}
-
 pub fn is_flags_attribute(property: &LuaCommonProperty) -> bool {
     property.find_attribute_use("flags").is_some()
 }
@@ -0,0 +1,209 @@
+use crate::{
+    DbIndex, FileId, LuaAttributeUse, LuaCommonProperty, LuaMemberKey, LuaType, LuaTypeDeclId,
+    semantic::shared::luaconfig::CONFIG_TABLE,
+};
+
+use super::ConfigTableMode;
+
+/// 检查字段值是否为配置表合法 key
+pub struct VRefAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> VRefAttribute<'a> {
+    pub const NAME: &'static str = "v.ref";
+
+    pub fn find_in(property: &'a LuaCommonProperty) -> Option<Self> {
+        property
+            .find_attribute_use(Self::NAME)
+            .map(|inner| Self { inner })
+    }
+
+    pub fn find_in_uses(attribute_uses: &'a [LuaAttributeUse]) -> Option<Self> {
+        attribute_uses
+            .iter()
+            .find(|attribute_use| attribute_use.id.get_name() == Self::NAME)
+            .map(|inner| Self { inner })
+    }
+
+    pub fn get_table_name(&self) -> Option<&str> {
+        let ty = self
+            .inner
+            .get_param_by_name("tableName")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
+
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+            _ => None,
+        }
+    }
+
+    /// 解析引用目标, 返回目标配置表与其主键
+    pub fn resolve_target(
+        &self,
+        db: &DbIndex,
+        file_id: FileId,
+    ) -> Option<(LuaTypeDeclId, LuaMemberKey)> {
+        parse_vref_signature(db, file_id, self.get_table_name()?, self.get_field_name()).ok()
+    }
+
+    pub fn get_field_name(&self) -> Option<&str> {
+        let ty = self
+            .inner
+            .get_param_by_name("field")
+            .or_else(|| self.inner.args.get(1).and_then(|(_, t)| t.as_ref()))?;
+
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum VRefSignatureError {
+    UnknownConfigTable { table: String },
+    NotConfigTable { table: String },
+    NoPrimaryKeys { table: LuaTypeDeclId },
+    MapMustHaveExactlyOnePrimaryKey { table: LuaTypeDeclId },
+    MapNonNamePrimaryKey { table: LuaTypeDeclId },
+    MapPrimaryKeyMismatch { table: LuaTypeDeclId, pk: String },
+    ListRequiresField { table: LuaTypeDeclId },
+    FieldNotPrimaryKey { table: LuaTypeDeclId, field: String },
+    UnsupportedSingleton,
+}
+
+impl VRefSignatureError {
+    pub fn to_message(&self) -> String {
+        match self {
+            VRefSignatureError::UnknownConfigTable { table } => t!(
+                "Invalid v.ref: unknown config table `%{table}`",
+                table = table
+            )
+            .to_string(),
+            VRefSignatureError::NotConfigTable { table } => t!(
+                "Invalid v.ref: `%{table}` is not a `ConfigTable`",
+                table = table
+            )
+            .to_string(),
+            VRefSignatureError::NoPrimaryKeys { table } => t!(
+                "Invalid v.ref: `%{table}` has no primary keys",
+                table = table.get_name()
+            )
+            .to_string(),
+            VRefSignatureError::MapMustHaveExactlyOnePrimaryKey { table } => t!(
+                "Invalid v.ref: map table `%{table}` must have exactly one primary key",
+                table = table.get_name()
+            )
+            .to_string(),
+            VRefSignatureError::MapNonNamePrimaryKey { table } => t!(
+                "Invalid v.ref: map table `%{table}` has non-name primary key",
+                table = table.get_name()
+            )
+            .to_string(),
+            VRefSignatureError::MapPrimaryKeyMismatch { table, pk } => t!(
+                "Invalid v.ref: map table `%{table}` primary key is `%{pk}`",
+                table = table.get_name(),
+                pk = pk
+            )
+            .to_string(),
+            VRefSignatureError::ListRequiresField { table } => t!(
+                "Invalid v.ref: list table `%{table}` requires explicit `field`",
+                table = table.get_name()
+            )
+            .to_string(),
+            VRefSignatureError::FieldNotPrimaryKey { table, field } => t!(
+                "Invalid v.ref: `%{field}` is not a primary key of `%{table}`",
+                field = field,
+                table = table.get_name()
+            )
+            .to_string(),
+            VRefSignatureError::UnsupportedSingleton => String::new(),
+        }
+    }
+}
+
+pub fn parse_vref_signature(
+    db: &DbIndex,
+    file_id: FileId,
+    target_table_name: &str,
+    target_field_name: Option<&str>,
+) -> Result<(LuaTypeDeclId, LuaMemberKey), VRefSignatureError> {
+    let Some(target_decl) = db
+        .get_type_index()
+        .find_type_decl(file_id, target_table_name)
+    else {
+        return Err(VRefSignatureError::UnknownConfigTable {
+            table: target_table_name.to_string(),
+        });
+    };
+
+    let target_table_id = target_decl.get_id();
+    if !CONFIG_TABLE.is_config_table(db, &target_table_id) {
+        return Err(VRefSignatureError::NotConfigTable {
+            table: target_table_name.to_string(),
+        });
+    }
+
+    let mode = db
+        .get_config_index()
+        .get_config_table_mode(&target_table_id);
+    if mode == ConfigTableMode::Singleton {
+        return Err(VRefSignatureError::UnsupportedSingleton);
+    }
+
+    let Some(index_keys) = db
+        .get_config_index()
+        .get_config_table_keys(&target_table_id)
+    else {
+        return Err(VRefSignatureError::NoPrimaryKeys {
+            table: target_table_id,
+        });
+    };
+
+    let keys = index_keys.keys();
+    match mode {
+        ConfigTableMode::Map => {
+            if keys.len() != 1 {
+                return Err(VRefSignatureError::MapMustHaveExactlyOnePrimaryKey {
+                    table: target_table_id,
+                });
+            }
+
+            let pk = keys[0].clone();
+            if let Some(field_name) = target_field_name {
+                let Some(pk_name) = pk.get_name() else {
+                    return Err(VRefSignatureError::MapNonNamePrimaryKey {
+                        table: target_table_id,
+                    });
+                };
+                if pk_name != field_name {
+                    return Err(VRefSignatureError::MapPrimaryKeyMismatch {
+                        table: target_table_id,
+                        pk: pk_name.to_string(),
+                    });
+                }
+            }
+
+            Ok((target_table_id, pk))
+        }
+        ConfigTableMode::List => {
+            let Some(field_name) = target_field_name else {
+                return Err(VRefSignatureError::ListRequiresField {
+                    table: target_table_id,
+                });
+            };
+
+            let field_key = LuaMemberKey::Name(field_name.to_string().into());
+            if !keys.iter().any(|k| k == &field_key) {
+                return Err(VRefSignatureError::FieldNotPrimaryKey {
+                    table: target_table_id,
+                    field: field_name.to_string(),
+                });
+            }
+
+            Ok((target_table_id, field_key))
+        }
+        ConfigTableMode::Singleton => Err(VRefSignatureError::UnsupportedSingleton),
+    }
+}
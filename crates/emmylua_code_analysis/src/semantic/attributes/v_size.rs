@@ -31,27 +31,48 @@ impl fmt::Display for SizeSpec {
     }
 }
 
-fn validate_size_range(spec: RangeSpec) -> Result<SizeSpec, RangeParseError> {
+fn validate_size_range(spec: RangeSpec, label: &str) -> Result<SizeSpec, RangeParseError> {
     if let Some(min) = spec.min {
         if !min.is_finite() || min.fract() != 0.0 {
-            return Err(RangeParseError::new("size min must be an integer"));
+            return Err(RangeParseError::new(format!(
+                "{label} min must be an integer"
+            )));
         }
         if min < 0.0 {
-            return Err(RangeParseError::new("size min must be >= 0"));
+            return Err(RangeParseError::new(format!("{label} min must be >= 0")));
         }
     }
     if let Some(max) = spec.max {
         if !max.is_finite() || max.fract() != 0.0 {
-            return Err(RangeParseError::new("size max must be an integer"));
+            return Err(RangeParseError::new(format!(
+                "{label} max must be an integer"
+            )));
         }
         if max < 0.0 {
-            return Err(RangeParseError::new("size max must be >= 0"));
+            return Err(RangeParseError::new(format!("{label} max must be >= 0")));
         }
     }
 
     Ok(SizeSpec { inner: spec })
 }
 
+/// 解析整数或区间字符串形式的长度参数, `label` 用于错误信息
+pub(crate) fn parse_size_type(ty: &LuaType, label: &str) -> Result<SizeSpec, RangeParseError> {
+    match ty {
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => {
+            if *i < 0 {
+                return Err(RangeParseError::new(format!("{label} must be >= 0")));
+            }
+            Ok(SizeSpec::exact(*i as usize))
+        }
+        LuaType::DocStringConst(s) | LuaType::StringConst(s) => {
+            let range = parse_range_spec(s.as_ref())?;
+            validate_size_range(range, label)
+        }
+        _ => Err(RangeParseError::new(format!("invalid {label} parameter"))),
+    }
+}
+
 /// Luban size validator attribute.
 pub struct VSizeAttribute<'a> {
     inner: &'a LuaAttributeUse,
@@ -94,18 +115,6 @@ impl<'a> VSizeAttribute<'a> {
             return Err(RangeParseError::new("missing size parameter"));
         };
 
-        match first {
-            LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => {
-                if *i < 0 {
-                    return Err(RangeParseError::new("size must be >= 0"));
-                }
-                Ok(SizeSpec::exact(*i as usize))
-            }
-            LuaType::DocStringConst(s) | LuaType::StringConst(s) => {
-                let range = parse_range_spec(s.as_ref())?;
-                validate_size_range(range)
-            }
-            _ => Err(RangeParseError::new("invalid size parameter")),
-        }
+        parse_size_type(first, "size")
     }
 }
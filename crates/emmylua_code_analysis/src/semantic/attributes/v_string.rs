@@ -0,0 +1,272 @@
+use std::fmt;
+
+use regex::Regex;
+
+use crate::{LuaAttributeUse, LuaType};
+
+use super::{RangeParseError, SizeSpec, parse_size_type};
+
+/// 检查字符串是否匹配正则表达式
+pub struct VRegexAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> VRegexAttribute<'a> {
+    pub const NAME: &'static str = "v.regex";
+
+    pub fn find_all_in_uses(attribute_uses: &'a [LuaAttributeUse]) -> Vec<Self> {
+        attribute_uses
+            .iter()
+            .filter(|attribute_use| attribute_use.id.get_name() == Self::NAME)
+            .map(|inner| Self { inner })
+            .collect()
+    }
+
+    pub fn get_pattern(&self) -> Option<&str> {
+        let ty = self
+            .inner
+            .get_param_by_name("pattern")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+            _ => None,
+        }
+    }
+
+    /// 编译正则表达式, 不合法的表达式返回错误的最后一行说明
+    pub fn compile(&self) -> Result<Regex, String> {
+        let Some(pattern) = self.get_pattern() else {
+            return Err("missing pattern parameter".to_string());
+        };
+        compile_pattern(pattern)
+    }
+}
+
+pub fn compile_pattern(pattern: &str) -> Result<Regex, String> {
+    Regex::new(pattern).map_err(|err| {
+        let message = err.to_string();
+        message
+            .lines()
+            .last()
+            .map(|line| line.trim_start_matches("error:").trim().to_string())
+            .unwrap_or(message)
+    })
+}
+
+/// 检查字符串的字符个数
+pub struct VLenAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> VLenAttribute<'a> {
+    pub const NAME: &'static str = "v.len";
+
+    pub fn find_all_in_uses(attribute_uses: &'a [LuaAttributeUse]) -> Vec<Self> {
+        attribute_uses
+            .iter()
+            .filter(|attribute_use| attribute_use.id.get_name() == Self::NAME)
+            .map(|inner| Self { inner })
+            .collect()
+    }
+
+    pub fn parse(&self) -> Result<SizeSpec, RangeParseError> {
+        let Some(first) = self
+            .inner
+            .get_param_by_name("len")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))
+        else {
+            return Err(RangeParseError::new("missing len parameter"));
+        };
+
+        parse_size_type(first, "len")
+    }
+}
+
+/// 检查字符串是否符合预设格式
+pub struct VFormatAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> VFormatAttribute<'a> {
+    pub const NAME: &'static str = "v.format";
+
+    pub fn find_all_in_uses(attribute_uses: &'a [LuaAttributeUse]) -> Vec<Self> {
+        attribute_uses
+            .iter()
+            .filter(|attribute_use| attribute_use.id.get_name() == Self::NAME)
+            .map(|inner| Self { inner })
+            .collect()
+    }
+
+    pub fn get_format(&self) -> Option<StringFormat> {
+        let ty = self
+            .inner
+            .get_param_by_name("format")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => StringFormat::from_name(s),
+            _ => None,
+        }
+    }
+}
+
+/// `v.format` 支持的预设格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringFormat {
+    /// `#RRGGBB` 或 `#RRGGBBAA`
+    Color,
+    /// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    Uuid,
+    /// 逗号分隔的整数列表, 如 `1,2,3`
+    IntList,
+    /// 逗号分隔的数字列表, 如 `1.5,2,3`
+    FloatList,
+}
+
+impl StringFormat {
+    pub const ALL: &'static [StringFormat] = &[
+        StringFormat::Color,
+        StringFormat::Uuid,
+        StringFormat::IntList,
+        StringFormat::FloatList,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|format| format.get_name() == name)
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            StringFormat::Color => "color",
+            StringFormat::Uuid => "uuid",
+            StringFormat::IntList => "int_list",
+            StringFormat::FloatList => "float_list",
+        }
+    }
+
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            StringFormat::Color => value.strip_prefix('#').is_some_and(|hex| {
+                matches!(hex.len(), 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+            }),
+            StringFormat::Uuid => {
+                let groups: Vec<&str> = value.split('-').collect();
+                groups.len() == 5
+                    && groups.iter().zip([8, 4, 4, 4, 12]).all(|(group, len)| {
+                        group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit())
+                    })
+            }
+            StringFormat::IntList => {
+                value.is_empty()
+                    || value
+                        .split(',')
+                        .all(|item| item.trim().parse::<i64>().is_ok())
+            }
+            StringFormat::FloatList => {
+                value.is_empty()
+                    || value.split(',').all(|item| {
+                        item.trim()
+                            .parse::<f64>()
+                            .is_ok_and(|number| number.is_finite())
+                    })
+            }
+        }
+    }
+}
+
+impl fmt::Display for StringFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.get_name())
+    }
+}
+
+/// 一个字符串值上所有 `v.regex` / `v.len` / `v.format` 规则
+#[derive(Debug, Clone, Default)]
+pub struct StringSpec {
+    pub patterns: Vec<Regex>,
+    pub len: Option<SizeSpec>,
+    pub formats: Vec<StringFormat>,
+}
+
+impl StringSpec {
+    /// 收集规则, 签名不合法的规则会被忽略
+    pub fn extend_from_uses(&mut self, attribute_uses: &[LuaAttributeUse]) {
+        for attr in VRegexAttribute::find_all_in_uses(attribute_uses) {
+            if let Ok(regex) = attr.compile() {
+                self.patterns.push(regex);
+            }
+        }
+        for attr in VLenAttribute::find_all_in_uses(attribute_uses) {
+            if let Ok(len) = attr.parse() {
+                self.len = Some(len);
+            }
+        }
+        for attr in VFormatAttribute::find_all_in_uses(attribute_uses) {
+            if let Some(format) = attr.get_format()
+                && !self.formats.contains(&format)
+            {
+                self.formats.push(format);
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty() && self.len.is_none() && self.formats.is_empty()
+    }
+
+    /// 返回第一个不满足的规则
+    pub fn check(&self, value: &str) -> Option<StringViolation> {
+        if let Some(len) = &self.len {
+            let count = value.chars().count();
+            if !len.contains_len(count) {
+                return Some(StringViolation::Len {
+                    len: count,
+                    spec: len.clone(),
+                });
+            }
+        }
+        if let Some(format) = self.formats.iter().find(|format| !format.matches(value)) {
+            return Some(StringViolation::Format(*format));
+        }
+        if let Some(regex) = self.patterns.iter().find(|regex| !regex.is_match(value)) {
+            return Some(StringViolation::Pattern(regex.as_str().to_string()));
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum StringViolation {
+    Pattern(String),
+    Len { len: usize, spec: SizeSpec },
+    Format(StringFormat),
+}
+
+impl StringViolation {
+    pub fn to_message(&self, value: &str) -> String {
+        match self {
+            StringViolation::Pattern(pattern) => t!(
+                "String `%{value}` does not match pattern `%{pattern}`",
+                value = value,
+                pattern = pattern
+            )
+            .to_string(),
+            StringViolation::Len { len, spec } => t!(
+                "String `%{value}` has length %{len}, expected `%{spec}`",
+                value = value,
+                len = len,
+                spec = spec
+            )
+            .to_string(),
+            StringViolation::Format(format) => t!(
+                "String `%{value}` is not a valid `%{format}`",
+                value = value,
+                format = format
+            )
+            .to_string(),
+        }
+    }
+}
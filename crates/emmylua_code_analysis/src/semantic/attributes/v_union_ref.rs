@@ -0,0 +1,246 @@
+use smol_str::SmolStr;
+
+use crate::{
+    ConfigTableIndexKeys, DbIndex, FileId, LuaAttributeUse, LuaCommonProperty, LuaMemberKey,
+    LuaType, LuaTypeDeclId, find_members_with_key, semantic::shared::luaconfig::CONFIG_TABLE,
+};
+
+/// 检查 Bean 中多个字段组成的值是否为联合主键配置表的合法 key
+pub struct VUnionRefAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> VUnionRefAttribute<'a> {
+    pub const NAME: &'static str = "v.union_ref";
+
+    pub fn find_all_in(property: &'a LuaCommonProperty) -> Vec<Self> {
+        property
+            .attribute_uses
+            .as_deref()
+            .map(|uses| {
+                uses.iter()
+                    .filter(|attribute_use| attribute_use.id.get_name() == Self::NAME)
+                    .map(|inner| Self { inner })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_table_name(&self) -> Option<&str> {
+        let ty = self.get_param("tableName", 0)?;
+        match ty {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+            _ => None,
+        }
+    }
+
+    /// 本地字段列表
+    pub fn get_fields(&self) -> Vec<SmolStr> {
+        self.get_param("fields", 1)
+            .map(collect_string_names)
+            .unwrap_or_default()
+    }
+
+    /// 目标配置表的主键列表, 未提供时使用目标配置表的联合主键
+    pub fn get_keys(&self) -> Option<Vec<SmolStr>> {
+        self.get_param("keys", 2).map(collect_string_names)
+    }
+
+    /// 解析引用目标
+    pub fn resolve_target(
+        &self,
+        db: &DbIndex,
+        file_id: FileId,
+        bean_id: &LuaTypeDeclId,
+    ) -> Option<VUnionRefTarget> {
+        parse_vunion_ref_signature(
+            db,
+            file_id,
+            Some(bean_id),
+            self.get_table_name()?,
+            &self.get_fields(),
+            self.get_keys().as_deref(),
+        )
+        .ok()
+    }
+
+    fn get_param(&self, name: &str, idx: usize) -> Option<&LuaType> {
+        self.inner
+            .get_param_by_name(name)
+            .or_else(|| self.inner.args.get(idx).and_then(|(_, t)| t.as_ref()))
+    }
+}
+
+/// 联合引用的目标
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VUnionRefTarget {
+    pub table: LuaTypeDeclId,
+    /// (本地字段, 目标主键), 按目标配置表联合主键的顺序排列
+    pub pairs: Vec<(LuaMemberKey, LuaMemberKey)>,
+}
+
+impl VUnionRefTarget {
+    pub fn get_local_field(&self, target_key: &LuaMemberKey) -> Option<&LuaMemberKey> {
+        self.pairs
+            .iter()
+            .find(|(_, key)| key == target_key)
+            .map(|(field, _)| field)
+    }
+
+    pub fn contains_field(&self, field: &LuaMemberKey) -> bool {
+        self.pairs.iter().any(|(local, _)| local == field)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum VUnionRefSignatureError {
+    UnknownConfigTable { table: String },
+    NotConfigTable { table: String },
+    NotUnionIndexed { table: LuaTypeDeclId },
+    FieldCountMismatch { fields: usize, keys: usize },
+    KeyNotPrimaryKey { table: LuaTypeDeclId, key: String },
+    KeysMismatch { table: LuaTypeDeclId },
+    UnknownField { bean: LuaTypeDeclId, field: String },
+}
+
+impl VUnionRefSignatureError {
+    pub fn to_message(&self) -> String {
+        match self {
+            VUnionRefSignatureError::UnknownConfigTable { table } => t!(
+                "Invalid v.union_ref: unknown config table `%{table}`",
+                table = table
+            )
+            .to_string(),
+            VUnionRefSignatureError::NotConfigTable { table } => t!(
+                "Invalid v.union_ref: `%{table}` is not a ConfigTable",
+                table = table
+            )
+            .to_string(),
+            VUnionRefSignatureError::NotUnionIndexed { table } => t!(
+                "Invalid v.union_ref: `%{table}` must have union primary keys",
+                table = table.get_name()
+            )
+            .to_string(),
+            VUnionRefSignatureError::FieldCountMismatch { fields, keys } => t!(
+                "Invalid v.union_ref: expected %{keys} fields, got %{fields}",
+                keys = keys,
+                fields = fields
+            )
+            .to_string(),
+            VUnionRefSignatureError::KeyNotPrimaryKey { table, key } => t!(
+                "Invalid v.union_ref: `%{key}` is not a primary key of `%{table}`",
+                key = key,
+                table = table.get_name()
+            )
+            .to_string(),
+            VUnionRefSignatureError::KeysMismatch { table } => t!(
+                "Invalid v.union_ref: keys must cover all union primary keys of `%{table}`",
+                table = table.get_name()
+            )
+            .to_string(),
+            VUnionRefSignatureError::UnknownField { bean, field } => t!(
+                "Invalid v.union_ref: `%{bean}` has no field `%{field}`",
+                bean = bean.get_name(),
+                field = field
+            )
+            .to_string(),
+        }
+    }
+}
+
+pub fn parse_vunion_ref_signature(
+    db: &DbIndex,
+    file_id: FileId,
+    bean_id: Option<&LuaTypeDeclId>,
+    target_table_name: &str,
+    fields: &[SmolStr],
+    keys: Option<&[SmolStr]>,
+) -> Result<VUnionRefTarget, VUnionRefSignatureError> {
+    let Some(target_decl) = db
+        .get_type_index()
+        .find_type_decl(file_id, target_table_name)
+    else {
+        return Err(VUnionRefSignatureError::UnknownConfigTable {
+            table: target_table_name.to_string(),
+        });
+    };
+
+    let target_table_id = target_decl.get_id();
+    if !CONFIG_TABLE.is_config_table(db, &target_table_id) {
+        return Err(VUnionRefSignatureError::NotConfigTable {
+            table: target_table_name.to_string(),
+        });
+    }
+
+    let union_keys = match db
+        .get_config_index()
+        .get_config_table_keys(&target_table_id)
+    {
+        Some(ConfigTableIndexKeys::Union(keys)) if keys.len() > 1 => keys.clone(),
+        _ => {
+            return Err(VUnionRefSignatureError::NotUnionIndexed {
+                table: target_table_id,
+            });
+        }
+    };
+
+    let keys: Vec<LuaMemberKey> = match keys {
+        Some(keys) => keys.iter().cloned().map(LuaMemberKey::Name).collect(),
+        None => union_keys.clone(),
+    };
+    if fields.len() != keys.len() {
+        return Err(VUnionRefSignatureError::FieldCountMismatch {
+            fields: fields.len(),
+            keys: keys.len(),
+        });
+    }
+    if let Some(key) = keys.iter().find(|key| !union_keys.contains(key)) {
+        return Err(VUnionRefSignatureError::KeyNotPrimaryKey {
+            table: target_table_id,
+            key: key.to_path(),
+        });
+    }
+    if union_keys.iter().any(|key| !keys.contains(key)) {
+        return Err(VUnionRefSignatureError::KeysMismatch {
+            table: target_table_id,
+        });
+    }
+
+    if let Some(bean_id) = bean_id {
+        let bean_type = LuaType::Ref(bean_id.clone());
+        if let Some(field) = fields.iter().find(|field| {
+            find_members_with_key(db, &bean_type, LuaMemberKey::Name((*field).clone()), false)
+                .is_none_or(|members| members.is_empty())
+        }) {
+            return Err(VUnionRefSignatureError::UnknownField {
+                bean: bean_id.clone(),
+                field: field.to_string(),
+            });
+        }
+    }
+
+    let pairs = union_keys
+        .iter()
+        .filter_map(|union_key| {
+            let idx = keys.iter().position(|key| key == union_key)?;
+            Some((LuaMemberKey::Name(fields[idx].clone()), union_key.clone()))
+        })
+        .collect();
+
+    Ok(VUnionRefTarget {
+        table: target_table_id,
+        pairs,
+    })
+}
+
+pub(crate) fn collect_string_names(ty: &LuaType) -> Vec<SmolStr> {
+    match ty {
+        LuaType::DocStringConst(s) | LuaType::StringConst(s) => vec![s.as_ref().clone()],
+        LuaType::Tuple(tuple) => tuple
+            .get_types()
+            .iter()
+            .flat_map(collect_string_names)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{LuaAttributeUse, LuaCommonProperty, LuaType};
+
+/// 比较权重与概率之和时允许的误差
+pub const WEIGHT_SUM_EPSILON: f64 = 1e-6;
+
+/// 检查同组记录的权重之和
+pub struct VWeightSumAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> VWeightSumAttribute<'a> {
+    pub const NAME: &'static str = "v.weight_sum";
+
+    pub fn find_in(property: &'a LuaCommonProperty) -> Option<Self> {
+        property
+            .find_attribute_use(Self::NAME)
+            .map(|inner| Self { inner })
+    }
+
+    pub fn get_total(&self) -> Option<f64> {
+        let ty = self
+            .inner
+            .get_param_by_name("total")
+            .or_else(|| self.inner.args.first().and_then(|(_, t)| t.as_ref()))?;
+        match ty {
+            LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => Some(*i as f64),
+            LuaType::FloatConst(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    pub fn get_group_by(&self) -> Option<&str> {
+        get_group_by(self.inner, 1)
+    }
+}
+
+/// 检查同组记录的概率之和为 1
+pub struct VProbSumAttribute<'a> {
+    inner: &'a LuaAttributeUse,
+}
+
+impl<'a> VProbSumAttribute<'a> {
+    pub const NAME: &'static str = "v.prob_sum";
+
+    pub fn find_in(property: &'a LuaCommonProperty) -> Option<Self> {
+        property
+            .find_attribute_use(Self::NAME)
+            .map(|inner| Self { inner })
+    }
+
+    pub fn get_group_by(&self) -> Option<&str> {
+        get_group_by(self.inner, 0)
+    }
+}
+
+fn get_group_by(inner: &LuaAttributeUse, idx: usize) -> Option<&str> {
+    let ty = inner
+        .get_param_by_name("groupBy")
+        .or_else(|| inner.args.get(idx).and_then(|(_, t)| t.as_ref()))?;
+    match ty {
+        LuaType::DocStringConst(s) | LuaType::StringConst(s) => Some(s.as_ref().as_str()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WeightKind {
+    /// `v.weight_sum`: 权重为正数, 同组之和为 `total`
+    Weight { total: f64 },
+    /// `v.prob_sum`: 概率在 `[0, 1]` 内, 同组之和为 1
+    Prob,
+}
+
+/// 字段上的 `v.weight_sum` 或 `v.prob_sum`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightSpec {
+    pub kind: WeightKind,
+    pub group_by: Option<String>,
+}
+
+impl WeightSpec {
+    pub fn find_in(property: &LuaCommonProperty) -> Option<Self> {
+        if let Some(attribute) = VWeightSumAttribute::find_in(property) {
+            return Some(Self {
+                kind: WeightKind::Weight {
+                    total: attribute.get_total()?,
+                },
+                group_by: attribute.get_group_by().map(str::to_string),
+            });
+        }
+
+        let attribute = VProbSumAttribute::find_in(property)?;
+        Some(Self {
+            kind: WeightKind::Prob,
+            group_by: attribute.get_group_by().map(str::to_string),
+        })
+    }
+
+    pub fn get_attribute_name(&self) -> &'static str {
+        match self.kind {
+            WeightKind::Weight { .. } => VWeightSumAttribute::NAME,
+            WeightKind::Prob => VProbSumAttribute::NAME,
+        }
+    }
+
+    pub fn expected_sum(&self) -> f64 {
+        match self.kind {
+            WeightKind::Weight { total } => total,
+            WeightKind::Prob => 1.0,
+        }
+    }
+
+    pub fn is_valid_value(&self, value: f64) -> bool {
+        match self.kind {
+            WeightKind::Weight { .. } => value > 0.0,
+            WeightKind::Prob => (0.0..=1.0).contains(&value),
+        }
+    }
+
+    pub fn is_valid_sum(&self, sum: f64) -> bool {
+        (sum - self.expected_sum()).abs() <= WEIGHT_SUM_EPSILON
+    }
+}
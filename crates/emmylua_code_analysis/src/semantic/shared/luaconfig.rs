@@ -1,6 +1,21 @@
-use std::sync::LazyLock;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::LazyLock,
+};
 
-use crate::{LuaMemberKey, LuaType, LuaTypeDeclId, find_index_operations, is_sub_type_of};
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaLiteralExpr, LuaTableExpr, LuaTableField};
+use rowan::TextRange;
+
+use crate::{
+    DbIndex, FileId, LuaInferCache, LuaMemberId, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId,
+    LuaType, LuaTypeDeclId, RenderLevel, SemanticModel,
+    attributes::{
+        AliasAttribute, VDefaultAttribute, VRefAttribute, VUnionRefAttribute, WeightSpec,
+        is_flags_attribute,
+    },
+    check_type_compact, find_index_operations, find_members_with_key, humanize_type, infer_expr,
+    is_sub_type_of,
+};
 
 pub struct ConfigTable {
     name: &'static str,
@@ -57,6 +72,28 @@ impl ConfigTable {
 
         Some(bean_id.clone())
     }
+
+    /// 获取用于展示记录的字段: Bean 中第一个非主键的字符串字段
+    pub fn get_display_key(&self, db: &crate::DbIndex, id: &LuaTypeDeclId) -> Option<LuaMemberKey> {
+        let bean_id = self.get_bean_id(db, id)?;
+        let index_keys = db.get_config_index().get_config_table_keys(id);
+        let mut members = db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::Type(bean_id))?
+            .to_vec();
+        members.sort_by_key(|m| m.get_sort_key());
+
+        members
+            .into_iter()
+            .filter(|m| matches!(m.get_key(), LuaMemberKey::Name(_)))
+            .filter(|m| index_keys.is_none_or(|keys| !keys.keys().contains(m.get_key())))
+            .find(|m| {
+                db.get_type_index()
+                    .get_type_cache(&m.get_id().into())
+                    .is_some_and(|cache| cache.as_type().is_string())
+            })
+            .map(|m| m.get_key().clone())
+    }
 }
 
 /// 配置表基类. 所有配置表都必须继承自 ConfigTable.
@@ -95,7 +132,618 @@ impl Bean {
     pub fn is_bean(&self, db: &crate::DbIndex, id: &LuaTypeDeclId) -> bool {
         is_sub_type_of(db, id, &self.get_id())
     }
+
+    /// 获取字段通过 `v.default` 声明的默认值
+    pub fn get_field_default(
+        &self,
+        db: &crate::DbIndex,
+        member_id: &LuaMemberId,
+    ) -> Option<LuaType> {
+        let property = db
+            .get_property_index()
+            .get_property(&LuaSemanticDeclId::Member(*member_id))?;
+        VDefaultAttribute::find_in(property)?.get_value().cloned()
+    }
 }
 
 /// Bean 基类. 所有 Bean 都必须继承自 Bean.
 pub static BEAN: Bean = Bean::new();
+
+/// 获取 luaconfig 内置向量类型(`vector2`/`vector3`/`vector4`/`color`)的分量名, 数据中可以按分量顺序书写, 如 `{1, 2}`
+pub fn get_vector_components(db: &DbIndex, id: &LuaTypeDeclId) -> Option<&'static [&'static str]> {
+    let components: &'static [&'static str] = match id.get_name() {
+        "vector2" => &["x", "y"],
+        "vector3" => &["x", "y", "z"],
+        "vector4" => &["x", "y", "z", "w"],
+        "color" => &["r", "g", "b", "a"],
+        _ => return None,
+    };
+    is_std_luaconfig_decl(db, id).then_some(components)
+}
+
+/// 类型是否声明在标准库的 `luaconfig.lua` 中, 避免项目中的同名类型被当作内置类型
+fn is_std_luaconfig_decl(db: &DbIndex, id: &LuaTypeDeclId) -> bool {
+    let Some(type_decl) = db.get_type_index().get_type_decl(id) else {
+        return false;
+    };
+    type_decl.get_locations().iter().any(|location| {
+        db.get_module_index().is_std(&location.file_id)
+            && db
+                .get_vfs()
+                .get_file_path(&location.file_id)
+                .is_some_and(|path| path.file_name().is_some_and(|name| name == "luaconfig.lua"))
+    })
+}
+
+/// 获取类型(去除 `nil` 与特性后)对应的内置向量类型
+pub fn find_vector_type(db: &DbIndex, ty: &LuaType) -> Option<LuaTypeDeclId> {
+    match ty {
+        LuaType::Attributed(attributed) => find_vector_type(db, attributed.get_base()),
+        LuaType::Ref(id) | LuaType::Def(id) => get_vector_components(db, id).map(|_| id.clone()),
+        LuaType::Union(union) => {
+            let mut types = union.into_vec().into_iter().filter(|inner| !inner.is_nil());
+            let (Some(inner), None) = (types.next(), types.next()) else {
+                return None;
+            };
+            find_vector_type(db, &inner)
+        }
+        _ => None,
+    }
+}
+
+/// 将向量数据中按位置书写的键转换为分量名, 超出分量个数时返回 `None`
+pub fn get_vector_component_key(components: &[&str], key: &LuaMemberKey) -> Option<LuaMemberKey> {
+    match key {
+        LuaMemberKey::Integer(i) => {
+            let idx = usize::try_from(*i).ok()?.checked_sub(1)?;
+            components
+                .get(idx)
+                .map(|name| LuaMemberKey::Name((*name).into()))
+        }
+        _ => Some(key.clone()),
+    }
+}
+
+/// 获取可以按名称书写值的枚举, 即字段类型(去除 `nil` 与特性后)为按值定义的 `---@enum`
+pub fn find_named_enum(db: &DbIndex, ty: &LuaType) -> Option<LuaTypeDeclId> {
+    match ty {
+        LuaType::Attributed(attributed) => find_named_enum(db, attributed.get_base()),
+        LuaType::Ref(id) | LuaType::Def(id) => {
+            let type_decl = db.get_type_index().get_type_decl(id)?;
+            (type_decl.is_enum() && !type_decl.is_enum_key()).then(|| id.clone())
+        }
+        LuaType::Union(union) => {
+            let mut found = None;
+            for inner in union.into_vec().iter() {
+                if inner.is_nil() {
+                    continue;
+                }
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(find_named_enum(db, inner)?);
+            }
+            found
+        }
+        _ => None,
+    }
+}
+
+/// 解析配置数据中按名称书写的枚举值, 如 `"Red"`, 或 `flags` 枚举的 `"Fire|Ice"`.
+///
+/// 名称可以是成员名, 也可以是成员通过 `alias` 声明的别名. 成功时返回枚举值, 否则返回无法识别的名称.
+pub fn resolve_enum_name(
+    db: &DbIndex,
+    enum_id: &LuaTypeDeclId,
+    text: &str,
+) -> Result<LuaType, String> {
+    let members = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(enum_id.clone()))
+        .unwrap_or_default();
+    let lookup = |name: &str| {
+        members
+            .iter()
+            .find(|member| {
+                matches!(member.get_key(), LuaMemberKey::Name(key) if key == name)
+                    || get_enum_member_alias(db, &member.get_id()).as_deref() == Some(name)
+            })
+            .and_then(|member| {
+                db.get_type_index()
+                    .get_type_cache(&member.get_id().into())
+                    .map(|type_cache| type_cache.as_type().clone())
+            })
+    };
+
+    let is_flags = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(enum_id.clone()))
+        .is_some_and(is_flags_attribute);
+    if !is_flags || !text.contains('|') {
+        return lookup(text).ok_or_else(|| text.to_string());
+    }
+
+    let mut value = 0;
+    for name in text.split('|').map(str::trim) {
+        match lookup(name) {
+            Some(LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i)) => value |= i,
+            _ => return Err(name.to_string()),
+        }
+    }
+    Ok(LuaType::IntegerConst(value))
+}
+
+/// 将 Bean 字段中按名称书写的枚举值解析为成员的值, 使索引与约束按枚举值比较, 其它值原样返回
+pub fn resolve_field_enum_value(
+    db: &DbIndex,
+    bean_id: &LuaTypeDeclId,
+    field: &LuaMemberKey,
+    value: LuaType,
+) -> LuaType {
+    let (LuaType::StringConst(name) | LuaType::DocStringConst(name)) = &value else {
+        return value;
+    };
+    let Some(enum_id) =
+        find_members_with_key(db, &LuaType::Ref(bean_id.clone()), field.clone(), false)
+            .and_then(|infos| infos.into_iter().next())
+            .and_then(|info| find_named_enum(db, &info.typ))
+    else {
+        return value;
+    };
+
+    match resolve_enum_name(db, &enum_id, name) {
+        Ok(LuaType::DocIntegerConst(i)) => LuaType::IntegerConst(i),
+        Ok(LuaType::DocStringConst(s)) => LuaType::StringConst(s),
+        Ok(resolved) => resolved,
+        Err(_) => value,
+    }
+}
+
+/// 获取枚举成员通过 `alias` 声明的别名
+pub fn get_enum_member_alias(db: &DbIndex, member_id: &LuaMemberId) -> Option<String> {
+    let property = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::Member(*member_id))?;
+    AliasAttribute::find_in(property)?
+        .get_name()
+        .map(str::to_string)
+}
+
+/// 判断 Bean 字段的值是否为按名称书写的枚举数据, 包括枚举容器中的名称.
+///
+/// 名称是否存在由 `unknown-enum-name` 诊断检查, 此处只判断书写形式.
+pub fn is_enum_name_data(db: &DbIndex, expected: &LuaType, value: &LuaType) -> bool {
+    match value {
+        LuaType::StringConst(_) | LuaType::DocStringConst(_) => {
+            find_named_enum(db, expected).is_some()
+        }
+        LuaType::Tuple(tuple) => {
+            let Some(element_type) = get_enum_container_element(db, expected) else {
+                return false;
+            };
+            tuple.get_types().iter().all(|member_type| {
+                is_enum_name_data(db, &element_type, member_type)
+                    || check_type_compact(db, &element_type, member_type).is_ok()
+            })
+        }
+        LuaType::TableConst(range) => {
+            let Some(element_type) = get_enum_container_element(db, expected) else {
+                return false;
+            };
+            let members = db
+                .get_member_index()
+                .get_members(&LuaMemberOwner::Element(range.clone()))
+                .unwrap_or_default();
+            members.iter().all(|member| {
+                let Some(type_cache) = db.get_type_index().get_type_cache(&member.get_id().into())
+                else {
+                    return true;
+                };
+                let member_type = type_cache.as_type();
+                is_enum_name_data(db, &element_type, member_type)
+                    || check_type_compact(db, &element_type, member_type).is_ok()
+            })
+        }
+        _ => false,
+    }
+}
+
+/// 获取 `array<E>`/`list<E>`/`set<E>`/`E[]` 中可以按名称书写的枚举元素类型
+pub fn get_enum_container_element(db: &DbIndex, ty: &LuaType) -> Option<LuaType> {
+    let element_type = match ty.strip_attributed() {
+        LuaType::Array(array) => array.get_base().clone(),
+        LuaType::Generic(generic)
+            if matches!(
+                generic.get_base_type_id_ref().get_name(),
+                "array" | "list" | "set"
+            ) =>
+        {
+            generic.get_params().first()?.clone()
+        }
+        LuaType::Union(union) => {
+            return union
+                .into_vec()
+                .iter()
+                .filter(|inner| !inner.is_nil())
+                .find_map(|inner| get_enum_container_element(db, inner));
+        }
+        _ => return None,
+    };
+    find_named_enum(db, &element_type)?;
+    Some(element_type)
+}
+
+/// 获取 Bean 数据中字符串字面量按名称书写的枚举, 字面量可以是 Bean 字段的值或枚举容器的元素
+pub fn find_enum_name_target(
+    semantic_model: &SemanticModel,
+    literal: &LuaLiteralExpr,
+) -> Option<LuaTypeDeclId> {
+    let field = literal.get_parent::<LuaTableField>()?;
+    let table = field.get_parent::<LuaTableExpr>()?;
+    if let Some(field_type) = get_bean_field_type(semantic_model, &table, &field) {
+        return find_named_enum(semantic_model.get_db(), &field_type);
+    }
+
+    let container_field = table.get_parent::<LuaTableField>()?;
+    let container_owner = container_field.get_parent::<LuaTableExpr>()?;
+    let container_type = get_bean_field_type(semantic_model, &container_owner, &container_field)?;
+    let element_type = get_enum_container_element(semantic_model.get_db(), &container_type)?;
+    find_named_enum(semantic_model.get_db(), &element_type)
+}
+
+fn get_bean_field_type(
+    semantic_model: &SemanticModel,
+    table: &LuaTableExpr,
+    field: &LuaTableField,
+) -> Option<LuaType> {
+    let (LuaType::Ref(bean_id) | LuaType::Def(bean_id)) =
+        semantic_model.infer_table_should_be(table.clone())?
+    else {
+        return None;
+    };
+    if !BEAN.is_bean(semantic_model.get_db(), &bean_id) {
+        return None;
+    }
+    let member_key = semantic_model.get_member_key(&field.get_field_key()?)?;
+    semantic_model
+        .infer_member_type(&LuaType::Ref(bean_id), &member_key)
+        .ok()
+}
+
+/// Bean 中带有 `v.weight_sum`/`v.prob_sum` 的字段
+#[derive(Debug, Clone)]
+pub struct WeightRule {
+    pub field: String,
+    pub spec: WeightSpec,
+    /// 字段通过 `v.default` 声明的默认值, 数据中省略该字段时使用
+    pub default: Option<f64>,
+}
+
+/// 一组记录的权重, 分组由 `groupBy` 字段的值决定
+#[derive(Debug, Clone)]
+pub struct WeightGroup {
+    pub key: Option<String>,
+    pub sum: f64,
+    pub entries: Vec<WeightEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WeightEntry {
+    pub file_id: FileId,
+    /// 记录的范围
+    pub row_range: TextRange,
+    /// 权重值的范围, 使用默认值时为 `None`
+    pub value_range: Option<TextRange>,
+    pub value: f64,
+}
+
+/// 获取 Bean 中的权重规则, 子类中的字段优先于父类中的同名字段
+pub fn get_weight_rules(db: &DbIndex, bean_id: &LuaTypeDeclId) -> Vec<WeightRule> {
+    let mut rules = Vec::new();
+    let mut visited = HashSet::new();
+    for typ in bean_id.collect_super_types_with_self(db, LuaType::Ref(bean_id.clone())) {
+        let LuaType::Ref(type_id) = typ else {
+            continue;
+        };
+        let Some(members) = db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::Type(type_id))
+        else {
+            continue;
+        };
+        for member in members {
+            let field = member.get_key().to_path();
+            if field.is_empty() || !visited.insert(field.clone()) {
+                continue;
+            }
+            let Some(spec) = db
+                .get_property_index()
+                .get_property(&LuaSemanticDeclId::Member(member.get_id()))
+                .and_then(WeightSpec::find_in)
+            else {
+                continue;
+            };
+            let default = match BEAN.get_field_default(db, &member.get_id()) {
+                Some(LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i)) => Some(i as f64),
+                Some(LuaType::FloatConst(f)) => Some(f),
+                _ => None,
+            };
+            rules.push(WeightRule {
+                field,
+                spec,
+                default,
+            });
+        }
+    }
+    rules
+}
+
+/// 获取 `array<Bean>`/`list<Bean>`/`set<Bean>`/`Bean[]` 的元素 Bean
+pub fn get_bean_container_element(db: &DbIndex, ty: &LuaType) -> Option<LuaTypeDeclId> {
+    let element_type = match ty.strip_attributed() {
+        LuaType::Array(array) => array.get_base().clone(),
+        LuaType::Generic(generic)
+            if matches!(
+                generic.get_base_type_id_ref().get_name(),
+                "array" | "list" | "set"
+            ) =>
+        {
+            generic.get_params().first()?.clone()
+        }
+        LuaType::Union(union) => {
+            return union
+                .into_vec()
+                .iter()
+                .filter(|inner| !inner.is_nil())
+                .find_map(|inner| get_bean_container_element(db, inner));
+        }
+        _ => return None,
+    };
+    match element_type.strip_attributed() {
+        LuaType::Ref(id) | LuaType::Def(id) if BEAN.is_bean(db, id) => Some(id.clone()),
+        _ => None,
+    }
+}
+
+/// 获取一次权重检查涉及的记录.
+///
+/// 表为 `list<Bean>` 等容器的值时为表中的元素, 表为配置表数据时为该配置表在所有文件中的记录.
+pub fn find_weight_records(
+    db: &DbIndex,
+    file_id: FileId,
+    table: &LuaTableExpr,
+    table_type: &LuaType,
+) -> Option<(LuaTypeDeclId, Vec<(FileId, LuaTableExpr)>)> {
+    if let LuaType::Ref(config_table) = table_type.strip_attributed()
+        && let Some(bean_id) = CONFIG_TABLE.get_bean_id(db, config_table)
+    {
+        let vfs = db.get_vfs();
+        let mut data = Vec::new();
+        for (data_file_id, occurrences) in db
+            .get_config_index()
+            .iter_file_config_table_data_occurrences()
+        {
+            let Some(tree) = vfs.get_syntax_tree(data_file_id) else {
+                continue;
+            };
+            let root = tree.get_red_root();
+            for occ in occurrences {
+                if occ.config_table != *config_table {
+                    continue;
+                }
+                if let Some(data_table) = occ
+                    .syntax_id
+                    .to_node_from_root(&root)
+                    .and_then(LuaTableExpr::cast)
+                {
+                    data.push((vfs.get_file_path(data_file_id), *data_file_id, data_table));
+                }
+            }
+        }
+        // 保证记录顺序稳定
+        data.sort_by_key(|(path, _, data_table)| (*path, data_table.get_position()));
+
+        let rows = data
+            .into_iter()
+            .flat_map(|(_, data_file_id, data_table)| {
+                collect_table_rows(&data_table)
+                    .into_iter()
+                    .map(move |row| (data_file_id, row))
+            })
+            .collect();
+        return Some((bean_id, rows));
+    }
+
+    let bean_id = get_bean_container_element(db, table_type)?;
+    let rows = collect_table_rows(table)
+        .into_iter()
+        .map(|row| (file_id, row))
+        .collect();
+    Some((bean_id, rows))
+}
+
+fn collect_table_rows(table: &LuaTableExpr) -> Vec<LuaTableExpr> {
+    table
+        .get_fields()
+        .filter_map(|field| match field.get_value_expr()? {
+            LuaExpr::TableExpr(row) => Some(row),
+            _ => None,
+        })
+        .collect()
+}
+
+/// 按 `groupBy` 字段分组计算权重之和, 分组按首次出现的顺序排列
+pub fn collect_weight_groups(
+    db: &DbIndex,
+    rule: &WeightRule,
+    rows: &[(FileId, LuaTableExpr)],
+) -> Vec<WeightGroup> {
+    let mut caches: HashMap<FileId, LuaInferCache> = HashMap::new();
+    let mut groups: Vec<WeightGroup> = Vec::new();
+    for (file_id, row) in rows {
+        let cache = caches
+            .entry(*file_id)
+            .or_insert_with(|| LuaInferCache::new(*file_id, Default::default()));
+
+        let (value, value_range) = match find_row_field_value(row, &rule.field) {
+            Some(expr) => match infer_expr(db, cache, expr.clone()) {
+                Ok(LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i)) => {
+                    (i as f64, Some(expr.get_range()))
+                }
+                Ok(LuaType::FloatConst(f)) => (f, Some(expr.get_range())),
+                _ => continue,
+            },
+            None => match rule.default {
+                Some(default) => (default, None),
+                None => continue,
+            },
+        };
+
+        let key = rule.spec.group_by.as_ref().map(|group_by| {
+            find_row_field_value(row, group_by)
+                .and_then(|expr| infer_expr(db, cache, expr).ok())
+                .map(|ty| humanize_type(db, &ty, RenderLevel::Simple))
+                .unwrap_or_else(|| "nil".to_string())
+        });
+        let entry = WeightEntry {
+            file_id: *file_id,
+            row_range: row.get_range(),
+            value_range,
+            value,
+        };
+        match groups.iter_mut().find(|group| group.key == key) {
+            Some(group) => {
+                group.sum += value;
+                group.entries.push(entry);
+            }
+            None => groups.push(WeightGroup {
+                key,
+                sum: value,
+                entries: vec![entry],
+            }),
+        }
+    }
+    groups
+}
+
+fn find_row_field_value(row: &LuaTableExpr, name: &str) -> Option<LuaExpr> {
+    row.get_fields()
+        .find(|field| {
+            field
+                .get_field_key()
+                .is_some_and(|key| key.get_path_part() == name)
+        })?
+        .get_value_expr()
+}
+
+/// 格式化权重, 整数不带小数部分, 其余最多保留 6 位小数
+pub fn format_weight_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return format!("{}", value as i64);
+    }
+    let text = format!("{:.6}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// 获取 Bean 的 `v.ref`/`v.union_ref` 引用的配置表, 包括容器元素上的 `v.ref`
+pub fn collect_bean_ref_tables(db: &DbIndex, bean_id: &LuaTypeDeclId) -> HashSet<LuaTypeDeclId> {
+    let mut tables = HashSet::new();
+    for typ in bean_id.collect_super_types_with_self(db, LuaType::Ref(bean_id.clone())) {
+        let LuaType::Ref(type_id) = typ else {
+            continue;
+        };
+
+        if let Some(property) = db
+            .get_property_index()
+            .get_property(&LuaSemanticDeclId::TypeDecl(type_id.clone()))
+            && let Some(file_id) = db
+                .get_type_index()
+                .get_type_decl(&type_id)
+                .and_then(|decl| decl.get_locations().first().map(|loc| loc.file_id))
+        {
+            for attribute in VUnionRefAttribute::find_all_in(property) {
+                if let Some(target) = attribute.resolve_target(db, file_id, &type_id) {
+                    tables.insert(target.table);
+                }
+            }
+        }
+
+        let Some(members) = db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::Type(type_id))
+        else {
+            continue;
+        };
+        for member in members {
+            let file_id = member.get_file_id();
+            if let Some(property) = db
+                .get_property_index()
+                .get_property(&LuaSemanticDeclId::Member(member.get_id()))
+                && let Some((table, _)) = VRefAttribute::find_in(property)
+                    .and_then(|attr| attr.resolve_target(db, file_id))
+            {
+                tables.insert(table);
+            }
+            if let Some(type_cache) = db.get_type_index().get_type_cache(&member.get_id().into()) {
+                collect_type_ref_tables(db, file_id, type_cache.as_type(), &mut tables);
+            }
+        }
+    }
+    tables
+}
+
+fn collect_type_ref_tables(
+    db: &DbIndex,
+    file_id: FileId,
+    typ: &LuaType,
+    out: &mut HashSet<LuaTypeDeclId>,
+) {
+    match typ {
+        LuaType::Attributed(attributed) => {
+            if let Some((table, _)) = VRefAttribute::find_in_uses(attributed.get_attributes())
+                .and_then(|attr| attr.resolve_target(db, file_id))
+            {
+                out.insert(table);
+            }
+            collect_type_ref_tables(db, file_id, attributed.get_base(), out);
+        }
+        LuaType::Array(array) => collect_type_ref_tables(db, file_id, array.get_base(), out),
+        LuaType::Generic(generic) => {
+            for param in generic.get_params() {
+                collect_type_ref_tables(db, file_id, param, out);
+            }
+        }
+        LuaType::TableGeneric(params) => {
+            for param in params.iter() {
+                collect_type_ref_tables(db, file_id, param, out);
+            }
+        }
+        LuaType::Union(union) => {
+            for inner in union.into_vec() {
+                collect_type_ref_tables(db, file_id, &inner, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 获取数据中的 `v.ref`/`v.union_ref` 引用了指定配置表的文件,
+/// 以及声明了 `t.cover_enum` 的配置表的声明文件
+pub fn find_config_table_dependents(db: &DbIndex, tables: &[LuaTypeDeclId]) -> Vec<FileId> {
+    let config_index = db.get_config_index();
+    let mut dependents = Vec::new();
+    for table in tables {
+        if let Some(files) = config_index.get_config_table_dependents(table) {
+            dependents.extend(files.iter().copied());
+        }
+        // 枚举覆盖诊断报告在配置表的声明上
+        if config_index.get_config_table_cover_enums(table).is_some()
+            && let Some(decl) = db.get_type_index().get_type_decl(table)
+        {
+            dependents.extend(decl.get_locations().iter().map(|location| location.file_id));
+        }
+    }
+    dependents.sort();
+    dependents.dedup();
+    dependents
+}
@@ -3,7 +3,13 @@ use std::collections::HashMap;
 use crate::{
     LuaMemberKey, LuaMemberOwner, LuaObjectType, LuaTupleType, LuaType, LuaTypeCache, LuaTypeDecl,
     LuaTypeDeclId, RenderLevel, humanize_type,
-    semantic::{member::find_members, type_check::type_check_context::TypeCheckContext},
+    semantic::{
+        member::find_members,
+        shared::luaconfig::{
+            BEAN, get_vector_component_key, get_vector_components, is_enum_name_data,
+        },
+        type_check::type_check_context::TypeCheckContext,
+    },
 };
 
 use super::{
@@ -225,7 +231,7 @@ fn check_ref_type_compact_table(
     check_guard: TypeCheckGuard,
 ) -> TypeCheckResult {
     let member_index = context.db.get_member_index();
-    let table_member_map: HashMap<_, _> = member_index
+    let mut table_member_map: HashMap<_, _> = member_index
         .get_members(&table_owner)
         .map(|members| {
             members
@@ -235,6 +241,29 @@ fn check_ref_type_compact_table(
         })
         .unwrap_or_default();
 
+    // 内置向量类型可以按分量顺序书写
+    if let Some(components) = get_vector_components(context.db, source_type_id) {
+        let mut vector_member_map = HashMap::new();
+        for (key, member_id) in table_member_map {
+            let Some(component_key) = get_vector_component_key(components, &key) else {
+                if !context.detail {
+                    return Err(TypeCheckFailReason::TypeNotMatch);
+                }
+
+                return Err(TypeCheckFailReason::TypeNotMatchWithReason(
+                    t!(
+                        "too many components for %{name}, expect at most %{count}",
+                        name = source_type_id.get_name(),
+                        count = components.len()
+                    )
+                    .to_string(),
+                ));
+            };
+            vector_member_map.insert(component_key, member_id);
+        }
+        table_member_map = vector_member_map;
+    }
+
     let source_type_members =
         member_index.get_members(&LuaMemberOwner::Type(source_type_id.clone()));
     let Some(source_type_members) = source_type_members else {
@@ -272,6 +301,9 @@ fn check_ref_type_compact_table(
                     table_member_type,
                     check_guard.next_level()?,
                 ) && err.is_type_not_match()
+                    // Bean 数据中的枚举可以按名称书写
+                    && !(BEAN.is_bean(context.db, source_type_id)
+                        && is_enum_name_data(context.db, source_member_type, table_member_type))
                 {
                     if !context.detail {
                         return Err(TypeCheckFailReason::TypeNotMatch);
@@ -289,7 +321,12 @@ fn check_ref_type_compact_table(
                     ));
                 }
             }
-            None if !source_member_type.is_optional() => {
+            // 带有 `v.default` 的 Bean 字段可以省略
+            None if !source_member_type.is_optional()
+                && BEAN
+                    .get_field_default(context.db, &source_member.get_id())
+                    .is_none() =>
+            {
                 if !context.detail {
                     return Err(TypeCheckFailReason::TypeNotMatch);
                 }
@@ -36,6 +36,11 @@ pub struct CmdArgs {
     #[cfg_attr(feature = "cli", structopt(long, default_value = "true"))]
     pub load_stdlib: CmdBool,
 
+    /// Whether to run the external validators configured in `diagnostics.externalValidators`.
+    /// Project config files can name any program, so they only run when this is enabled.
+    #[cfg_attr(feature = "cli", structopt(long, default_value = "false"))]
+    pub allow_external_validators: CmdBool,
+
     /// Force editor mode. Options: vscode, intellij, neovim
     #[cfg_attr(feature = "cli", structopt(long))]
     pub editor: Option<Editor>,
@@ -85,6 +85,34 @@ impl FileDiagnostic {
         }
     }
 
+    /// 重新诊断数据引用了主键集合发生变化的配置表的文件, `scheduled` 中的文件已安排诊断
+    pub async fn add_dependents_diagnostic_task(
+        &self,
+        dependents: Vec<FileId>,
+        scheduled: &[FileId],
+        interval: u64,
+    ) {
+        let file_ids: Vec<FileId> = dependents
+            .into_iter()
+            .filter(|file_id| !scheduled.contains(file_id))
+            .collect();
+        if !file_ids.is_empty() {
+            debug!("diagnose config table dependents: {:?}", file_ids);
+        }
+        self.add_files_diagnostic_task(file_ids, interval).await;
+    }
+
+    /// pull 模式下依赖文件的诊断由客户端拉取, 存在未安排诊断的依赖文件时请求客户端刷新
+    pub fn refresh_dependents_diagnostics(&self, dependents: &[FileId], scheduled: &[FileId]) {
+        if dependents
+            .iter()
+            .any(|file_id| !scheduled.contains(file_id))
+        {
+            debug!("refresh config table dependents: {:?}", dependents);
+            self.client.refresh_workspace_diagnostics();
+        }
+    }
+
     /// 清除指定文件的诊断信息
     pub fn clear_push_file_diagnostics(&self, uri: lsp_types::Uri) {
         let diagnostic_param = lsp_types::PublishDiagnosticsParams {
@@ -174,16 +202,29 @@ impl FileDiagnostic {
             .get_main_workspace_file_ids();
         drop(analysis);
 
+        let mut file_diagnostics = Vec::new();
         for file_id in main_workspace_file_ids {
             if cancel_token.is_cancelled() {
                 break;
             }
             let analysis = self.analysis.read().await;
+            let diagnostics =
+                analysis.diagnose_file_without_luaconfig(file_id, cancel_token.clone());
+            if let Some(diagnostics) = diagnostics {
+                file_diagnostics.push((file_id, diagnostics));
+            }
+        }
+
+        merge_luaconfig_diagnostics(
+            self.analysis.clone(),
+            &mut file_diagnostics,
+            cancel_token.clone(),
+        )
+        .await;
+        let analysis = self.analysis.read().await;
+        for (file_id, diagnostics) in file_diagnostics {
             if let Some(uri) = analysis.get_uri(file_id) {
-                let diagnostics = analysis.diagnose_file(file_id, cancel_token.clone());
-                if let Some(diagnostics) = diagnostics {
-                    result.push((uri, diagnostics));
-                }
+                result.push((uri, diagnostics));
             }
         }
 
@@ -216,7 +257,7 @@ impl FileDiagnostic {
             .create_progress_task(ProgressTask::DiagnoseWorkspace)
             .await;
 
-        let (tx, mut rx) = tokio::sync::mpsc::channel::<Option<(Vec<Diagnostic>, Uri)>>(100);
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<Option<(FileId, Vec<Diagnostic>)>>(100);
         let valid_file_count = main_workspace_file_ids.len();
 
         let analysis = self.analysis.clone();
@@ -226,47 +267,49 @@ impl FileDiagnostic {
             let tx = tx.clone();
             tokio::spawn(async move {
                 let analysis = analysis.read().await;
-                let diagnostics = analysis.diagnose_file(file_id, token);
-                if let Some(diagnostics) = diagnostics {
-                    let uri = analysis.get_uri(file_id).unwrap();
-                    let _ = tx.send(Some((diagnostics, uri))).await;
-                } else {
-                    let _ = tx.send(None).await;
-                }
+                let diagnostics = analysis.diagnose_file_without_luaconfig(file_id, token);
+                let _ = tx
+                    .send(diagnostics.map(|diagnostics| (file_id, diagnostics)))
+                    .await;
             });
         }
 
         let mut count = 0;
+        let mut file_diagnostics = Vec::new();
         if valid_file_count != 0 {
             let text = format!("diagnose {} files", valid_file_count);
             let _p = Profile::new(text.as_str());
-            let mut last_percentage = 0;
+            let mut progress = DiagnosticProgress::new(valid_file_count);
             while let Some(file_diagnostic_result) = rx.recv().await {
                 if cancel_token.is_cancelled() {
                     break;
                 }
 
-                if let Some((diagnostics, uri)) = file_diagnostic_result {
-                    result.push((uri, diagnostics));
+                if let Some(file_diagnostic) = file_diagnostic_result {
+                    file_diagnostics.push(file_diagnostic);
                 }
 
                 count += 1;
-                let percentage_done = ((count as f32 / valid_file_count as f32) * 100.0) as u32;
-                if last_percentage != percentage_done {
-                    last_percentage = percentage_done;
-                    let message = format!("diagnostic {}%", percentage_done);
-                    status_bar.update_progress_task(
-                        ProgressTask::DiagnoseWorkspace,
-                        Some(percentage_done),
-                        Some(message),
-                    );
-                }
+                progress.update(&status_bar, count);
                 if count == valid_file_count {
                     break;
                 }
             }
         }
 
+        merge_luaconfig_diagnostics(
+            analysis.clone(),
+            &mut file_diagnostics,
+            cancel_token.clone(),
+        )
+        .await;
+        let analysis = analysis.read().await;
+        for (file_id, diagnostics) in file_diagnostics {
+            if let Some(uri) = analysis.get_uri(file_id) {
+                result.push((uri, diagnostics));
+            }
+        }
+
         status_bar.finish_progress_task(
             ProgressTask::DiagnoseWorkspace,
             Some("Diagnosis complete".to_string()),
@@ -291,7 +334,7 @@ async fn push_workspace_diagnostic(
         .get_main_workspace_file_ids();
     drop(read_analysis);
     // diagnostic files
-    let (tx, mut rx) = tokio::sync::mpsc::channel::<FileId>(100);
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<Option<(FileId, Vec<Diagnostic>)>>(100);
     let valid_file_count = main_workspace_file_ids.len();
     if !silent {
         status_bar
@@ -306,24 +349,29 @@ async fn push_workspace_diagnostic(
         let tx = tx.clone();
         tokio::spawn(async move {
             let analysis = analysis.read().await;
-            let diagnostics = analysis.diagnose_file(file_id, token);
-            if let Some(diagnostics) = diagnostics {
-                let uri = analysis.get_uri(file_id).unwrap();
-                let diagnostic_param = lsp_types::PublishDiagnosticsParams {
+            let diagnostics = analysis.diagnose_file_without_luaconfig(file_id, token);
+            // 先发布不含配置表数据校验的诊断, 批量校验完成后再更新有数据校验诊断的文件
+            if let Some(diagnostics) = &diagnostics
+                && let Some(uri) = analysis.get_uri(file_id)
+            {
+                client.publish_diagnostics(lsp_types::PublishDiagnosticsParams {
                     uri,
-                    diagnostics,
+                    diagnostics: diagnostics.clone(),
                     version: None,
-                };
-                client.publish_diagnostics(diagnostic_param);
+                });
             }
-            let _ = tx.send(file_id).await;
+            let _ = tx
+                .send(diagnostics.map(|diagnostics| (file_id, diagnostics)))
+                .await;
         });
     }
 
     let mut count = 0;
+    let mut file_diagnostics = Vec::new();
     if valid_file_count != 0 {
         if silent {
-            while (rx.recv().await).is_some() {
+            while let Some(file_diagnostic_result) = rx.recv().await {
+                file_diagnostics.extend(file_diagnostic_result);
                 count += 1;
                 if count == valid_file_count {
                     break;
@@ -332,19 +380,11 @@ async fn push_workspace_diagnostic(
         } else {
             let text = format!("diagnose {} files", valid_file_count);
             let _p = Profile::new(text.as_str());
-            let mut last_percentage = 0;
-            while (rx.recv().await).is_some() {
+            let mut progress = DiagnosticProgress::new(valid_file_count);
+            while let Some(file_diagnostic_result) = rx.recv().await {
+                file_diagnostics.extend(file_diagnostic_result);
                 count += 1;
-                let percentage_done = ((count as f32 / valid_file_count as f32) * 100.0) as u32;
-                if last_percentage != percentage_done {
-                    last_percentage = percentage_done;
-                    let message = format!("diagnostic {}%", percentage_done);
-                    status_bar.update_progress_task(
-                        ProgressTask::DiagnoseWorkspace,
-                        Some(percentage_done),
-                        Some(message),
-                    );
-                }
+                progress.update(&status_bar, count);
                 if count == valid_file_count {
                     break;
                 }
@@ -352,6 +392,31 @@ async fn push_workspace_diagnostic(
         }
     }
 
+    let mut luaconfig_diagnostics = collect_luaconfig_diagnostics(
+        analysis.clone(),
+        file_diagnostics
+            .iter()
+            .map(|(file_id, _)| *file_id)
+            .collect(),
+        cancel_token.clone(),
+    )
+    .await;
+    let read_analysis = analysis.read().await;
+    for (file_id, mut diagnostics) in file_diagnostics {
+        let Some(luaconfig_diagnostics) = luaconfig_diagnostics.remove(&file_id) else {
+            continue;
+        };
+        if let Some(uri) = read_analysis.get_uri(file_id) {
+            diagnostics.extend(luaconfig_diagnostics);
+            client_proxy.publish_diagnostics(lsp_types::PublishDiagnosticsParams {
+                uri,
+                diagnostics,
+                version: None,
+            });
+        }
+    }
+    drop(read_analysis);
+
     if !silent {
         status_bar.finish_progress_task(
             ProgressTask::DiagnoseWorkspace,
@@ -359,3 +424,78 @@ async fn push_workspace_diagnostic(
         );
     }
 }
+
+/// 工作区诊断的进度, 逐个文件的诊断之后还有一步配置表数据的批量校验
+struct DiagnosticProgress {
+    total: usize,
+    last_percentage: u32,
+}
+
+impl DiagnosticProgress {
+    fn new(file_count: usize) -> Self {
+        Self {
+            total: file_count + 1,
+            last_percentage: 0,
+        }
+    }
+
+    fn update(&mut self, status_bar: &StatusBar, count: usize) {
+        let percentage_done = ((count as f32 / self.total as f32) * 100.0) as u32;
+        if self.last_percentage != percentage_done {
+            self.last_percentage = percentage_done;
+            let message = format!("diagnostic {}%", percentage_done);
+            status_bar.update_progress_task(
+                ProgressTask::DiagnoseWorkspace,
+                Some(percentage_done),
+                Some(message),
+            );
+        }
+    }
+}
+
+/// 批量校验各文件的配置表数据, 线程数由 `diagnostics.luaconfigThreads` 指定
+///
+/// 取消时数据校验不完整, 返回空的结果
+async fn collect_luaconfig_diagnostics(
+    analysis: Arc<RwLock<EmmyLuaAnalysis>>,
+    file_ids: Vec<FileId>,
+    cancel_token: CancellationToken,
+) -> HashMap<FileId, Vec<Diagnostic>> {
+    if cancel_token.is_cancelled() || file_ids.is_empty() {
+        return HashMap::new();
+    }
+
+    let token = cancel_token.clone();
+    let luaconfig_diagnostics = tokio::task::spawn_blocking(move || {
+        let analysis = analysis.blocking_read();
+        analysis.diagnose_luaconfig_files(&file_ids, token)
+    })
+    .await
+    .unwrap_or_default();
+    if cancel_token.is_cancelled() {
+        return HashMap::new();
+    }
+    luaconfig_diagnostics
+        .into_iter()
+        .filter(|(_, diagnostics)| !diagnostics.is_empty())
+        .collect()
+}
+
+/// 将配置表数据校验的诊断合并到各文件的诊断中, 取消时保留已完成的其他诊断
+async fn merge_luaconfig_diagnostics(
+    analysis: Arc<RwLock<EmmyLuaAnalysis>>,
+    file_diagnostics: &mut [(FileId, Vec<Diagnostic>)],
+    cancel_token: CancellationToken,
+) {
+    let file_ids = file_diagnostics
+        .iter()
+        .map(|(file_id, _)| *file_id)
+        .collect::<Vec<_>>();
+    let mut luaconfig_diagnostics =
+        collect_luaconfig_diagnostics(analysis, file_ids, cancel_token).await;
+    for (file_id, diagnostics) in file_diagnostics.iter_mut() {
+        if let Some(luaconfig_diagnostics) = luaconfig_diagnostics.remove(file_id) {
+            diagnostics.extend(luaconfig_diagnostics);
+        }
+    }
+}
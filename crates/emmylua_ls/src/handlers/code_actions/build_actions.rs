@@ -30,7 +30,9 @@ pub fn build_actions(
 
         if let Some(code) = diagnostic.code
             && let NumberOrString::String(action_string) = code
-            && let Ok(diagnostic_code) = DiagnosticCode::from_str(&action_string)
+            // 外部校验器的诊断代码形如 `external-validator/<name>/<rule>`
+            && let Some(code_name) = action_string.split('/').next()
+            && let Ok(diagnostic_code) = DiagnosticCode::from_str(code_name)
         {
             add_fix_code_action(
                 semantic_model,
@@ -0,0 +1,305 @@
+use std::collections::HashSet;
+
+use emmylua_code_analysis::{
+    DocTypeInferContext, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaType, LuaTypeDeclId,
+    attributes::{TIndexAttribute, VIndexAttribute, VRefAttribute, VUnionRefAttribute},
+    infer_doc_type,
+    shared::luaconfig::{BEAN, CONFIG_TABLE},
+};
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaDocAttributeCallArgList, LuaDocAttributeUse, LuaDocLiteralType,
+    LuaDocTagAttributeUse, LuaDocTagClass, LuaDocTagField, LuaDocType, LuaKind, LuaStringToken,
+    LuaSyntaxKind, LuaSyntaxNode,
+};
+use lsp_types::{CompletionItem, CompletionItemKind, CompletionTextEdit, TextEdit};
+
+use super::get_text_edit_range_in_string;
+use crate::handlers::completion::{
+    completion_builder::CompletionBuilder, completion_data::CompletionData,
+};
+
+/// 特性参数中的字符串补全: 配置表名, 主键字段, Bean 字段与字面量联合值
+pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
+    if builder.is_cancelled() {
+        return None;
+    }
+
+    let string_token = LuaStringToken::cast(builder.trigger_token.clone())?;
+    let literal = string_token.get_parent::<LuaDocLiteralType>()?;
+    let arg = find_attribute_arg(literal.syntax())?;
+    let arg_list = arg.get_parent::<LuaDocAttributeCallArgList>()?;
+    let attribute_use = arg_list.get_parent::<LuaDocAttributeUse>()?;
+    let param_idx = arg_list
+        .get_args()
+        .position(|it| it.get_position() == arg.get_position())?;
+
+    let db = builder.semantic_model.get_db();
+    let doc_ctx = DocTypeInferContext::new(db, builder.semantic_model.get_file_id());
+    let LuaType::Ref(attribute_id) =
+        infer_doc_type(doc_ctx, &LuaDocType::Name(attribute_use.get_type()?))
+    else {
+        return None;
+    };
+    let type_decl = db.get_type_index().get_type_decl(&attribute_id)?;
+    let LuaType::DocAttribute(attribute) = type_decl.get_attribute_type()? else {
+        return None;
+    };
+    let param_type = attribute
+        .get_params()
+        .get(param_idx)
+        .and_then(|(_, typ)| typ.clone());
+
+    let candidates = match attribute_id.get_name() {
+        VRefAttribute::NAME if param_idx == 0 => get_config_table_candidates(builder),
+        VRefAttribute::NAME if param_idx == 1 => {
+            get_ref_key_candidates(builder, &arg_list).unwrap_or_default()
+        }
+        TIndexAttribute::NAME if param_idx == 0 => {
+            get_bean_field_candidates(builder, &attribute_use).unwrap_or_default()
+        }
+        VIndexAttribute::NAME if param_idx == 0 => {
+            get_bean_field_candidates(builder, &attribute_use).unwrap_or_default()
+        }
+        VUnionRefAttribute::NAME if param_idx == 0 => get_config_table_candidates(builder),
+        VUnionRefAttribute::NAME if param_idx == 1 => {
+            get_bean_field_candidates(builder, &attribute_use).unwrap_or_default()
+        }
+        VUnionRefAttribute::NAME if param_idx == 2 => {
+            get_ref_key_candidates(builder, &arg_list).unwrap_or_default()
+        }
+        _ => get_literal_candidates(param_type.as_ref()?),
+    };
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let text_edit_range = get_text_edit_range_in_string(builder, string_token)?;
+    for (label, kind, data_id) in candidates {
+        let data = data_id.and_then(|id| CompletionData::from_property_owner_id(builder, id, None));
+        builder.add_completion_item(CompletionItem {
+            label: label.clone(),
+            kind: Some(kind),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: text_edit_range,
+                new_text: label,
+            })),
+            data,
+            ..CompletionItem::default()
+        });
+    }
+
+    builder.stop_here();
+    Some(())
+}
+
+type Candidate = (String, CompletionItemKind, Option<LuaSemanticDeclId>);
+
+/// 字符串所在的参数, 数组参数中的元素如 `t.index(["chapterId", "stageId"])` 归属于整个数组
+fn find_attribute_arg(literal: &LuaSyntaxNode) -> Option<LuaDocType> {
+    let mut node = literal.clone();
+    loop {
+        let parent = node.parent()?;
+        match parent.kind() {
+            LuaKind::Syntax(LuaSyntaxKind::DocAttributeCallArgList) => {
+                return LuaDocType::cast(node);
+            }
+            LuaKind::Syntax(LuaSyntaxKind::TypeArray | LuaSyntaxKind::TypeTuple) => node = parent,
+            _ => return None,
+        }
+    }
+}
+
+/// 所有 `ConfigTable` 子类
+fn get_config_table_candidates(builder: &CompletionBuilder) -> Vec<Candidate> {
+    let type_index = builder.semantic_model.get_db().get_type_index();
+    type_index
+        .get_all_sub_types(CONFIG_TABLE.get_id())
+        .into_iter()
+        .map(|decl| {
+            (
+                decl.get_full_name().to_string(),
+                CompletionItemKind::CLASS,
+                Some(LuaSemanticDeclId::TypeDecl(decl.get_id())),
+            )
+        })
+        .collect()
+}
+
+/// `v.ref` 目标配置表中可以被引用的主键
+fn get_ref_key_candidates(
+    builder: &CompletionBuilder,
+    arg_list: &LuaDocAttributeCallArgList,
+) -> Option<Vec<Candidate>> {
+    let LuaDocType::Literal(table_arg) = arg_list.get_args().next()? else {
+        return None;
+    };
+    let table_name = LuaStringToken::cast(table_arg.syntax().first_token()?)?.get_value();
+
+    let db = builder.semantic_model.get_db();
+    let table_decl = db
+        .get_type_index()
+        .find_type_decl(builder.semantic_model.get_file_id(), &table_name)?;
+    let keys = db
+        .get_config_index()
+        .get_config_table_keys(&table_decl.get_id())?;
+    Some(
+        keys.keys()
+            .iter()
+            .filter_map(|key| match key {
+                LuaMemberKey::Name(name) => {
+                    Some((name.to_string(), CompletionItemKind::FIELD, None))
+                }
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// 特性所属 Bean 的字段
+fn get_bean_field_candidates(
+    builder: &CompletionBuilder,
+    attribute_use: &LuaDocAttributeUse,
+) -> Option<Vec<Candidate>> {
+    let bean_id = find_attribute_bean(builder, attribute_use)?;
+    let db = builder.semantic_model.get_db();
+    let member_index = db.get_member_index();
+    let members = builder
+        .semantic_model
+        .get_member_infos(&LuaType::Ref(bean_id))?;
+
+    let mut visited = HashSet::new();
+    let mut candidates = Vec::new();
+    for member in members {
+        let LuaMemberKey::Name(name) = &member.key else {
+            continue;
+        };
+        // 跳过 `Bean` 基类上的内置字段
+        if let Some(LuaSemanticDeclId::Member(member_id)) = &member.property_owner_id
+            && let Some(LuaMemberOwner::Type(owner_id)) = member_index.get_current_owner(member_id)
+            && BEAN.matches_decl(owner_id)
+        {
+            continue;
+        }
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+
+        candidates.push((
+            name.to_string(),
+            CompletionItemKind::FIELD,
+            member.property_owner_id.clone(),
+        ));
+    }
+
+    Some(candidates)
+}
+
+/// 找到特性作用的 Bean
+///
+/// - `---@[t.index(...)]` 作用于配置表时, 为配置表绑定的 Bean
+/// - `list<[v.index(...)] Item>` 作用于类型时, 为被标注的类型
+/// - 作用于 `---@field` 时, 为字段类型(或其容器元素类型)
+fn find_attribute_bean(
+    builder: &CompletionBuilder,
+    attribute_use: &LuaDocAttributeUse,
+) -> Option<LuaTypeDeclId> {
+    let db = builder.semantic_model.get_db();
+    let file_id = builder.semantic_model.get_file_id();
+    let tag_use = attribute_use.get_parent::<LuaDocTagAttributeUse>()?;
+
+    let owner_type = if tag_use.syntax().parent()?.kind() == LuaSyntaxKind::Comment.into() {
+        match find_attached_tag(tag_use.syntax())? {
+            AttachedTag::Class(class) => {
+                let name = class.get_name_token()?;
+                let decl = db
+                    .get_type_index()
+                    .find_type_decl(file_id, name.get_name_text())?;
+                let id = decl.get_id();
+                return CONFIG_TABLE
+                    .get_bean_id(db, &id)
+                    .or_else(|| BEAN.is_bean(db, &id).then_some(id));
+            }
+            AttachedTag::Field(field) => field.get_type()?,
+        }
+    } else {
+        tag_use.syntax().next_sibling().and_then(LuaDocType::cast)?
+    };
+
+    let doc_ctx = DocTypeInferContext::new(db, file_id);
+    get_element_bean_id(builder, &infer_doc_type(doc_ctx, &owner_type))
+}
+
+enum AttachedTag {
+    Class(LuaDocTagClass),
+    Field(LuaDocTagField),
+}
+
+/// 与分析器一致: 特性标注作用于其后第一个 `---@class` / `---@field`
+fn find_attached_tag(tag_use: &LuaSyntaxNode) -> Option<AttachedTag> {
+    let mut next_sibling = tag_use.next_sibling_or_token();
+    while let Some(sibling) = next_sibling {
+        match sibling.kind() {
+            LuaKind::Syntax(LuaSyntaxKind::DocTagClass) => {
+                return LuaDocTagClass::cast(sibling.into_node()?).map(AttachedTag::Class);
+            }
+            LuaKind::Syntax(LuaSyntaxKind::DocTagField) => {
+                return LuaDocTagField::cast(sibling.into_node()?).map(AttachedTag::Field);
+            }
+            LuaKind::Syntax(
+                LuaSyntaxKind::DocTagParam
+                | LuaSyntaxKind::DocTagReturn
+                | LuaSyntaxKind::DocTagEnum
+                | LuaSyntaxKind::Comment,
+            ) => return None,
+            _ => {}
+        }
+        next_sibling = sibling.next_sibling_or_token();
+    }
+    None
+}
+
+fn get_element_bean_id(builder: &CompletionBuilder, typ: &LuaType) -> Option<LuaTypeDeclId> {
+    let db = builder.semantic_model.get_db();
+    match typ.strip_attributed() {
+        LuaType::Ref(id) if BEAN.is_bean(db, id) => Some(id.clone()),
+        LuaType::Array(array) => get_element_bean_id(builder, array.get_base()),
+        LuaType::Generic(generic) => {
+            let params = generic.get_params();
+            match generic.get_base_type_id_ref().get_name() {
+                "array" | "list" | "set" => get_element_bean_id(builder, params.first()?),
+                "map" => get_element_bean_id(builder, params.get(1)?),
+                _ => None,
+            }
+        }
+        LuaType::TableGeneric(params) => get_element_bean_id(builder, params.get(1)?),
+        LuaType::Union(union) => union
+            .into_vec()
+            .iter()
+            .find_map(|inner| get_element_bean_id(builder, inner)),
+        _ => None,
+    }
+}
+
+/// 参数类型中的字符串字面量, 如 `t.mode(mode: "map" | "list" | "singleton")`
+fn get_literal_candidates(param_type: &LuaType) -> Vec<Candidate> {
+    let mut candidates = Vec::new();
+    collect_literal_candidates(param_type, &mut candidates);
+    candidates
+}
+
+fn collect_literal_candidates(typ: &LuaType, candidates: &mut Vec<Candidate>) {
+    match typ {
+        LuaType::DocStringConst(value) | LuaType::StringConst(value) => {
+            candidates.push((value.to_string(), CompletionItemKind::ENUM_MEMBER, None));
+        }
+        LuaType::Union(union) => {
+            for inner in union.into_vec() {
+                collect_literal_candidates(&inner, candidates);
+            }
+        }
+        LuaType::MultiLineUnion(multi) => {
+            collect_literal_candidates(&multi.to_union(), candidates);
+        }
+        _ => {}
+    }
+}
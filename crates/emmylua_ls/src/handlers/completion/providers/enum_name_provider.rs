@@ -0,0 +1,76 @@
+use emmylua_code_analysis::{
+    LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, RenderLevel,
+    attributes::is_flags_attribute,
+    humanize_type,
+    shared::luaconfig::{find_enum_name_target, get_enum_member_alias},
+};
+use emmylua_parser::{LuaAstToken, LuaLiteralExpr, LuaStringToken};
+use lsp_types::{CompletionItem, CompletionItemKind, CompletionTextEdit, TextEdit};
+
+use super::get_text_edit_range_in_string;
+use crate::handlers::completion::{
+    completion_builder::CompletionBuilder, completion_data::CompletionData,
+};
+
+/// Bean 数据中按名称书写的枚举值, 补全枚举成员名与别名
+pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
+    if builder.is_cancelled() {
+        return None;
+    }
+
+    let string_token = LuaStringToken::cast(builder.trigger_token.clone())?;
+    let literal = string_token.get_parent::<LuaLiteralExpr>()?;
+    let enum_id = find_enum_name_target(&builder.semantic_model, &literal)?;
+
+    let db = builder.semantic_model.get_db();
+    let is_flags = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(enum_id.clone()))
+        .is_some_and(is_flags_attribute);
+    // `flags` 枚举只替换最后一个 `|` 之后的名称
+    let value = string_token.get_value();
+    let prefix = match value.rfind('|') {
+        Some(idx) if is_flags => &value[..=idx],
+        _ => "",
+    };
+
+    let mut candidates = Vec::new();
+    for member in db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(enum_id.clone()))?
+    {
+        let LuaMemberKey::Name(name) = member.get_key() else {
+            continue;
+        };
+        let detail = db
+            .get_type_index()
+            .get_type_cache(&member.get_id().into())
+            .map(|type_cache| humanize_type(db, type_cache.as_type(), RenderLevel::Simple));
+        let owner_id = LuaSemanticDeclId::Member(member.get_id());
+        if let Some(alias) = get_enum_member_alias(db, &member.get_id()) {
+            candidates.push((alias, detail.clone(), owner_id.clone()));
+        }
+        candidates.push((name.to_string(), detail, owner_id));
+    }
+
+    let text_edit_range = get_text_edit_range_in_string(builder, string_token)?;
+    for (label, detail, owner_id) in candidates {
+        let new_text = format!("{prefix}{label}");
+        let data = CompletionData::from_property_owner_id(builder, owner_id, None);
+        builder.add_completion_item(CompletionItem {
+            label,
+            kind: Some(CompletionItemKind::ENUM_MEMBER),
+            detail: detail.map(|value| format!("{} = {value}", enum_id.get_name())),
+            filter_text: Some(new_text.clone()),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: text_edit_range,
+                new_text,
+            })),
+            data,
+            ..CompletionItem::default()
+        });
+    }
+
+    builder.stop_here();
+    Some(())
+}
@@ -1,8 +1,10 @@
+mod attribute_arg_provider;
 mod auto_require_provider;
 mod desc_provider;
 mod doc_name_token_provider;
 mod doc_tag_provider;
 mod doc_type_provider;
+mod enum_name_provider;
 mod env_provider;
 mod equality_provider;
 mod file_path_provider;
@@ -23,6 +25,8 @@ pub fn add_completions(builder: &mut CompletionBuilder) -> Option<()> {
     postfix_provider::add_completion(builder);
     // `function_provider`优先级必须高于`env_provider`
     function_provider::add_completion(builder);
+    // Bean 数据中的枚举名称补全成功时中止补全
+    enum_name_provider::add_completion(builder);
     equality_provider::add_completion(builder);
     // 如果`table_field_provider`执行成功会中止补全, 同时优先级必须高于`env_provider`
     table_field_provider::add_completion(builder);
@@ -30,6 +34,7 @@ pub fn add_completions(builder: &mut CompletionBuilder) -> Option<()> {
     keywords_provider::add_completion(builder);
     member_provider::add_completion(builder);
 
+    attribute_arg_provider::add_completion(builder);
     module_path_provider::add_completion(builder);
     file_path_provider::add_completion(builder);
     auto_require_provider::add_completion(builder);
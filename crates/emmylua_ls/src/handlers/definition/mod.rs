@@ -25,7 +25,7 @@ use super::RegisterCapabilities;
 use crate::context::ServerContextSnapshot;
 use crate::handlers::definition::goto_function::goto_overload_function;
 use crate::handlers::definition::goto_path::goto_path;
-use crate::util::find_ref_at;
+use crate::util::{find_config_union_ref_target, find_ref_at};
 
 pub async fn on_goto_definition_handler(
     context: ServerContextSnapshot,
@@ -72,6 +72,12 @@ pub fn definition(
         }
     };
 
+    if let Some(target) = find_config_union_ref_target(&semantic_model, &token) {
+        let document = semantic_model.get_document_by_file_id(target.file_id)?;
+        let location = document.to_lsp_location(target.range)?;
+        return Some(GotoDefinitionResponse::Scalar(location));
+    }
+
     if let Some(semantic_decl) =
         semantic_model.find_decl(token.clone().into(), SemanticDeclLevel::default())
     {
@@ -1,17 +1,24 @@
 use std::collections::HashMap;
 
 use emmylua_code_analysis::{
-    DbIndex, FileId, LuaDecl, LuaDeclId, LuaDeclarationTree, LuaDocument, LuaType, LuaTypeOwner,
+    DbIndex, FileId, LuaDecl, LuaDeclId, LuaDeclarationTree, LuaDocument, LuaType, LuaTypeDeclId,
+    LuaTypeOwner, shared::luaconfig::CONFIG_TABLE,
+};
+use emmylua_parser::{
+    LuaAstNode, LuaChunk, LuaSyntaxId, LuaSyntaxNode, LuaSyntaxToken, LuaTableExpr, LuaTableField,
 };
-use emmylua_parser::{LuaAstNode, LuaChunk, LuaSyntaxId, LuaSyntaxNode, LuaSyntaxToken};
 use lsp_types::{DocumentSymbol, SymbolKind};
 use rowan::TextRange;
 
+use crate::util::ConfigRow;
+
 pub struct DocumentSymbolBuilder<'a> {
     db: &'a DbIndex,
     decl_tree: &'a LuaDeclarationTree,
     document: &'a LuaDocument<'a>,
     document_symbols: HashMap<LuaSyntaxId, Box<LuaSymbol>>,
+    config_rows: HashMap<TextRange, ConfigRow>,
+    config_tables: HashMap<TextRange, LuaTypeDeclId>,
 }
 
 impl<'a> DocumentSymbolBuilder<'a> {
@@ -25,9 +32,42 @@ impl<'a> DocumentSymbolBuilder<'a> {
             decl_tree,
             document,
             document_symbols: HashMap::new(),
+            config_rows: HashMap::new(),
+            config_tables: HashMap::new(),
         }
     }
 
+    pub fn get_db(&self) -> &DbIndex {
+        self.db
+    }
+
+    /// 记录文件中的配置表记录, 包含这些记录的表字面量将按记录构建符号
+    pub fn set_config_rows(&mut self, rows: Vec<ConfigRow>) {
+        for row in rows {
+            if let Some(table) = row
+                .row
+                .get_parent::<LuaTableField>()
+                .and_then(|field| field.get_parent::<LuaTableExpr>())
+            {
+                self.config_tables
+                    .insert(table.get_range(), row.config_table.clone());
+            }
+            self.config_rows.insert(row.row.get_range(), row);
+        }
+    }
+
+    pub fn get_config_row(&self, row: &LuaTableExpr) -> Option<&ConfigRow> {
+        self.config_rows.get(&row.get_range())
+    }
+
+    pub fn get_config_table(&self, table: &LuaTableExpr) -> Option<LuaTypeDeclId> {
+        self.config_tables.get(&table.get_range()).cloned()
+    }
+
+    pub fn get_config_table_bean(&self, config_table: &LuaTypeDeclId) -> Option<LuaTypeDeclId> {
+        CONFIG_TABLE.get_bean_id(self.db, config_table)
+    }
+
     pub fn get_file_id(&self) -> FileId {
         self.document.get_file_id()
     }
@@ -0,0 +1,150 @@
+use emmylua_code_analysis::{
+    LuaMemberKey, LuaType, LuaTypeDeclId, find_members_with_key, shared::luaconfig::BEAN,
+};
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaExpr, LuaIndexKey, LuaSyntaxId, LuaTableExpr};
+use lsp_types::SymbolKind;
+
+use super::builder::{DocumentSymbolBuilder, LuaSymbol};
+
+/// 为配置表数据构建按记录分组的符号, 每条记录以主键(及展示字段)命名, 嵌套的 Bean 作为子符号
+pub fn build_config_table_symbol(
+    builder: &mut DocumentSymbolBuilder,
+    table: LuaTableExpr,
+    config_table: &LuaTypeDeclId,
+    parent_id: LuaSyntaxId,
+) -> Option<()> {
+    let bean_id = builder.get_config_table_bean(config_table);
+    for field in table.get_fields() {
+        let Some(LuaExpr::TableExpr(row)) = field.get_value_expr() else {
+            continue;
+        };
+
+        let (name, selection_range) = match builder.get_config_row(&row) {
+            Some(config_row) => (config_row.name.clone(), config_row.pk_range),
+            None => {
+                let Some(name) = get_field_name(field.get_field_key()) else {
+                    continue;
+                };
+                (name, row.get_range())
+            }
+        };
+        let symbol = LuaSymbol::with_selection_range(
+            name,
+            bean_id.as_ref().map(|id| id.get_name().to_string()),
+            SymbolKind::STRUCT,
+            row.get_range(),
+            selection_range,
+        );
+        let row_id = builder.add_node_symbol(row.syntax().clone(), symbol, Some(parent_id));
+
+        if let Some(bean_id) = &bean_id {
+            build_bean_children_symbol(builder, &row, bean_id, row_id);
+        }
+    }
+
+    Some(())
+}
+
+fn build_bean_children_symbol(
+    builder: &mut DocumentSymbolBuilder,
+    table: &LuaTableExpr,
+    bean_id: &LuaTypeDeclId,
+    parent_id: LuaSyntaxId,
+) -> Option<()> {
+    for field in table.get_fields() {
+        let Some(LuaExpr::TableExpr(value)) = field.get_value_expr() else {
+            continue;
+        };
+        let Some(LuaIndexKey::Name(name)) = field.get_field_key() else {
+            continue;
+        };
+
+        let member_key = LuaMemberKey::Name(name.get_name_text().into());
+        let Some(member_typ) = find_members_with_key(
+            builder.get_db(),
+            &LuaType::Ref(bean_id.clone()),
+            member_key,
+            false,
+        )
+        .and_then(|infos| infos.first().map(|info| info.typ.clone())) else {
+            continue;
+        };
+
+        if let Some(field_bean_id) = get_bean_id(builder, &member_typ) {
+            let symbol = LuaSymbol::with_selection_range(
+                name.get_name_text().to_string(),
+                Some(field_bean_id.get_name().to_string()),
+                SymbolKind::STRUCT,
+                field.get_range(),
+                name.get_range(),
+            );
+            let field_id = builder.add_node_symbol(field.syntax().clone(), symbol, Some(parent_id));
+            build_bean_children_symbol(builder, &value, &field_bean_id, field_id);
+        } else if let Some(element_bean_id) = get_container_bean_id(builder, &member_typ) {
+            let symbol = LuaSymbol::with_selection_range(
+                name.get_name_text().to_string(),
+                Some(element_bean_id.get_name().to_string()),
+                SymbolKind::ARRAY,
+                field.get_range(),
+                name.get_range(),
+            );
+            let field_id = builder.add_node_symbol(field.syntax().clone(), symbol, Some(parent_id));
+            for element_field in value.get_fields() {
+                let Some(LuaExpr::TableExpr(element)) = element_field.get_value_expr() else {
+                    continue;
+                };
+                let Some(element_name) = get_field_name(element_field.get_field_key()) else {
+                    continue;
+                };
+
+                let symbol = LuaSymbol::new(
+                    element_name,
+                    Some(element_bean_id.get_name().to_string()),
+                    SymbolKind::STRUCT,
+                    element.get_range(),
+                );
+                let element_id =
+                    builder.add_node_symbol(element.syntax().clone(), symbol, Some(field_id));
+                build_bean_children_symbol(builder, &element, &element_bean_id, element_id);
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn get_field_name(key: Option<LuaIndexKey>) -> Option<String> {
+    Some(key?.get_path_part())
+}
+
+fn get_bean_id(builder: &DocumentSymbolBuilder, typ: &LuaType) -> Option<LuaTypeDeclId> {
+    match typ.strip_attributed() {
+        LuaType::Ref(id) if BEAN.is_bean(builder.get_db(), id) => Some(id.clone()),
+        LuaType::Union(union) => {
+            let mut bean_ids = union
+                .into_vec()
+                .into_iter()
+                .filter_map(|inner| get_bean_id(builder, &inner));
+            let first = bean_ids.next()?;
+            bean_ids.next().is_none().then_some(first)
+        }
+        _ => None,
+    }
+}
+
+/// `array<Bean>` / `list<Bean>` / `set<Bean>` / `Bean[]` / `map<K, Bean>` 的元素 Bean
+fn get_container_bean_id(builder: &DocumentSymbolBuilder, typ: &LuaType) -> Option<LuaTypeDeclId> {
+    match typ.strip_attributed() {
+        LuaType::Array(array) => get_bean_id(builder, array.get_base()),
+        LuaType::Generic(generic) => {
+            let params = generic.get_params();
+            match generic.get_base_type_id_ref().get_name() {
+                "array" | "list" | "set" => get_bean_id(builder, params.first()?),
+                "map" => get_bean_id(builder, params.get(1)?),
+                _ => None,
+            }
+        }
+        LuaType::TableGeneric(params) => get_bean_id(builder, params.get(1)?),
+        _ => None,
+    }
+}
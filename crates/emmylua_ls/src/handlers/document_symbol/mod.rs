@@ -1,10 +1,12 @@
 mod builder;
 mod comment;
+mod config;
 mod expr;
 mod stats;
 
 use builder::{DocumentSymbolBuilder, LuaSymbol};
-use emmylua_code_analysis::SemanticModel;
+use config::build_config_table_symbol;
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, SemanticModel};
 use emmylua_parser::{
     LuaAstNode, LuaBlock, LuaChunk, LuaComment, LuaExpr, LuaSingleArgExpr, LuaStat, LuaSyntaxId,
     LuaSyntaxNode,
@@ -21,7 +23,7 @@ use stats::{
 };
 use tokio_util::sync::CancellationToken;
 
-use crate::context::ServerContextSnapshot;
+use crate::{context::ServerContextSnapshot, util::collect_config_rows};
 
 use super::RegisterCapabilities;
 use comment::build_doc_region_symbol;
@@ -34,6 +36,13 @@ pub async fn on_document_symbol(
     let uri = params.text_document.uri;
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
+    document_symbol(&analysis, file_id)
+}
+
+pub fn document_symbol(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+) -> Option<DocumentSymbolResponse> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let document_symbol_root = build_document_symbol(&semantic_model)?;
     // remove root file symbol
@@ -53,6 +62,7 @@ fn build_document_symbol(semantic_model: &SemanticModel) -> Option<DocumentSymbo
     let db = semantic_model.get_db();
 
     let mut builder = DocumentSymbolBuilder::new(db, decl_tree, &document);
+    builder.set_config_rows(collect_config_rows(db, file_id, root, |_| true));
     let symbol = LuaSymbol::new("".into(), None, SymbolKind::FILE, root.get_range());
     let root_id = builder.add_node_symbol(root.syntax().clone(), symbol, None);
     build_child_document_symbols(&mut builder, root, root_id);
@@ -263,6 +273,9 @@ fn process_expr(
 ) -> Option<()> {
     match expr {
         LuaExpr::TableExpr(table) => {
+            if let Some(config_table) = builder.get_config_table(&table) {
+                return build_config_table_symbol(builder, table, &config_table, parent_id);
+            }
             if !inline_table_to_parent {
                 if table.is_object() {
                     for field in table.get_fields() {
@@ -1,9 +1,11 @@
+use std::collections::HashMap;
+
 use emmylua_code_analysis::LuaDocument;
-use emmylua_parser::{LuaAstNode, LuaBlock, LuaChunk, LuaTokenKind};
+use emmylua_parser::{LuaAstNode, LuaBlock, LuaChunk, LuaTableExpr, LuaTokenKind};
 use lsp_types::{FoldingRange, FoldingRangeKind};
 use rowan::TextRange;
 
-use crate::context::ClientId;
+use crate::{context::ClientId, util::ConfigRow};
 
 #[derive(Debug)]
 pub struct FoldingRangeBuilder<'a> {
@@ -12,6 +14,7 @@ pub struct FoldingRangeBuilder<'a> {
     folding_ranges: Vec<FoldingRange>,
     region_starts: Vec<TextRange>,
     client_id: ClientId,
+    config_row_names: HashMap<TextRange, String>,
 }
 
 impl FoldingRangeBuilder<'_> {
@@ -26,9 +29,23 @@ impl FoldingRangeBuilder<'_> {
             folding_ranges: Vec::new(),
             region_starts: Vec::new(),
             client_id,
+            config_row_names: HashMap::new(),
+        }
+    }
+
+    /// 记录文件中的配置表记录, 折叠后以记录名称代替表内容
+    pub fn set_config_rows(&mut self, rows: Vec<ConfigRow>) {
+        for row in rows {
+            self.config_row_names.insert(row.row.get_range(), row.name);
         }
     }
 
+    pub fn get_config_row_name(&self, table: &LuaTableExpr) -> Option<&str> {
+        self.config_row_names
+            .get(&table.get_range())
+            .map(|name| name.as_str())
+    }
+
     pub fn get_root(&self) -> &LuaChunk {
         &self.root
     }
@@ -25,7 +25,12 @@ pub fn build_table_expr_fold_range(
         end_line: folding_lsp_range.end.line,
         end_character: Some(folding_lsp_range.end.character),
         kind: Some(FoldingRangeKind::Region),
-        collapsed_text: Some(" .. ".to_string()),
+        collapsed_text: Some(
+            builder
+                .get_config_row_name(&table_expr)
+                .map(|name| format!(" {name} "))
+                .unwrap_or_else(|| " .. ".to_string()),
+        ),
     };
 
     builder.push(folding_range);
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use emmylua_code_analysis::humanize_type;
+use emmylua_code_analysis::shared::luaconfig::BEAN;
 use emmylua_code_analysis::{
     DbIndex, LuaCompilation, LuaDeclId, LuaDocument, LuaMemberId, LuaMemberKey, LuaSemanticDeclId,
     LuaSignatureId, LuaType, RenderLevel, SemanticInfo, SemanticModel,
@@ -255,8 +256,15 @@ fn build_member_hover(
                 RenderLevel::Simple
             };
             let type_humanize_text = hover_humanize_type(builder, &member_hover_type, Some(level));
-            builder
-                .set_type_description(format!("(field) {}: {}", member_name, type_humanize_text));
+            // Bean 字段的默认值
+            let default_value = BEAN
+                .get_field_default(db, &member_id)
+                .map(|value| format!(" = {}", humanize_type(db, &value, RenderLevel::Simple)))
+                .unwrap_or_default();
+            builder.set_type_description(format!(
+                "(field) {}: {}{}",
+                member_name, type_humanize_text, default_value
+            ));
             builder.set_location_path(Some(member));
         }
 
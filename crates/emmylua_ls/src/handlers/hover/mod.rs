@@ -8,11 +8,24 @@ mod keyword_hover;
 
 use super::RegisterCapabilities;
 use crate::context::ServerContextSnapshot;
-use crate::util::{find_ref_at, resolve_ref_single};
+use crate::util::{find_config_union_ref_target, find_ref_at, resolve_ref_single};
 pub use build_hover::build_hover_content_for_completion;
 use build_hover::build_semantic_info_hover;
-use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, WorkspaceId};
-use emmylua_parser::{LuaAstNode, LuaDocDescription, LuaTokenKind};
+use emmylua_code_analysis::{
+    EmmyLuaAnalysis, FileId, LuaMemberOwner, LuaSemanticDeclId, LuaType, RenderLevel, SemanticInfo,
+    SemanticModel, WorkspaceId,
+    attributes::WeightKind,
+    humanize_type,
+    shared::luaconfig::{
+        collect_weight_groups, find_enum_name_target, find_vector_type, find_weight_records,
+        format_weight_value, get_vector_component_key, get_vector_components, get_weight_rules,
+        resolve_enum_name,
+    },
+};
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaDocDescription, LuaLiteralExpr, LuaStringToken, LuaSyntaxToken,
+    LuaTableExpr, LuaTableField, LuaTokenKind,
+};
 use emmylua_parser_desc::parse_ref_target;
 pub use find_origin::{find_all_same_named_members, find_member_origin_owner};
 pub use hover_builder::HoverBuilder;
@@ -70,6 +83,30 @@ pub fn hover(analysis: &EmmyLuaAnalysis, file_id: FileId, position: Position) ->
         }
         TokenAtOffset::None => return None,
     };
+    if let Some(target) = find_config_union_ref_target(&semantic_model, &token) {
+        let document = semantic_model.get_document();
+        let row_name = target.get_row_name(&semantic_model).unwrap_or_default();
+        return Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: lsp_types::MarkupKind::Markdown,
+                value: format!(
+                    "```lua\n(ref) {}: {}\n```",
+                    target.config_table.get_name(),
+                    row_name
+                ),
+            }),
+            range: document.to_lsp_range(token.text_range()),
+        });
+    }
+    if let Some(hover) = hover_enum_name(&semantic_model, &token) {
+        return Some(hover);
+    }
+    if let Some(hover) = hover_vector_component(analysis, &semantic_model, &token) {
+        return Some(hover);
+    }
+    if let Some(hover) = hover_weight_value(&semantic_model, &token) {
+        return Some(hover);
+    }
     match token {
         keywords if is_keyword(keywords.clone()) => {
             let document = semantic_model.get_document();
@@ -155,3 +192,132 @@ impl RegisterCapabilities for HoverCapabilities {
         server_capabilities.hover_provider = Some(HoverProviderCapability::Simple(true));
     }
 }
+
+/// Bean 数据中按名称书写的枚举值, 显示解析后的值
+fn hover_enum_name(semantic_model: &SemanticModel, token: &LuaSyntaxToken) -> Option<Hover> {
+    let string_token = LuaStringToken::cast(token.clone())?;
+    let literal = string_token.get_parent::<LuaLiteralExpr>()?;
+    let enum_id = find_enum_name_target(semantic_model, &literal)?;
+    let name = string_token.get_value();
+    let db = semantic_model.get_db();
+    let value = resolve_enum_name(db, &enum_id, &name).ok()?;
+
+    let document = semantic_model.get_document();
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: lsp_types::MarkupKind::Markdown,
+            value: format!(
+                "```lua\n(enum) {}: {} = {}\n```",
+                enum_id.get_name(),
+                name,
+                humanize_type(db, &value, RenderLevel::Simple)
+            ),
+        }),
+        range: document.to_lsp_range(token.text_range()),
+    })
+}
+
+/// 内置向量类型按位置书写的分量, 显示对应字段
+fn hover_vector_component(
+    analysis: &EmmyLuaAnalysis,
+    semantic_model: &SemanticModel,
+    token: &LuaSyntaxToken,
+) -> Option<Hover> {
+    let literal = LuaLiteralExpr::cast(token.parent()?)?;
+    let field = literal.get_parent::<LuaTableField>()?;
+    if !field.is_value_field() {
+        return None;
+    }
+    let table_expr = field.get_parent::<LuaTableExpr>()?;
+    let db = semantic_model.get_db();
+    let vector_id = find_vector_type(db, &semantic_model.infer_table_should_be(table_expr)?)?;
+    let components = get_vector_components(db, &vector_id)?;
+    let key = semantic_model.get_member_key(&field.get_field_key()?)?;
+    let component_key = get_vector_component_key(components, &key)?;
+
+    let db = semantic_model.get_db();
+    let member = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(vector_id))?
+        .into_iter()
+        .find(|member| member.get_key() == &component_key)?;
+    let typ = db
+        .get_type_index()
+        .get_type_cache(&member.get_id().into())
+        .map(|type_cache| type_cache.as_type().clone())
+        .unwrap_or(LuaType::Unknown);
+
+    build_semantic_info_hover(
+        &analysis.compilation,
+        semantic_model,
+        db,
+        &semantic_model.get_document(),
+        token.clone(),
+        SemanticInfo {
+            typ,
+            semantic_decl: Some(LuaSemanticDeclId::Member(member.get_id())),
+        },
+        token.text_range(),
+    )
+}
+
+/// 带有 `v.weight_sum`/`v.prob_sum` 的字段值, 显示所在组的实际总和与该记录的占比
+fn hover_weight_value(semantic_model: &SemanticModel, token: &LuaSyntaxToken) -> Option<Hover> {
+    let literal = LuaLiteralExpr::cast(token.parent()?)?;
+    let field = literal.get_parent::<LuaTableField>()?;
+    let name = field.get_field_key()?.get_path_part();
+    let row = field.get_parent::<LuaTableExpr>()?;
+    let container = row
+        .get_parent::<LuaTableField>()?
+        .get_parent::<LuaTableExpr>()?;
+    let container_type = semantic_model.infer_table_should_be(container.clone())?;
+
+    let db = semantic_model.get_db();
+    let file_id = semantic_model.get_file_id();
+    let (bean_id, rows) = find_weight_records(db, file_id, &container, &container_type)?;
+    let rule = get_weight_rules(db, &bean_id)
+        .into_iter()
+        .find(|rule| rule.field == name)?;
+    let row_range = row.get_range();
+    let (group, value) = collect_weight_groups(db, &rule, &rows)
+        .into_iter()
+        .find_map(|group| {
+            let value = group
+                .entries
+                .iter()
+                .find(|entry| entry.file_id == file_id && entry.row_range == row_range)?
+                .value;
+            Some((group, value))
+        })?;
+
+    let kind = match rule.spec.kind {
+        WeightKind::Weight { .. } => "weight",
+        WeightKind::Prob => "prob",
+    };
+    let percentage = if group.sum != 0.0 {
+        format!("{}%", format_weight_value(value / group.sum * 100.0))
+    } else {
+        "-".to_string()
+    };
+    let group_text = group
+        .key
+        .map(|key| format!(" in group `{}`", key))
+        .unwrap_or_default();
+    let document = semantic_model.get_document();
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: lsp_types::MarkupKind::Markdown,
+            value: format!(
+                "```lua\n({}) {} = {}\n```\n\n{} of {}{} (expected {})",
+                kind,
+                rule.field,
+                format_weight_value(value),
+                percentage,
+                format_weight_value(group.sum),
+                group_text,
+                format_weight_value(rule.spec.expected_sum())
+            ),
+        }),
+        range: document.to_lsp_range(token.text_range()),
+    })
+}
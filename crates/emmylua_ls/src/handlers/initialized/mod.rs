@@ -77,6 +77,12 @@ pub async fn initialized_handler(
     let emmyrc = load_emmy_config(config_root, client_config.clone());
     load_editorconfig(workspace_folders.clone());
 
+    context
+        .analysis()
+        .write()
+        .await
+        .set_external_validators_trusted(cmd_args.allow_external_validators.0);
+
     // init std lib
     init_std_lib(context.analysis(), &cmd_args, emmyrc.clone()).await;
 
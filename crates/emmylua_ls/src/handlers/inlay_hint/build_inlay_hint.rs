@@ -1,15 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use emmylua_code_analysis::{
     AsyncState, FileId, InferGuard, LuaFunctionType, LuaMember, LuaMemberId, LuaMemberKey,
     LuaMemberOwner, LuaOperatorId, LuaOperatorMetaMethod, LuaSemanticDeclId, LuaType, LuaTypeDecl,
-    SemanticModel,
+    RenderLevel, SemanticModel, humanize_type,
+    shared::luaconfig::{BEAN, find_vector_type, get_vector_component_key, get_vector_components},
 };
 use emmylua_parser::{
     LuaAst, LuaAstNode, LuaCallExpr, LuaExpr, LuaFuncStat, LuaIndexExpr, LuaIndexKey,
     LuaLiteralToken, LuaLocalFuncStat, LuaLocalName, LuaLocalStat, LuaStat, LuaSyntaxId,
-    LuaVarExpr,
+    LuaTableExpr, LuaVarExpr,
 };
 use emmylua_parser::{LuaAstToken, LuaTokenKind};
 use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, InlayHintLabelPart, Location};
@@ -51,6 +52,10 @@ pub fn build_inlay_hints(
             LuaAst::LuaIndexExpr(index_expr) => {
                 build_index_expr_hint(semantic_model, &mut result, index_expr);
             }
+            LuaAst::LuaTableExpr(table_expr) => {
+                build_vector_component_hint(semantic_model, &mut result, table_expr.clone());
+                build_default_value_hint(semantic_model, &mut result, table_expr);
+            }
             _ => {}
         }
     }
@@ -683,6 +688,146 @@ fn build_index_expr_hint(
     Some(())
 }
 
+/// 在 Bean 数据的 `}` 前显示省略字段的默认值
+fn build_default_value_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    table_expr: LuaTableExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.default_value_hint {
+        return Some(());
+    }
+
+    let db = semantic_model.get_db();
+    let LuaType::Ref(bean_id) = semantic_model.infer_table_should_be(table_expr.clone())? else {
+        return Some(());
+    };
+    // 只有内置向量类型可以按位置书写
+    let components = get_vector_components(db, &bean_id);
+    if !BEAN.is_bean(db, &bean_id)
+        || (components.is_none() && table_expr.get_fields().any(|f| f.is_value_field()))
+    {
+        return Some(());
+    }
+
+    let current_fields: HashSet<String> = table_expr
+        .get_fields()
+        .filter_map(|field| {
+            let key = semantic_model.get_member_key(&field.get_field_key()?)?;
+            match components {
+                Some(components) => get_vector_component_key(components, &key),
+                None => Some(key),
+            }
+        })
+        .map(|key| key.to_path())
+        .collect();
+    let mut visited = HashSet::new();
+    let mut label_parts = Vec::new();
+    for super_type in bean_id.collect_super_types_with_self(db, LuaType::Ref(bean_id.clone())) {
+        let LuaType::Ref(type_id) = super_type else {
+            continue;
+        };
+        let Some(members) = db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::Type(type_id))
+        else {
+            continue;
+        };
+        let mut members = members.to_vec();
+        members.sort_by_key(|member| member.get_sort_key());
+
+        for member in members {
+            let name = member.get_key().to_path();
+            if !visited.insert(name.clone()) || current_fields.contains(&name) {
+                continue;
+            }
+            let Some(value) = BEAN.get_field_default(db, &member.get_id()) else {
+                continue;
+            };
+
+            if !label_parts.is_empty() {
+                label_parts.push(InlayHintLabelPart {
+                    value: ", ".to_string(),
+                    ..Default::default()
+                });
+            }
+            label_parts.push(InlayHintLabelPart {
+                value: format!(
+                    "{} = {}",
+                    name,
+                    humanize_type(db, &value, RenderLevel::Simple)
+                ),
+                location: get_override_lsp_location(
+                    semantic_model,
+                    member.get_file_id(),
+                    member.get_syntax_id(),
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    if label_parts.is_empty() {
+        return Some(());
+    }
+
+    let right_brace = table_expr
+        .syntax()
+        .last_token()
+        .filter(|token| token.kind() == LuaTokenKind::TkRightBrace.into())?;
+    let position = semantic_model
+        .get_document()
+        .to_lsp_position(right_brace.text_range().start())?;
+
+    result.push(InlayHint {
+        kind: Some(InlayHintKind::PARAMETER),
+        label: InlayHintLabel::LabelParts(label_parts),
+        position,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: Some(true),
+        data: None,
+    });
+    Some(())
+}
+
+/// 在内置向量类型按位置书写的分量前显示分量名, 如 `{x: 1, y: 2}`
+fn build_vector_component_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    table_expr: LuaTableExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.param_hint {
+        return Some(());
+    }
+
+    let db = semantic_model.get_db();
+    let vector_id = find_vector_type(
+        db,
+        &semantic_model.infer_table_should_be(table_expr.clone())?,
+    )?;
+    let components = get_vector_components(db, &vector_id)?;
+    let document = semantic_model.get_document();
+    for (field, component) in table_expr
+        .get_fields()
+        .filter(|field| field.is_value_field())
+        .zip(components)
+    {
+        let position = document.to_lsp_position(field.get_position())?;
+        result.push(InlayHint {
+            kind: Some(InlayHintKind::PARAMETER),
+            label: InlayHintLabel::String(format!("{component}:")),
+            position,
+            text_edits: None,
+            tooltip: None,
+            padding_left: None,
+            padding_right: Some(true),
+            data: None,
+        });
+    }
+    Some(())
+}
+
 fn build_enum_param_hint(
     semantic_model: &SemanticModel,
     result: &mut Vec<InlayHint>,
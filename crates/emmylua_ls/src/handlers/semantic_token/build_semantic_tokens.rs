@@ -1,6 +1,7 @@
 use super::{
     SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES, semantic_token_builder::SemanticBuilder,
 };
+use crate::handlers::semantic_token::config_data_highlight::config_data_highlight;
 use crate::handlers::semantic_token::function_string_highlight::fun_string_highlight;
 use crate::handlers::semantic_token::semantic_token_builder::CustomSemanticTokenType;
 use crate::util::parse_desc;
@@ -35,6 +36,8 @@ pub fn build_semantic_tokens(
         SEMANTIC_TOKEN_MODIFIERS.to_vec(),
     );
 
+    config_data_highlight(&mut builder, semantic_model);
+
     for node_or_token in root.syntax().descendants_with_tokens() {
         match node_or_token {
             NodeOrToken::Node(node) => {
@@ -0,0 +1,350 @@
+use emmylua_code_analysis::{
+    ConfigTablePkOccurrence, FileId, LuaMemberKey, LuaSemanticDeclId, LuaType, LuaTypeDeclId,
+    SemanticModel,
+    attributes::VRefAttribute,
+    find_members_with_key,
+    shared::luaconfig::{BEAN, CONFIG_TABLE},
+};
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaExpr, LuaIndexKey, LuaSyntaxNode, LuaSyntaxToken, LuaTableExpr,
+    LuaTableField, LuaTokenKind, NumberResult,
+};
+use internment::ArcIntern;
+use lsp_types::{SemanticTokenModifier, SemanticTokenType};
+use rowan::{NodeOrToken, TextRange};
+
+use super::semantic_token_builder::{CustomSemanticTokenModifier, SemanticBuilder};
+use crate::util::collect_config_rows;
+
+const TAG_FIELD_NAME: &str = "__tag__";
+
+/// 为配置表数据着色: 主键字段, `v.ref` 约束的值(目标不存在时单独标记), 枚举值与 `__tag__` 值
+///
+/// 需要在常规着色之前调用, 以保证这里推送的 token 优先生效.
+pub fn config_data_highlight(
+    builder: &mut SemanticBuilder,
+    semantic_model: &SemanticModel,
+) -> Option<()> {
+    let db = semantic_model.get_db();
+    let file_id = semantic_model.get_file_id();
+    let occurrences = db
+        .get_config_index()
+        .get_config_table_pk_occurrences(&file_id)?;
+    let root = semantic_model.get_root();
+
+    for occurrence in occurrences {
+        let ranges = match occurrence {
+            ConfigTablePkOccurrence::Solo { range, .. } => vec![*range],
+            ConfigTablePkOccurrence::Union { ranges, .. } => ranges.to_vec(),
+        };
+        for range in ranges {
+            highlight_primary_key_field(builder, root.syntax(), range);
+        }
+    }
+
+    let mut highlighter = ConfigDataHighlighter {
+        semantic_model,
+        file_id,
+    };
+    for row in collect_config_rows(db, file_id, root, |_| true) {
+        let Some(bean_id) = CONFIG_TABLE.get_bean_id(db, &row.config_table) else {
+            continue;
+        };
+        highlighter.highlight_bean_table(builder, &row.row, &bean_id);
+    }
+
+    Some(())
+}
+
+fn highlight_primary_key_field(
+    builder: &mut SemanticBuilder,
+    root: &LuaSyntaxNode,
+    range: TextRange,
+) -> Option<()> {
+    let node = match root.covering_element(range) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let field = node.ancestors().find_map(LuaTableField::cast)?;
+    let LuaIndexKey::Name(name) = field.get_field_key()? else {
+        return None;
+    };
+    builder.push_with_modifiers(
+        name.syntax(),
+        SemanticTokenType::PROPERTY,
+        &[
+            SemanticTokenModifier::DECLARATION,
+            CustomSemanticTokenModifier::PRIMARY_KEY,
+        ],
+    )
+}
+
+struct ConfigDataHighlighter<'a> {
+    semantic_model: &'a SemanticModel<'a>,
+    file_id: FileId,
+}
+
+impl ConfigDataHighlighter<'_> {
+    fn highlight_bean_table(
+        &mut self,
+        builder: &mut SemanticBuilder,
+        table: &LuaTableExpr,
+        bean_id: &LuaTypeDeclId,
+    ) {
+        let db = self.semantic_model.get_db();
+        for field in table.get_fields() {
+            let Some(LuaIndexKey::Name(name)) = field.get_field_key() else {
+                continue;
+            };
+            let Some(value_expr) = field.get_value_expr() else {
+                continue;
+            };
+
+            let name = name.get_name_text();
+            if name == TAG_FIELD_NAME {
+                self.push_literal(builder, &value_expr, CustomSemanticTokenModifier::TAG);
+                continue;
+            }
+
+            let Some(info) = find_members_with_key(
+                db,
+                &LuaType::Ref(bean_id.clone()),
+                LuaMemberKey::Name(name.into()),
+                false,
+            )
+            .and_then(|infos| infos.into_iter().next()) else {
+                continue;
+            };
+
+            let property_target = info.property_owner_id.as_ref().and_then(|owner_id| {
+                let property = db.get_property_index().get_property(owner_id)?;
+                let file_id = match owner_id {
+                    LuaSemanticDeclId::Member(member_id) => member_id.file_id,
+                    _ => self.file_id,
+                };
+                VRefAttribute::find_in(property)?.resolve_target(db, file_id)
+            });
+            if let Some(target) = property_target {
+                self.highlight_ref_value(builder, &value_expr, &target);
+            } else {
+                self.highlight_value(builder, &value_expr, &info.typ);
+            }
+        }
+    }
+
+    fn highlight_value(&mut self, builder: &mut SemanticBuilder, value: &LuaExpr, typ: &LuaType) {
+        if let Some(target) = self.get_vref_target(typ) {
+            self.highlight_ref_value(builder, value, &target);
+            return;
+        }
+
+        if self.is_enum_type(typ) {
+            self.push_literal(builder, value, CustomSemanticTokenModifier::ENUM);
+            return;
+        }
+
+        let LuaExpr::TableExpr(table) = value else {
+            return;
+        };
+        if let Some(bean_id) = self.get_bean_id(typ) {
+            self.highlight_bean_table(builder, table, &bean_id);
+            return;
+        }
+
+        match typ.strip_attributed() {
+            LuaType::Array(array) => self.highlight_elements(builder, table, array.get_base()),
+            LuaType::Generic(generic) => {
+                let params = generic.get_params();
+                match generic.get_base_type_id_ref().get_name() {
+                    "array" | "list" | "set" => {
+                        if let Some(element) = params.first() {
+                            self.highlight_elements(builder, table, element);
+                        }
+                    }
+                    "map" => {
+                        if let (Some(key), Some(value)) = (params.first(), params.get(1)) {
+                            self.highlight_map(builder, table, key, value);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            LuaType::TableGeneric(params) => {
+                if let (Some(key), Some(value)) = (params.first(), params.get(1)) {
+                    self.highlight_map(builder, table, key, value);
+                }
+            }
+            LuaType::Union(union) => {
+                let types = union.into_vec();
+                let mut non_nil = types.iter().filter(|typ| !typ.is_nil());
+                if let (Some(inner), None) = (non_nil.next(), non_nil.next()) {
+                    self.highlight_value(builder, value, inner);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn highlight_elements(
+        &mut self,
+        builder: &mut SemanticBuilder,
+        table: &LuaTableExpr,
+        element_typ: &LuaType,
+    ) {
+        for field in table.get_fields() {
+            if let Some(value) = field.get_value_expr() {
+                self.highlight_value(builder, &value, element_typ);
+            }
+        }
+    }
+
+    fn highlight_map(
+        &mut self,
+        builder: &mut SemanticBuilder,
+        table: &LuaTableExpr,
+        key_typ: &LuaType,
+        value_typ: &LuaType,
+    ) {
+        let key_target = self.get_vref_target(key_typ);
+        let key_is_enum = self.is_enum_type(key_typ);
+        for field in table.get_fields() {
+            if let Some(key) = field.get_field_key()
+                && let Some((key_token, key_value)) = self.get_key_literal(&key)
+            {
+                if let Some(target) = &key_target {
+                    self.push_ref_token(builder, &key_token, &key_value, target);
+                } else if key_is_enum {
+                    push_token(builder, &key_token, CustomSemanticTokenModifier::ENUM);
+                }
+            }
+
+            if let Some(value) = field.get_value_expr() {
+                self.highlight_value(builder, &value, value_typ);
+            }
+        }
+    }
+
+    fn highlight_ref_value(
+        &mut self,
+        builder: &mut SemanticBuilder,
+        value: &LuaExpr,
+        target: &(LuaTypeDeclId, LuaMemberKey),
+    ) -> Option<()> {
+        let token = get_literal_token(value)?;
+        let value = self.semantic_model.infer_expr(value.clone()).ok()?;
+        self.push_ref_token(builder, &token, &value, target)
+    }
+
+    fn push_ref_token(
+        &mut self,
+        builder: &mut SemanticBuilder,
+        token: &LuaSyntaxToken,
+        value: &LuaType,
+        target: &(LuaTypeDeclId, LuaMemberKey),
+    ) -> Option<()> {
+        let (table, key) = target;
+        let modifier = if self
+            .semantic_model
+            .get_db()
+            .get_config_index()
+            .get_pk_value_index()
+            .contains(table, key, value)
+        {
+            CustomSemanticTokenModifier::REF
+        } else {
+            CustomSemanticTokenModifier::INVALID_REF
+        };
+        push_token(builder, token, modifier)
+    }
+
+    /// map 字面量 key 的 token 及其常量类型
+    fn get_key_literal(&self, key: &LuaIndexKey) -> Option<(LuaSyntaxToken, LuaType)> {
+        match key {
+            LuaIndexKey::String(token) => Some((
+                token.syntax().clone(),
+                LuaType::StringConst(ArcIntern::new(token.get_value().into())),
+            )),
+            LuaIndexKey::Integer(token) => match token.get_number_value() {
+                NumberResult::Int(value) => {
+                    Some((token.syntax().clone(), LuaType::IntegerConst(value)))
+                }
+                _ => None,
+            },
+            LuaIndexKey::Expr(expr) => Some((
+                get_literal_token(expr)?,
+                self.semantic_model.infer_expr(expr.clone()).ok()?,
+            )),
+            _ => None,
+        }
+    }
+
+    fn push_literal(
+        &self,
+        builder: &mut SemanticBuilder,
+        value: &LuaExpr,
+        modifier: SemanticTokenModifier,
+    ) -> Option<()> {
+        let token = get_literal_token(value)?;
+        push_token(builder, &token, modifier)
+    }
+
+    /// 类型上 `v.ref` 属性指向的目标
+    fn get_vref_target(&self, typ: &LuaType) -> Option<(LuaTypeDeclId, LuaMemberKey)> {
+        let LuaType::Attributed(attributed) = typ else {
+            return None;
+        };
+        VRefAttribute::find_in_uses(attributed.get_attributes())?
+            .resolve_target(self.semantic_model.get_db(), self.file_id)
+    }
+
+    fn is_enum_type(&self, typ: &LuaType) -> bool {
+        match typ.strip_attributed() {
+            LuaType::Ref(id) => self
+                .semantic_model
+                .get_db()
+                .get_type_index()
+                .get_type_decl(id)
+                .is_some_and(|decl| decl.is_enum()),
+            LuaType::Union(union) => union
+                .into_vec()
+                .iter()
+                .any(|inner| self.is_enum_type(inner)),
+            _ => false,
+        }
+    }
+
+    fn get_bean_id(&self, typ: &LuaType) -> Option<LuaTypeDeclId> {
+        match typ.strip_attributed() {
+            LuaType::Ref(id) if BEAN.is_bean(self.semantic_model.get_db(), id) => Some(id.clone()),
+            LuaType::Union(union) => {
+                let mut bean_ids = union
+                    .into_vec()
+                    .into_iter()
+                    .filter_map(|inner| self.get_bean_id(&inner));
+                let first = bean_ids.next()?;
+                bean_ids.next().is_none().then_some(first)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn get_literal_token(expr: &LuaExpr) -> Option<LuaSyntaxToken> {
+    let LuaExpr::LiteralExpr(literal) = expr else {
+        return None;
+    };
+    Some(literal.get_literal()?.syntax().clone())
+}
+
+fn push_token(
+    builder: &mut SemanticBuilder,
+    token: &LuaSyntaxToken,
+    modifier: SemanticTokenModifier,
+) -> Option<()> {
+    let ty = match token.kind().into() {
+        LuaTokenKind::TkString | LuaTokenKind::TkLongString => SemanticTokenType::STRING,
+        LuaTokenKind::TkInt | LuaTokenKind::TkFloat => SemanticTokenType::NUMBER,
+        _ => return None,
+    };
+    builder.push_with_modifiers(token, ty, &[modifier])
+}
@@ -1,4 +1,5 @@
 mod build_semantic_tokens;
+mod config_data_highlight;
 mod function_string_highlight;
 mod language_injector;
 mod semantic_token_builder;
@@ -13,7 +14,8 @@ use lsp_types::{
 };
 #[allow(unused)]
 pub use semantic_token_builder::{
-    CustomSemanticTokenType, SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES,
+    CustomSemanticTokenModifier, CustomSemanticTokenType, SEMANTIC_TOKEN_MODIFIERS,
+    SEMANTIC_TOKEN_TYPES,
 };
 use tokio_util::sync::CancellationToken;
 
@@ -13,6 +13,16 @@ impl CustomSemanticTokenType {
     pub const DELIMITER: SemanticTokenType = SemanticTokenType::new("delimiter");
 }
 
+pub struct CustomSemanticTokenModifier;
+impl CustomSemanticTokenModifier {
+    // luaconfig 数据着色
+    pub const PRIMARY_KEY: SemanticTokenModifier = SemanticTokenModifier::new("primaryKey");
+    pub const REF: SemanticTokenModifier = SemanticTokenModifier::new("configRef");
+    pub const INVALID_REF: SemanticTokenModifier = SemanticTokenModifier::new("invalidConfigRef");
+    pub const ENUM: SemanticTokenModifier = SemanticTokenModifier::new("configEnum");
+    pub const TAG: SemanticTokenModifier = SemanticTokenModifier::new("configTag");
+}
+
 pub const SEMANTIC_TOKEN_TYPES: &[SemanticTokenType] = &[
     SemanticTokenType::NAMESPACE,
     SemanticTokenType::TYPE,
@@ -52,6 +62,12 @@ pub const SEMANTIC_TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
     SemanticTokenModifier::MODIFICATION,
     SemanticTokenModifier::DOCUMENTATION,
     SemanticTokenModifier::DEFAULT_LIBRARY,
+    // Custom modifiers
+    CustomSemanticTokenModifier::PRIMARY_KEY,
+    CustomSemanticTokenModifier::REF,
+    CustomSemanticTokenModifier::INVALID_REF,
+    CustomSemanticTokenModifier::ENUM,
+    CustomSemanticTokenModifier::TAG,
 ];
 
 #[derive(Debug)]
@@ -224,7 +240,6 @@ impl<'a> SemanticBuilder<'a> {
         Some(())
     }
 
-    #[allow(unused)]
     pub fn push_with_modifiers(
         &mut self,
         token: &LuaSyntaxToken,
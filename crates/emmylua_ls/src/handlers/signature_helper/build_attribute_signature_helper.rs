@@ -0,0 +1,126 @@
+use emmylua_code_analysis::{
+    DocTypeInferContext, LuaSemanticDeclId, LuaType, SemanticModel, infer_doc_type,
+};
+use emmylua_parser::{
+    LuaAstNode, LuaDocAttributeCallArgList, LuaDocAttributeUse, LuaDocType, LuaSyntaxToken,
+    LuaTokenKind,
+};
+use lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, SignatureHelp,
+    SignatureInformation,
+};
+use rowan::NodeOrToken;
+
+use super::build_signature_helper::generate_param_label;
+
+/// 为 `---@[attribute(...)]` 的参数列表构建签名帮助
+pub fn build_attribute_signature_helper(
+    semantic_model: &SemanticModel,
+    arg_list: LuaDocAttributeCallArgList,
+    token: LuaSyntaxToken,
+) -> Option<SignatureHelp> {
+    let db = semantic_model.get_db();
+    let attribute_use = arg_list.get_parent::<LuaDocAttributeUse>()?;
+    let doc_ctx = DocTypeInferContext::new(db, semantic_model.get_file_id());
+    let LuaType::Ref(type_id) =
+        infer_doc_type(doc_ctx, &LuaDocType::Name(attribute_use.get_type()?))
+    else {
+        return None;
+    };
+    let type_decl = db.get_type_index().get_type_decl(&type_id)?;
+    if !type_decl.is_attribute() {
+        return None;
+    }
+    let LuaType::DocAttribute(attribute) = type_decl.get_attribute_type()? else {
+        return None;
+    };
+
+    let description = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(type_id.clone()))
+        .and_then(|property| property.description())
+        .cloned();
+
+    let params = attribute.get_params();
+    let param_infos = params
+        .iter()
+        .map(|param| ParameterInformation {
+            label: ParameterLabel::Simple(generate_param_label(db, param.clone())),
+            documentation: description
+                .as_deref()
+                .and_then(|description| find_param_description(description, &param.0))
+                .map(|value| {
+                    Documentation::MarkupContent(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value,
+                    })
+                }),
+        })
+        .collect::<Vec<_>>();
+
+    let label = format!(
+        "{}({})",
+        type_id.get_name(),
+        param_infos
+            .iter()
+            .map(|info| match &info.label {
+                ParameterLabel::Simple(label) => label.as_str(),
+                _ => "",
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let mut current_idx = get_attribute_param_index(&arg_list, &token);
+    if let Some((name, _)) = params.last()
+        && name == "..."
+        && current_idx >= params.len()
+    {
+        current_idx = params.len() - 1;
+    }
+
+    let signature_info = SignatureInformation {
+        label,
+        documentation: description.map(|value| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            })
+        }),
+        parameters: Some(param_infos),
+        active_parameter: Some(current_idx as u32),
+    };
+
+    Some(SignatureHelp {
+        signatures: vec![signature_info],
+        active_signature: Some(0),
+        active_parameter: Some(current_idx as u32),
+    })
+}
+
+fn get_attribute_param_index(
+    arg_list: &LuaDocAttributeCallArgList,
+    token: &LuaSyntaxToken,
+) -> usize {
+    let token_position = token.text_range().start();
+    arg_list
+        .syntax()
+        .children_with_tokens()
+        .filter(|node_or_token| {
+            matches!(node_or_token, NodeOrToken::Token(token)
+                if token.kind() == LuaTokenKind::TkComma.into()
+                    && token.text_range().start() <= token_position)
+        })
+        .count()
+}
+
+/// 从特性的文档注释中提取参数说明, 格式为 `` - `name`: description ``
+fn find_param_description(description: &str, param_name: &str) -> Option<String> {
+    let prefix = format!("`{}`", param_name);
+    description.lines().find_map(|line| {
+        let rest = line.trim_start().strip_prefix('-')?.trim_start();
+        let rest = rest.strip_prefix(&prefix)?.trim_start();
+        let rest = rest.strip_prefix(':').unwrap_or(rest).trim();
+        (!rest.is_empty()).then(|| rest.to_string())
+    })
+}
@@ -1,11 +1,15 @@
+mod build_attribute_signature_helper;
 mod build_signature_helper;
 mod signature_helper_builder;
 
 use crate::context::ServerContextSnapshot;
+use build_attribute_signature_helper::build_attribute_signature_helper;
 use build_signature_helper::build_signature_helper;
 pub use build_signature_helper::get_current_param_index;
 use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
-use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaSyntaxKind, LuaTokenKind};
+use emmylua_parser::{
+    LuaAstNode, LuaCallExpr, LuaDocAttributeCallArgList, LuaSyntaxKind, LuaTokenKind,
+};
 use lsp_types::Position;
 use lsp_types::{
     ClientCapabilities, ServerCapabilities, SignatureHelp, SignatureHelpContext,
@@ -67,13 +71,21 @@ pub fn signature_help(
             }
             // todo
             LuaSyntaxKind::TypeGeneric | LuaSyntaxKind::DocTypeList => None,
-            _ => None,
+            _ => {
+                let arg_list = token
+                    .parent_ancestors()
+                    .find_map(LuaDocAttributeCallArgList::cast)?;
+                build_attribute_signature_helper(&semantic_model, arg_list, token)
+            }
         }
     } else if matches!(
         token.kind().into(),
         LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine
     ) {
-        if token.parent()?.kind() == LuaSyntaxKind::CallArgList.into() {
+        if matches!(
+            token.parent()?.kind().into(),
+            LuaSyntaxKind::CallArgList | LuaSyntaxKind::DocAttributeCallArgList
+        ) {
             param_context.active_signature_help
         } else {
             None
@@ -83,6 +95,7 @@ pub fn signature_help(
             matches!(
                 node.kind().into(),
                 LuaSyntaxKind::CallArgList
+                    | LuaSyntaxKind::DocAttributeCallArgList
                     | LuaSyntaxKind::TypeGeneric
                     | LuaSyntaxKind::DocTypeList
             )
@@ -92,6 +105,10 @@ pub fn signature_help(
                 let call_expr = LuaCallExpr::cast(node.parent()?)?;
                 build_signature_helper(&semantic_model, &analysis.compilation, call_expr, token)
             }
+            LuaSyntaxKind::DocAttributeCallArgList => {
+                let arg_list = LuaDocAttributeCallArgList::cast(node)?;
+                build_attribute_signature_helper(&semantic_model, arg_list, token)
+            }
             // todo
             LuaSyntaxKind::TypeGeneric | LuaSyntaxKind::DocTypeList => None,
             _ => None,
@@ -2402,4 +2402,272 @@ mod tests {
 
         Ok(())
     }
+
+    fn def_luaconfig_tables(ws: &mut ProviderVirtualWorkspace) {
+        ws.def(
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+            ---@field name string
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+
+            ---@[t.index(["chapterId", "stageId"])]
+            ---@class TbStage: ConfigTable
+            ---@field [int] Stage
+
+            ---@class Stage: Bean
+            ---@field chapterId int
+            ---@field stageId int
+            "#,
+        );
+    }
+
+    #[gtest]
+    fn test_attribute_arg_config_table_name() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        def_luaconfig_tables(&mut ws);
+        check!(ws.check_completion(
+            r#"
+            ---@class User: Bean
+            ---@[v.ref("<??>")]
+            ---@field itemId int
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "TbItem".to_string(),
+                    kind: CompletionItemKind::CLASS,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "TbStage".to_string(),
+                    kind: CompletionItemKind::CLASS,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_attribute_arg_ref_key() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        def_luaconfig_tables(&mut ws);
+        check!(ws.check_completion(
+            r#"
+            ---@class User: Bean
+            ---@[v.ref("TbStage", "<??>")]
+            ---@field stageId int
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "chapterId".to_string(),
+                    kind: CompletionItemKind::FIELD,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "stageId".to_string(),
+                    kind: CompletionItemKind::FIELD,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_attribute_arg_bean_fields() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        def_luaconfig_tables(&mut ws);
+        let expected = || {
+            vec![
+                VirtualCompletionItem {
+                    label: "id".to_string(),
+                    kind: CompletionItemKind::FIELD,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "name".to_string(),
+                    kind: CompletionItemKind::FIELD,
+                    ..Default::default()
+                },
+            ]
+        };
+        check!(ws.check_completion(
+            r#"
+            ---@[t.index("<??>")]
+            ---@class TbItem2: ConfigTable
+            ---@field [int] Item
+            "#,
+            expected(),
+        ));
+        check!(ws.check_completion(
+            r#"
+            ---@type list<[v.index("<??>")] Item>
+            local items
+            "#,
+            expected(),
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_attribute_arg_array_bean_fields() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        def_luaconfig_tables(&mut ws);
+        let expected = || {
+            vec![
+                VirtualCompletionItem {
+                    label: "chapterId".to_string(),
+                    kind: CompletionItemKind::FIELD,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "stageId".to_string(),
+                    kind: CompletionItemKind::FIELD,
+                    ..Default::default()
+                },
+            ]
+        };
+        check!(ws.check_completion(
+            r#"
+            ---@[t.index(["chapterId", "<??>"])]
+            ---@class TbStage2: ConfigTable
+            ---@field [int] Stage
+            "#,
+            expected(),
+        ));
+        check!(ws.check_completion(
+            r#"
+            ---@[v.union_ref("TbStage", ["<??>"])]
+            ---@class Reward: Bean
+            ---@field chapterId int
+            ---@field stageId int
+            "#,
+            expected(),
+        ));
+        check!(ws.check_completion(
+            r#"
+            ---@[v.union_ref("TbStage", ["chapter", "stage"], ["chapterId", "<??>"])]
+            ---@class Reward2: Bean
+            ---@field chapter int
+            ---@field stage int
+            "#,
+            expected(),
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_attribute_arg_literal_values() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        check!(ws.check_completion(
+            r#"
+            ---@[t.mode("<??>")]
+            ---@class TbConst: ConfigTable
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "list".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "map".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "singleton".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_bean_enum_name() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@enum Quality
+            Quality = {
+                White = 1,
+                ---@[alias("红")]
+                Red = 3,
+            }
+
+            ---@[flags]
+            ---@enum Element
+            Element = {
+                Fire = 1,
+                Ice = 2,
+            }
+
+            ---@class Item: Bean
+            ---@field id int
+            ---@field quality Quality
+            ---@field element Element
+            ---@field tags list<Quality>
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        let quality = || {
+            vec![
+                VirtualCompletionItem {
+                    label: "Red".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "White".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "红".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+            ]
+        };
+        check!(ws.check_completion(
+            r#"
+            ---@type TbItem
+            local t = { [1] = { id = 1, quality = "<??>" } }
+            "#,
+            quality(),
+        ));
+        check!(ws.check_completion(
+            r#"
+            ---@type TbItem
+            local t = { [1] = { id = 1, tags = { "Red", "<??>" } } }
+            "#,
+            quality(),
+        ));
+        check!(ws.check_completion(
+            r#"
+            ---@type TbItem
+            local t = { [1] = { id = 1, element = "Fire|<??>" } }
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "Fire".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "Ice".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
 }
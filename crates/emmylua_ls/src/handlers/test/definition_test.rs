@@ -580,4 +580,49 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_config_union_ref_definition() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Stage: Bean
+            ---@field chapterId int
+            ---@field stageId int
+            ---@field name string
+
+            ---@[t.index(["chapterId", "stageId"])]
+            ---@class TbStage: ConfigTable
+            ---@field [int] Stage
+
+            ---@type TbStage
+            local stages = {
+                { chapterId = 1, stageId = 1, name = "Forest" },
+                { chapterId = 1, stageId = 2, name = "Cave" },
+            }
+
+            ---@[v.union_ref("TbStage", ["chapter", "stage"])]
+            ---@class Drop: Bean
+            ---@field id int
+            ---@field chapter int
+            ---@field stage int
+
+            ---@class TbDrop: ConfigTable
+            ---@field [int] Drop
+            "#,
+        );
+        check!(ws.check_definition(
+            r#"
+                ---@type TbDrop
+                local drops = {
+                    { id = 1, chapter = 1, stage = <??>2 },
+                }
+            "#,
+            vec![Expected {
+                file: "".to_string(),
+                line: 13,
+            }],
+        ));
+        Ok(())
+    }
 }
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn test_config_table_rows() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Reward: Bean
+            ---@field itemId int
+            ---@field count int
+
+            ---@class Cost: Bean
+            ---@field gold int
+
+            ---@class Item: Bean
+            ---@field id int
+            ---@field name string
+            ---@field cost Cost
+            ---@field rewards list<Reward>
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        check!(ws.check_document_symbol(
+            r#"
+            ---@type TbItem
+            local items = {
+                {
+                    id = 1001,
+                    name = "Sword",
+                    cost = { gold = 10 },
+                    rewards = { { itemId = 1, count = 2 } },
+                },
+                { id = 1002, name = "Shield" },
+            }
+            "#,
+            vec![
+                "items",
+                "  1001 Sword",
+                "    cost",
+                "    rewards",
+                "      [1]",
+                "  1002 Shield",
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_returned_config_table_rows() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Stage: Bean
+            ---@field chapterId int
+            ---@field stageId int
+
+            ---@[t.index(["chapterId", "stageId"])]
+            ---@class TbStage: ConfigTable
+            ---@field [int] Stage
+            "#,
+        );
+        check!(ws.check_document_symbol(
+            r#"
+            ---@type TbStage
+            local stages = {
+                { chapterId = 1, stageId = 1 },
+                { chapterId = 1, stageId = 2 },
+            }
+            return stages
+            "#,
+            vec!["stages", "  1, 1", "  1, 2"],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_config_table_row_without_key() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+            ---@field name string
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        // 无法得到键的记录不影响后续记录
+        check!(ws.check_document_symbol(
+            r#"
+            ---@type TbItem
+            local items = {
+                [] = { name = "Broken" },
+                { id = 1002, name = "Shield" },
+            }
+            "#,
+            vec!["items", "  1002 Shield"],
+        ));
+        Ok(())
+    }
+}
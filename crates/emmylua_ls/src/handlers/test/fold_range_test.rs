@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn test_config_row_collapsed_text() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+            ---@field name string
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        check!(ws.check_folding_range(
+            r#"
+            ---@type TbItem
+            local items = {
+                {
+                    id = 1001,
+                    name = "Sword",
+                },
+                {
+                    id = 1002,
+                    name = "Shield",
+                },
+            }
+            "#,
+            vec![
+                (2, 10, " .. "),
+                (3, 5, " 1001 Sword "),
+                (7, 9, " 1002 Shield "),
+            ],
+        ));
+        Ok(())
+    }
+}
@@ -441,4 +441,161 @@ mod tests {
 
         Ok(())
     }
+
+    #[gtest]
+    fn test_config_union_ref_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Stage: Bean
+            ---@field chapterId int
+            ---@field stageId int
+            ---@field name string
+
+            ---@[t.index(["chapterId", "stageId"])]
+            ---@class TbStage: ConfigTable
+            ---@field [int] Stage
+
+            ---@type TbStage
+            local stages = {
+                { chapterId = 1, stageId = 1, name = "Forest" },
+                { chapterId = 1, stageId = 2, name = "Cave" },
+            }
+
+            ---@[v.union_ref("TbStage", ["chapter", "stage"])]
+            ---@class Drop: Bean
+            ---@field id int
+            ---@field chapter int
+            ---@field stage int
+
+            ---@class TbDrop: ConfigTable
+            ---@field [int] Drop
+            "#,
+        );
+        check!(ws.check_hover(
+            r#"
+            ---@type TbDrop
+            local drops = {
+                { id = 1, chapter = <??>1, stage = 2 },
+            }
+            "#,
+            VirtualHoverResult {
+                value: "```lua\n(ref) TbStage: 1, 2 Cave\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_bean_field_default_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        check!(ws.check_hover(
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+            ---@[v.default(1)]
+            ---@field stack int
+
+            ---@type Item
+            local item = { id = 1 }
+            print(item.<??>stack)
+            "#,
+            VirtualHoverResult {
+                value: "```lua\n(field) stack: integer = 1\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_bean_enum_name_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@enum Quality
+            Quality = {
+                White = 1,
+                Red = 3,
+            }
+
+            ---@[flags]
+            ---@enum Element
+            Element = {
+                Fire = 1,
+                Ice = 2,
+            }
+
+            ---@class Item: Bean
+            ---@field quality Quality
+            ---@field element Element
+            "#,
+        );
+        check!(ws.check_hover(
+            r#"
+            ---@type Item
+            local item = { quality = "R<??>ed", element = "Fire|Ice" }
+            "#,
+            VirtualHoverResult {
+                value: "```lua\n(enum) Quality: Red = 3\n```".to_string(),
+            },
+        ));
+        check!(ws.check_hover(
+            r#"
+            ---@type Item
+            local item = { quality = "Red", element = "Fire|<??>Ice" }
+            "#,
+            VirtualHoverResult {
+                value: "```lua\n(enum) Element: Fire|Ice = 3\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_vector_component_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        check!(ws.check_hover(
+            r#"
+            ---@type vector3
+            local pos = { 1, <??>2, 3 }
+            "#,
+            VirtualHoverResult {
+                value: "```lua\n(field) y: float\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_weight_value_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class DropItem: Bean
+            ---@field itemId int
+            ---@[v.weight_sum(100)]
+            ---@field weight int
+
+            ---@class Drop: Bean
+            ---@field id int
+            ---@field items list<DropItem>
+
+            ---@class TbDrop: ConfigTable
+            ---@field [int] Drop
+            "#,
+        );
+        check!(ws.check_hover(
+            r#"
+            ---@type TbDrop
+            local drops = {
+                { id = 1, items = { { itemId = 1, weight = <??>30 }, { itemId = 2, weight = 90 } } },
+            }
+            "#,
+            VirtualHoverResult {
+                value: "```lua\n(weight) weight = 30\n```\n\n25% of 120 (expected 100)"
+                    .to_string(),
+            },
+        ));
+        Ok(())
+    }
 }
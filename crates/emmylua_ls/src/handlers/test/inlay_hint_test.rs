@@ -386,4 +386,141 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_bean_default_value_hint() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        check!(ws.check_inlay_hint(
+            r#"
+                ---@class Item: Bean
+                ---@field id int
+                ---@[v.default(1)]
+                ---@field stack int
+                ---@[v.default("none")]
+                ---@field icon string
+
+                ---@type Item
+                local item = { id = 1, icon = "sword" }
+                ---@type Item
+                local other = { id = 2 }
+            "#,
+            vec![
+                VirtualInlayHint {
+                    label: ": Item".to_string(),
+                    line: 9,
+                    pos: 26,
+                    ref_file: Some("".to_string()),
+                },
+                VirtualInlayHint {
+                    label: "stack = 1".to_string(),
+                    line: 9,
+                    pos: 54,
+                    ref_file: Some("".to_string()),
+                },
+                VirtualInlayHint {
+                    label: ": Item".to_string(),
+                    line: 11,
+                    pos: 27,
+                    ref_file: Some("".to_string()),
+                },
+                VirtualInlayHint {
+                    label: "stack = 1, icon = \"none\"".to_string(),
+                    line: 11,
+                    pos: 39,
+                    ref_file: Some("".to_string()),
+                },
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_vector_component_hint() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        check!(ws.check_inlay_hint(
+            r#"
+                ---@type vector2
+                local pos = { 1, 2 }
+                ---@type color
+                local tint = { 1, 0.5, 0 }
+            "#,
+            vec![
+                VirtualInlayHint {
+                    label: ": vector2".to_string(),
+                    line: 2,
+                    pos: 25,
+                    ref_file: Some("luaconfig.lua".to_string()),
+                },
+                VirtualInlayHint {
+                    label: "x:".to_string(),
+                    line: 2,
+                    pos: 30,
+                    ref_file: None,
+                },
+                VirtualInlayHint {
+                    label: "y:".to_string(),
+                    line: 2,
+                    pos: 33,
+                    ref_file: None,
+                },
+                VirtualInlayHint {
+                    label: ": color".to_string(),
+                    line: 4,
+                    pos: 26,
+                    ref_file: Some("luaconfig.lua".to_string()),
+                },
+                VirtualInlayHint {
+                    label: "r:".to_string(),
+                    line: 4,
+                    pos: 31,
+                    ref_file: None,
+                },
+                VirtualInlayHint {
+                    label: "g:".to_string(),
+                    line: 4,
+                    pos: 34,
+                    ref_file: None,
+                },
+                VirtualInlayHint {
+                    label: "b:".to_string(),
+                    line: 4,
+                    pos: 39,
+                    ref_file: None,
+                },
+                VirtualInlayHint {
+                    label: "a = 1".to_string(),
+                    line: 4,
+                    pos: 41,
+                    ref_file: Some("luaconfig.lua".to_string()),
+                },
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_project_class_named_like_vector() -> Result<()> {
+        // 只有标准库 luaconfig.lua 中的向量类型显示分量名
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class color
+            ---@field r number
+            ---@field g number
+            "#,
+        );
+        check!(ws.check_inlay_hint(
+            r#"
+                ---@type color
+                local tint = { 1, 0.5 }
+            "#,
+            vec![VirtualInlayHint {
+                label: ": color".to_string(),
+                line: 2,
+                pos: 26,
+                ref_file: Some("virtual_0.lua".to_string()),
+            }]
+        ));
+        Ok(())
+    }
 }
@@ -2,6 +2,8 @@ mod code_actions_test;
 mod completion_resolve_test;
 mod completion_test;
 mod definition_test;
+mod document_symbol_test;
+mod fold_range_test;
 mod hover_function_test;
 mod hover_test;
 mod implementation_test;
@@ -10,3 +12,4 @@ mod references_test;
 mod rename_test;
 mod semantic_token_test;
 mod signature_helper_test;
+mod workspace_symbol_test;
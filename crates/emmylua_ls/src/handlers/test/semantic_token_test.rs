@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use crate::handlers::test_lib::ProviderVirtualWorkspace;
+    use crate::handlers::{
+        semantic_token::CustomSemanticTokenModifier, test_lib::ProviderVirtualWorkspace,
+    };
     use googletest::prelude::*;
+    use lsp_types::SemanticTokenModifier;
 
     #[gtest]
     fn test_1() -> Result<()> {
@@ -15,4 +18,68 @@ mod tests {
         );
         Ok(())
     }
+
+    #[gtest]
+    fn test_config_data_modifiers() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@enum Quality
+            Quality = { Normal = 1, Rare = 2 }
+
+            ---@class Item: Bean
+            ---@field id int
+            ---@field name string
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+
+            ---@class Reward: Bean
+            ---@field id int
+            ---@[v.ref("TbItem")]
+            ---@field itemId int
+            ---@field quality Quality
+
+            ---@class TbReward: ConfigTable
+            ---@field [int] Reward
+
+            ---@type TbItem
+            local items = {
+                { id = 1001, name = "Sword" },
+            }
+            "#,
+        );
+
+        let tokens = ws.get_semantic_tokens(
+            r#"
+            ---@type TbReward
+            local rewards = {
+                { id = 1, itemId = 1001, quality = 2, __tag__ = "test" },
+                { id = 2, itemId = 9999, quality = 1 },
+            }
+            "#,
+        )?;
+        let find = |modifier: &SemanticTokenModifier| {
+            tokens
+                .iter()
+                .filter(|token| token.token_modifier.contains(modifier))
+                .map(|token| (token.line, token.start, token.length))
+                .collect::<Vec<_>>()
+        };
+
+        verify_eq!(
+            find(&CustomSemanticTokenModifier::PRIMARY_KEY),
+            vec![(3, 18, 2), (4, 18, 2)]
+        )?;
+        verify_eq!(find(&CustomSemanticTokenModifier::REF), vec![(3, 35, 4)])?;
+        verify_eq!(
+            find(&CustomSemanticTokenModifier::INVALID_REF),
+            vec![(4, 35, 4)]
+        )?;
+        verify_eq!(
+            find(&CustomSemanticTokenModifier::ENUM),
+            vec![(3, 51, 1), (4, 51, 1)]
+        )?;
+        verify_eq!(find(&CustomSemanticTokenModifier::TAG), vec![(3, 64, 6)])
+    }
 }
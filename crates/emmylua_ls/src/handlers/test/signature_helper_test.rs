@@ -43,4 +43,51 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_attribute_use() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        check!(ws.check_signature_helper(
+            r#"
+                ---@[v.ref(<??>)]
+                local a
+            "#,
+            VirtualSignatureHelp {
+                target_label: "v.ref(tableName: string, key: string?)".to_string(),
+                active_signature: 0,
+                active_parameter: 0,
+            },
+        ));
+        check!(ws.check_signature_helper(
+            r#"
+                ---@[t.mode(<??>
+                ---@class TbItem: ConfigTable
+            "#,
+            VirtualSignatureHelp {
+                target_label: r#"t.mode(mode: ("map"|"list"|"singleton"))"#.to_string(),
+                active_signature: 0,
+                active_parameter: 0,
+            },
+        ));
+        check!(ws.check_signature_helper(
+            r#"
+                ---@[t.index("id", <??>)]
+                ---@class TbItem: ConfigTable
+            "#,
+            VirtualSignatureHelp {
+                target_label:
+                    r#"t.index(indexs: (string|string[]), mode: ("union"|"solo")?)"#.to_string(),
+                active_signature: 0,
+                active_parameter: 1,
+            },
+        ));
+        check!(ws.check_signature_helper_param_doc(
+            r#"
+                ---@[v.ref("TbItem", <??>)]
+                local a
+            "#,
+            "指定主键名称. 如果不提供, 则自动使用配置表的主键.",
+        ));
+        Ok(())
+    }
 }
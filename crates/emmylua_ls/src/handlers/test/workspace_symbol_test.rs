@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+
+    fn def_item_table(ws: &mut ProviderVirtualWorkspace) {
+        ws.def(
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+            ---@field name string
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+
+            ---@type TbItem
+            local items = {
+                { id = 1001, name = "Sword" },
+                { id = 1002, name = "Shield" },
+            }
+            "#,
+        );
+    }
+
+    #[gtest]
+    fn test_config_row_by_table_prefix() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        def_item_table(&mut ws);
+        check!(ws.check_workspace_symbol("#TbItem 1001", vec!["1001 Sword"]));
+        check!(ws.check_workspace_symbol("#TbItem 100", vec!["1001 Sword", "1002 Shield"]));
+        check!(ws.check_workspace_symbol("#TbUser", vec![]));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_config_row_by_display_field() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        def_item_table(&mut ws);
+        check!(ws.check_workspace_symbol("item: Sword", vec!["1001 Sword"]));
+        check!(ws.check_workspace_symbol("item: shi", vec!["1002 Shield"]));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_config_row_union_keys() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Stage: Bean
+            ---@field chapterId int
+            ---@field stageId int
+            ---@field title string
+
+            ---@[t.index(["chapterId", "stageId"])]
+            ---@class TbStage: ConfigTable
+            ---@field [int] Stage
+
+            ---@type TbStage
+            local stages = {
+                { chapterId = 1, stageId = 1, title = "Forest" },
+                { chapterId = 1, stageId = 2, title = "Cave" },
+            }
+            "#,
+        );
+        check!(ws.check_workspace_symbol("#TbStage 1, 2", vec!["1, 2 Cave"]));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_config_row_query_requires_table_and_row() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        def_item_table(&mut ws);
+        check!(ws.check_workspace_symbol("#", vec![]));
+        check!(ws.check_workspace_symbol("#TbItem", vec![]));
+        check!(ws.check_workspace_symbol("x:", vec![]));
+        check!(ws.check_workspace_symbol(":Sword", vec![]));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_plain_query_skips_rows() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        def_item_table(&mut ws);
+        check!(ws.check_workspace_symbol("TbItem", vec!["TbItem"]));
+        Ok(())
+    }
+}
@@ -3,10 +3,10 @@ use googletest::prelude::*;
 use itertools::Itertools;
 use lsp_types::{
     CodeActionOrCommand, CompletionItem, CompletionItemKind, CompletionResponse,
-    CompletionTriggerKind, Documentation, GotoDefinitionResponse, Hover, HoverContents,
-    InlayHintLabel, Location, MarkupContent, Position, SemanticTokenModifier, SemanticTokenType,
-    SemanticTokensResult, SignatureHelpContext, SignatureHelpTriggerKind, SignatureInformation,
-    TextEdit,
+    CompletionTriggerKind, DocumentSymbol, DocumentSymbolResponse, Documentation,
+    GotoDefinitionResponse, Hover, HoverContents, InlayHintLabel, Location, MarkupContent,
+    Position, SemanticTokenModifier, SemanticTokenType, SemanticTokensResult, SignatureHelpContext,
+    SignatureHelpTriggerKind, SignatureInformation, TextEdit, WorkspaceSymbolResponse,
 };
 use std::collections::HashSet;
 use std::{ops::Deref, sync::Arc};
@@ -17,10 +17,13 @@ use crate::{
     handlers::{
         code_actions::code_action,
         completion::{completion, completion_resolve},
+        document_symbol::document_symbol,
+        fold_range::folding_range,
         inlay_hint::inlay_hint,
         rename::rename,
         semantic_token::semantic_token,
         signature_helper::signature_help,
+        workspace_symbol::build_workspace_symbols,
     },
 };
 
@@ -415,6 +418,37 @@ impl ProviderVirtualWorkspace {
         )
     }
 
+    /// 检查当前激活参数的文档说明
+    pub fn check_signature_helper_param_doc(
+        &mut self,
+        block_str: &str,
+        expected_doc: &str,
+    ) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let param_context = SignatureHelpContext {
+            trigger_kind: SignatureHelpTriggerKind::INVOKED,
+            trigger_character: None,
+            is_retrigger: false,
+            active_signature_help: None,
+        };
+        let result = signature_help(&self.analysis, file_id, position, param_context)
+            .ok_or("failed to get signature help")
+            .or_fail()?;
+        let active_parameter = result.active_parameter.unwrap_or(0) as usize;
+        let doc = result
+            .signatures
+            .first()
+            .and_then(|signature| signature.parameters.as_ref())
+            .and_then(|params| params.get(active_parameter))
+            .and_then(|param| param.documentation.as_ref())
+            .map(|doc| match doc {
+                Documentation::String(s) => s.clone(),
+                Documentation::MarkupContent(MarkupContent { value, .. }) => value.clone(),
+            });
+        verify_eq!(doc.as_deref(), Some(expected_doc))
+    }
+
     pub fn check_inlay_hint(
         &mut self,
         block_str: &str,
@@ -516,12 +550,20 @@ impl ProviderVirtualWorkspace {
         block_str: &str,
         expected: Vec<VirtualSemanticToken>,
     ) -> Result<()> {
+        let virtual_result = self.get_semantic_tokens(block_str)?;
+        verify_eq!(virtual_result, expected)
+    }
+
+    pub fn get_semantic_tokens(&mut self, block_str: &str) -> Result<Vec<VirtualSemanticToken>> {
         let file_id = self.def(block_str);
         let result = semantic_token(&self.analysis, file_id, true, ClientId::VSCode)
             .ok_or("failed to get semantic tokens")
             .or_fail()?;
         let SemanticTokensResult::Tokens(result) = result else {
-            return fail!("expected SemanticTokensResult::Tokens, got {result:?}");
+            return Err(format!(
+                "expected SemanticTokensResult::Tokens, got {result:?}"
+            ))
+            .or_fail();
         };
 
         fn type_index_to_type(index: u32) -> Result<SemanticTokenType> {
@@ -568,7 +610,7 @@ impl ProviderVirtualWorkspace {
             });
         }
 
-        verify_eq!(virtual_result, expected)
+        Ok(virtual_result)
     }
 
     pub fn check_rename(
@@ -621,4 +663,72 @@ impl ProviderVirtualWorkspace {
             .or_fail()?;
         Self::assert_locations(result, expected)
     }
+
+    pub fn check_workspace_symbol(&mut self, query: &str, mut expected: Vec<&str>) -> Result<()> {
+        let result = build_workspace_symbols(
+            &self.analysis.compilation,
+            query.to_string(),
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get workspace symbols")
+        .or_fail()?;
+        let WorkspaceSymbolResponse::Nested(symbols) = result else {
+            return fail!("expected WorkspaceSymbolResponse::Nested, got {result:?}");
+        };
+        let mut names = symbols
+            .iter()
+            .map(|symbol| symbol.name.as_str())
+            .collect::<Vec<_>>();
+        names.sort();
+        expected.sort();
+        verify_eq!(names, expected)
+    }
+
+    /// 以缩进文本的形式比较文档符号树, 每层缩进两个空格
+    pub fn check_document_symbol(&mut self, block_str: &str, expected: Vec<&str>) -> Result<()> {
+        let file_id = self.def(block_str);
+        let result = document_symbol(&self.analysis, file_id)
+            .ok_or("failed to get document symbols")
+            .or_fail()?;
+        let DocumentSymbolResponse::Nested(symbols) = result else {
+            return fail!("expected DocumentSymbolResponse::Nested, got {result:?}");
+        };
+
+        fn flatten(symbols: &[DocumentSymbol], depth: usize, out: &mut Vec<String>) {
+            for symbol in symbols {
+                out.push(format!("{}{}", "  ".repeat(depth), symbol.name));
+                if let Some(children) = &symbol.children {
+                    flatten(children, depth + 1, out);
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+        flatten(&symbols, 0, &mut lines);
+        verify_eq!(lines, expected)
+    }
+
+    /// 比较折叠范围的 (起始行, 结束行, 折叠文本)
+    pub fn check_folding_range(
+        &mut self,
+        block_str: &str,
+        expected: Vec<(u32, u32, &str)>,
+    ) -> Result<()> {
+        let file_id = self.def(block_str);
+        let result = folding_range(&self.analysis, file_id, ClientId::VSCode)
+            .ok_or("failed to get folding ranges")
+            .or_fail()?;
+        let mut ranges = result
+            .iter()
+            .map(|range| {
+                (
+                    range.start_line,
+                    range.end_line,
+                    range.collapsed_text.as_deref().unwrap_or_default(),
+                )
+            })
+            .collect::<Vec<_>>();
+        ranges.sort();
+        verify_eq!(ranges, expected)
+    }
 }
@@ -33,13 +33,14 @@ pub async fn on_did_open_text_document(
     }
 
     // Update file and get diagnostic settings
-    let (file_id, supports_pull, interval) = {
+    let (file_id, dependents, supports_pull, interval) = {
         let mut analysis = context.analysis().write().await;
         let file_id = analysis.update_file_by_uri(&uri, Some(text));
+        let dependents = analysis.take_config_table_dependents();
         let emmyrc = analysis.get_emmyrc();
         let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
         let supports_pull = context.lsp_features().supports_pull_diagnostic();
-        (file_id, supports_pull, interval)
+        (file_id, dependents, supports_pull, interval)
     };
 
     // Schedule diagnostic task without holding any locks
@@ -50,6 +51,14 @@ pub async fn on_did_open_text_document(
                 .add_diagnostic_task(file_id, interval)
                 .await;
         }
+        context
+            .file_diagnostic()
+            .add_dependents_diagnostic_task(dependents, file_id.as_slice(), interval)
+            .await;
+    } else if context.lsp_features().supports_refresh_diagnostic() {
+        context
+            .file_diagnostic()
+            .refresh_dependents_diagnostics(&dependents, file_id.as_slice());
     }
 
     // Update open files list
@@ -117,12 +126,13 @@ pub async fn on_did_change_text_document(
     }
 
     // Update file and get settings
-    let (file_id, emmyrc, supports_pull) = {
+    let (file_id, dependents, emmyrc, supports_pull) = {
         let mut analysis = context.analysis().write().await;
         let file_id = analysis.update_file_by_uri(&uri, Some(text));
+        let dependents = analysis.take_config_table_dependents();
         let emmyrc = analysis.get_emmyrc();
         let supports_pull = context.lsp_features().supports_pull_diagnostic();
-        (file_id, emmyrc, supports_pull)
+        (file_id, dependents, emmyrc, supports_pull)
     };
 
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
@@ -141,6 +151,14 @@ pub async fn on_did_change_text_document(
                 .add_diagnostic_task(file_id, interval)
                 .await;
         }
+        context
+            .file_diagnostic()
+            .add_dependents_diagnostic_task(dependents, file_id.as_slice(), interval)
+            .await;
+    } else if context.lsp_features().supports_refresh_diagnostic() {
+        context
+            .file_diagnostic()
+            .refresh_dependents_diagnostics(&dependents, file_id.as_slice());
     }
 
     Some(())
@@ -164,12 +182,26 @@ pub async fn on_did_close_document(
     {
         let mut mut_analysis = context.analysis().write().await;
         mut_analysis.remove_file_by_uri(uri);
+        let dependents = mut_analysis.take_config_table_dependents();
+        let interval = mut_analysis
+            .get_emmyrc()
+            .diagnostics
+            .diagnostic_interval
+            .unwrap_or(500);
         drop(mut_analysis);
 
         if !lsp_features.supports_pull_diagnostic() {
             context
                 .file_diagnostic()
                 .clear_push_file_diagnostics(uri.clone());
+            context
+                .file_diagnostic()
+                .add_dependents_diagnostic_task(dependents, &[], interval)
+                .await;
+        } else if lsp_features.supports_refresh_diagnostic() {
+            context
+                .file_diagnostic()
+                .refresh_dependents_diagnostics(&dependents, &[]);
         }
 
         return Some(());
@@ -71,10 +71,21 @@ pub async fn on_did_change_watched_files(
     }
 
     let file_ids = analysis.update_files_by_uri(watched_lua_files);
+    let dependents = analysis.take_config_table_dependents();
     context
         .file_diagnostic()
-        .add_files_diagnostic_task(file_ids, interval)
+        .add_files_diagnostic_task(file_ids.clone(), interval)
         .await;
+    if !lsp_features.supports_pull_diagnostic() {
+        context
+            .file_diagnostic()
+            .add_dependents_diagnostic_task(dependents, &file_ids, interval)
+            .await;
+    } else if lsp_features.supports_refresh_diagnostic() {
+        context
+            .file_diagnostic()
+            .refresh_dependents_diagnostics(&dependents, &file_ids);
+    }
 
     Some(())
 }
@@ -1,7 +1,10 @@
 use emmylua_code_analysis::{DbIndex, LuaCompilation, LuaSemanticDeclId, LuaType};
+use emmylua_parser::LuaAstNode;
 use lsp_types::{OneOf, SymbolKind, SymbolTag, WorkspaceSymbol, WorkspaceSymbolResponse};
 use tokio_util::sync::CancellationToken;
 
+use crate::util::collect_config_rows;
+
 /// if query contains uppercase, do case-sensitive match; otherwise, ignore case
 fn match_symbol(text: &str, query: &str) -> bool {
     if query.chars().any(|c| c.is_uppercase()) {
@@ -17,6 +20,10 @@ pub fn build_workspace_symbols(
     cancel_token: CancellationToken,
 ) -> Option<WorkspaceSymbolResponse> {
     let mut symbols = Vec::new();
+    if let Some(row_query) = ConfigRowQuery::parse(&query) {
+        add_config_row_symbols(&mut symbols, compilation, &row_query, &cancel_token)?;
+    }
+
     add_global_variable_symbols(&mut symbols, compilation, &query, &cancel_token)?;
     add_type_symbols(&mut symbols, compilation, &query, &cancel_token)?;
     Some(WorkspaceSymbolResponse::Nested(symbols))
@@ -111,6 +118,89 @@ fn add_type_symbols(
     Some(())
 }
 
+/// 配置表记录查询, 支持 `#TbItem 1001` 与 `item: Sword` 两种写法, 配置表与记录部分都不能为空
+#[derive(Debug, PartialEq, Eq)]
+struct ConfigRowQuery<'a> {
+    table: &'a str,
+    row: &'a str,
+}
+
+impl<'a> ConfigRowQuery<'a> {
+    fn parse(query: &'a str) -> Option<Self> {
+        let query = query.trim();
+        let (table, row) = match query.strip_prefix('#') {
+            Some(rest) => rest.split_once(char::is_whitespace)?,
+            None => {
+                let (table, row) = query.split_once(':')?;
+                if !table.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    return None;
+                }
+                (table, row)
+            }
+        };
+
+        let row = row.trim();
+        if table.is_empty() || row.is_empty() {
+            return None;
+        }
+        Some(Self { table, row })
+    }
+}
+
+fn add_config_row_symbols(
+    symbols: &mut Vec<WorkspaceSymbol>,
+    compilation: &LuaCompilation,
+    query: &ConfigRowQuery,
+    cancel_token: &CancellationToken,
+) -> Option<()> {
+    let db = compilation.get_db();
+    for (file_id, occurrences) in db
+        .get_config_index()
+        .iter_file_config_table_pk_occurrences()
+    {
+        if cancel_token.is_cancelled() {
+            return None;
+        }
+
+        if !occurrences
+            .iter()
+            .any(|occ| match_symbol(occ.get_config_table().get_name(), query.table))
+        {
+            continue;
+        }
+
+        let Some(tree) = db.get_vfs().get_syntax_tree(file_id) else {
+            continue;
+        };
+        let Some(document) = db.get_vfs().get_document(file_id) else {
+            continue;
+        };
+
+        let rows = collect_config_rows(db, *file_id, &tree.get_chunk_node(), |config_table| {
+            match_symbol(config_table.get_name(), query.table)
+        });
+        for row in rows {
+            if !match_symbol(&row.name, query.row) {
+                continue;
+            }
+
+            let Some(location) = document.to_lsp_location(row.row.get_range()) else {
+                continue;
+            };
+            symbols.push(WorkspaceSymbol {
+                name: row.name,
+                kind: SymbolKind::STRUCT,
+                tags: None,
+                container_name: Some(row.config_table.get_name().to_string()),
+                location: OneOf::Left(location),
+                data: None,
+            });
+        }
+    }
+
+    Some(())
+}
+
 fn get_symbol_kind(typ: &LuaType) -> SymbolKind {
     if typ.is_function() {
         return SymbolKind::FUNCTION;
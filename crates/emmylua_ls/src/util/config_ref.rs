@@ -0,0 +1,125 @@
+use emmylua_code_analysis::{
+    ConfigTablePkOccurrence, FileId, LuaMemberKey, LuaSemanticDeclId, LuaType, LuaTypeDeclId,
+    SemanticModel, attributes::VUnionRefAttribute, shared::luaconfig::BEAN,
+};
+use emmylua_parser::{
+    LuaAstNode, LuaExpr, LuaLiteralExpr, LuaSyntaxToken, LuaTableExpr, LuaTableField,
+};
+use rowan::TextRange;
+
+use super::collect_config_rows;
+
+/// 配置数据中联合引用 (`v.union_ref`) 指向的目标记录
+#[derive(Debug, Clone)]
+pub struct ConfigRefTarget {
+    pub config_table: LuaTypeDeclId,
+    pub file_id: FileId,
+    /// 目标记录中第一个主键字段的范围
+    pub range: TextRange,
+}
+
+impl ConfigRefTarget {
+    /// 目标记录的名称, 如 `1, 2 Cave`
+    pub fn get_row_name(&self, semantic_model: &SemanticModel) -> Option<String> {
+        let db = semantic_model.get_db();
+        let tree = db.get_vfs().get_syntax_tree(&self.file_id)?;
+        collect_config_rows(db, self.file_id, &tree.get_chunk_node(), |config_table| {
+            config_table == &self.config_table
+        })
+        .into_iter()
+        .find(|row| row.pk_range == self.range)
+        .map(|row| row.name)
+    }
+}
+
+/// 查找配置数据中字面量所在字段参与的联合引用目标
+pub fn find_config_union_ref_target(
+    semantic_model: &SemanticModel,
+    token: &LuaSyntaxToken,
+) -> Option<ConfigRefTarget> {
+    let literal = token.parent().and_then(LuaLiteralExpr::cast)?;
+    let field = literal.get_parent::<LuaTableField>()?;
+    let row = field.get_parent::<LuaTableExpr>()?;
+    let field_key = LuaMemberKey::Name(field.get_field_key()?.get_path_part().into());
+
+    let db = semantic_model.get_db();
+    let bean_id = match semantic_model.infer_table_should_be(row.clone())? {
+        LuaType::Ref(id) | LuaType::Def(id) if BEAN.is_bean(db, &id) => id,
+        _ => return None,
+    };
+    let property = db
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(bean_id.clone()))?;
+    let bean_file_id = db
+        .get_type_index()
+        .get_type_decl(&bean_id)?
+        .get_locations()
+        .first()?
+        .file_id;
+
+    let target = VUnionRefAttribute::find_all_in(property)
+        .iter()
+        .filter_map(|attr| attr.resolve_target(db, bean_file_id, &bean_id))
+        .find(|target| target.contains_field(&field_key))?;
+
+    let values = target
+        .pairs
+        .iter()
+        .map(|(local_field, target_key)| {
+            let value = get_row_field_value(semantic_model, &row, local_field)?;
+            Some((target_key, value))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let config_index = db.get_config_index();
+    for (file_id, occurrences) in config_index.iter_file_config_table_pk_occurrences() {
+        for occ in occurrences {
+            let ConfigTablePkOccurrence::Union {
+                config_table,
+                keys,
+                values: occ_values,
+                ranges,
+            } = occ
+            else {
+                continue;
+            };
+            if config_table != &target.table {
+                continue;
+            }
+
+            let matched = values.iter().all(|(target_key, value)| {
+                keys.iter()
+                    .position(|key| key == *target_key)
+                    .and_then(|idx| occ_values.get(idx))
+                    .is_some_and(|occ_value| occ_value == value)
+            });
+            if matched {
+                return Some(ConfigRefTarget {
+                    config_table: config_table.clone(),
+                    file_id: *file_id,
+                    range: *ranges.first()?,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+fn get_row_field_value(
+    semantic_model: &SemanticModel,
+    row: &LuaTableExpr,
+    key: &LuaMemberKey,
+) -> Option<LuaType> {
+    let name = key.get_name()?;
+    let field = row.get_fields().find(|field| {
+        field
+            .get_field_key()
+            .is_some_and(|field_key| field_key.get_path_part() == name)
+    })?;
+    let value_expr = field.get_value_expr()?;
+    if !matches!(value_expr, LuaExpr::LiteralExpr(_)) {
+        return None;
+    }
+    semantic_model.infer_expr(value_expr).ok()
+}
@@ -0,0 +1,151 @@
+use std::collections::{HashMap, HashSet};
+
+use emmylua_code_analysis::{
+    ConfigTablePkOccurrence, DbIndex, FileId, LuaMemberKey, LuaType, LuaTypeDeclId, RenderLevel,
+    humanize_type, shared::luaconfig::CONFIG_TABLE,
+};
+use emmylua_parser::{LuaAstNode, LuaChunk, LuaExpr, LuaLiteralToken, LuaTableExpr, LuaTableField};
+use rowan::TextRange;
+
+/// 配置表数据中的一条记录
+#[derive(Debug, Clone)]
+pub struct ConfigRow {
+    pub config_table: LuaTypeDeclId,
+    /// 记录名称, 由主键值与展示字段组成
+    pub name: String,
+    pub row: LuaTableExpr,
+    /// 第一个主键字段的范围
+    pub pk_range: TextRange,
+}
+
+/// 基于 `LuaConfigPipeline` 收集的主键出现位置, 收集文件中的配置表记录
+///
+/// 独立多主键的记录只会收集一次.
+pub fn collect_config_rows(
+    db: &DbIndex,
+    file_id: FileId,
+    root: &LuaChunk,
+    mut filter: impl FnMut(&LuaTypeDeclId) -> bool,
+) -> Vec<ConfigRow> {
+    let config_index = db.get_config_index();
+    let Some(occurrences) = config_index.get_config_table_pk_occurrences(&file_id) else {
+        return Vec::new();
+    };
+
+    let mut rows = Vec::new();
+    let mut visited: HashSet<TextRange> = HashSet::new();
+    let mut display_keys: HashMap<LuaTypeDeclId, Option<LuaMemberKey>> = HashMap::new();
+    for occ in occurrences {
+        let config_table = occ.get_config_table();
+        if !filter(config_table) {
+            continue;
+        }
+
+        let Some(pk_range) = get_config_row_pk_range(occ) else {
+            continue;
+        };
+        let Some(row) = find_config_row(root, pk_range) else {
+            continue;
+        };
+        if !visited.insert(row.get_range()) {
+            continue;
+        }
+
+        let display_key = display_keys
+            .entry(config_table.clone())
+            .or_insert_with(|| CONFIG_TABLE.get_display_key(db, config_table));
+        let display = display_key
+            .as_ref()
+            .and_then(|key| get_config_row_display(&row, key));
+        let keys_len = config_index
+            .get_config_table_keys(config_table)
+            .map(|keys| keys.keys().len())
+            .unwrap_or(1);
+
+        rows.push(ConfigRow {
+            config_table: config_table.clone(),
+            name: build_config_row_name(db, occ, keys_len, display.as_deref()),
+            row,
+            pk_range,
+        });
+    }
+
+    rows
+}
+
+/// 获取主键出现位置中第一个主键字段的范围
+fn get_config_row_pk_range(occurrence: &ConfigTablePkOccurrence) -> Option<TextRange> {
+    match occurrence {
+        ConfigTablePkOccurrence::Solo { range, .. } => Some(*range),
+        ConfigTablePkOccurrence::Union { ranges, .. } => ranges.first().copied(),
+    }
+}
+
+/// 根据主键字段的位置找到其所在的记录
+fn find_config_row(root: &LuaChunk, pk_range: TextRange) -> Option<LuaTableExpr> {
+    if !root.get_range().contains_range(pk_range) {
+        return None;
+    }
+
+    let node = match root.syntax().covering_element(pk_range) {
+        rowan::NodeOrToken::Node(node) => node,
+        rowan::NodeOrToken::Token(token) => token.parent()?,
+    };
+    let field = node.ancestors().find_map(LuaTableField::cast)?;
+    field.get_parent::<LuaTableExpr>()
+}
+
+/// 获取记录中展示字段的字符串值
+fn get_config_row_display(row: &LuaTableExpr, display_key: &LuaMemberKey) -> Option<String> {
+    let display_name = display_key.get_name()?;
+    let field = row.get_fields().find(|field| {
+        field
+            .get_field_key()
+            .is_some_and(|key| key.get_path_part() == display_name)
+    })?;
+
+    let LuaExpr::LiteralExpr(literal) = field.get_value_expr()? else {
+        return None;
+    };
+    match literal.get_literal()? {
+        LuaLiteralToken::String(token) => Some(token.get_value()),
+        _ => None,
+    }
+}
+
+/// 构造记录名称: 主键值 + 展示字段
+///
+/// - 单主键: `1001 Sword`
+/// - 独立多主键: `code="sword" Sword`
+/// - 联合主键: `1, 2 Sword`
+fn build_config_row_name(
+    db: &DbIndex,
+    occurrence: &ConfigTablePkOccurrence,
+    keys_len: usize,
+    display: Option<&str>,
+) -> String {
+    let mut name = match occurrence {
+        ConfigTablePkOccurrence::Solo { key, value, .. } => {
+            if keys_len > 1 {
+                format!("{}={}", key.to_path(), render_pk_value(db, value))
+            } else {
+                render_pk_value(db, value)
+            }
+        }
+        ConfigTablePkOccurrence::Union { values, .. } => values
+            .iter()
+            .map(|value| render_pk_value(db, value))
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+
+    if let Some(display) = display {
+        name.push(' ');
+        name.push_str(display);
+    }
+    name
+}
+
+fn render_pk_value(db: &DbIndex, value: &LuaType) -> String {
+    humanize_type(db, value, RenderLevel::Simple)
+}
@@ -1,7 +1,11 @@
+mod config_ref;
+mod config_row;
 mod desc;
 mod module_name_convert;
 mod time_cancel_token;
 
+pub use config_ref::find_config_union_ref_target;
+pub use config_row::{ConfigRow, collect_config_rows};
 pub use desc::*;
 pub use module_name_convert::{
     file_name_convert, module_name_convert, to_camel_case, to_pascal_case, to_snake_case,
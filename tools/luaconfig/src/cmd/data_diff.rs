@@ -0,0 +1,40 @@
+use std::{path::Path, process::ExitCode};
+
+use luaconfig::data_diff::{data_diff_to_json, diff_config_data, render_data_diff_markdown};
+
+use crate::load_analysis;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Format {
+    Markdown,
+    Json,
+}
+
+impl Format {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "markdown" | "md" => Some(Self::Markdown),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// 输出记录级别的数据差异
+pub fn run(old_root: &Path, new_root: &Path, format: Format) -> ExitCode {
+    let old = load_analysis(old_root);
+    let new = load_analysis(new_root);
+    let diff = diff_config_data(old.compilation.get_db(), new.compilation.get_db());
+    match format {
+        Format::Markdown => print!("{}", render_data_diff_markdown(&diff)),
+        Format::Json => match serde_json::to_string_pretty(&data_diff_to_json(&diff)) {
+            Ok(json) => println!("{json}"),
+            Err(err) => {
+                eprintln!("failed to serialize data diff: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    ExitCode::SUCCESS
+}
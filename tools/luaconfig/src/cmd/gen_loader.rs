@@ -0,0 +1,21 @@
+use std::{fs, path::Path, process::ExitCode};
+
+use luaconfig::codegen::generate_lua_loader;
+
+use crate::load_analysis;
+
+pub fn run(root: &Path, output: &Path) -> ExitCode {
+    let Some(module_name) = output.file_stem().and_then(|stem| stem.to_str()) else {
+        eprintln!("invalid output path {}", output.display());
+        return ExitCode::FAILURE;
+    };
+
+    let analysis = load_analysis(root);
+    let source = generate_lua_loader(analysis.compilation.get_db(), module_name);
+    if let Err(err) = fs::write(output, source) {
+        eprintln!("failed to write {}: {err}", output.display());
+        return ExitCode::FAILURE;
+    }
+    println!("wrote {}", output.display());
+    ExitCode::SUCCESS
+}
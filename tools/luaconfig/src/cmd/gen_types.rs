@@ -0,0 +1,31 @@
+use std::{fs, path::Path, process::ExitCode};
+
+use luaconfig::codegen::{generate_csharp_types, generate_typescript_types};
+
+use crate::load_analysis;
+
+/// 按输出文件扩展名选择语言, C# 的命名空间为输出文件名
+pub fn run(root: &Path, output: &Path) -> ExitCode {
+    let extension = output.extension().and_then(|ext| ext.to_str());
+    let stem = output.file_stem().and_then(|stem| stem.to_str());
+    let analysis = load_analysis(root);
+    let db = analysis.compilation.get_db();
+    let source = match (extension, stem) {
+        (Some("cs"), Some(namespace)) => generate_csharp_types(db, namespace),
+        (Some("ts"), _) => generate_typescript_types(db),
+        _ => {
+            eprintln!(
+                "unsupported output {}, expected a .cs or .ts file",
+                output.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(err) = fs::write(output, source) {
+        eprintln!("failed to write {}: {err}", output.display());
+        return ExitCode::FAILURE;
+    }
+    println!("wrote {}", output.display());
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,90 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use luaconfig::import::import_csv;
+use tokio_util::sync::CancellationToken;
+
+use crate::load_analysis;
+
+/// 导入 CSV, 存在转换错误时不写入文件. 写入后以工作区的定义校验生成的文件, 存在诊断时返回失败
+pub fn run(root: &Path, csv: &Path, config_table: &str, output: Option<&Path>) -> ExitCode {
+    let text = match fs::read_to_string(csv) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("failed to read {}: {err}", csv.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let delimiter = match csv.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("tsv") => '\t',
+        _ => ',',
+    };
+
+    let mut analysis = load_analysis(root);
+    let result = match import_csv(
+        analysis.compilation.get_db(),
+        config_table,
+        &text,
+        delimiter,
+    ) {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if !result.errors.is_empty() {
+        for error in &result.errors {
+            match &error.column {
+                Some(column) => eprintln!(
+                    "{}:{}: [{column}] {}",
+                    csv.display(),
+                    error.line,
+                    error.message
+                ),
+                None => eprintln!("{}:{}: {}", csv.display(), error.line, error.message),
+            }
+        }
+        eprintln!("{} error(s), nothing written", result.errors.len());
+        return ExitCode::FAILURE;
+    }
+
+    let output = output
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| csv.with_extension("lua"));
+    if let Err(err) = fs::write(&output, &result.source) {
+        eprintln!("failed to write {}: {err}", output.display());
+        return ExitCode::FAILURE;
+    }
+    println!("wrote {} rows to {}", result.rows, output.display());
+
+    let Some(file_id) = analysis.update_file_by_path(&absolute_path(&output), Some(result.source))
+    else {
+        return ExitCode::SUCCESS;
+    };
+    let diagnostics = analysis
+        .diagnose_file(file_id, CancellationToken::new())
+        .unwrap_or_default();
+    for diagnostic in &diagnostics {
+        eprintln!(
+            "{}:{}:{}: {}",
+            output.display(),
+            diagnostic.range.start.line + 1,
+            diagnostic.range.start.character + 1,
+            diagnostic.message
+        );
+    }
+
+    if !diagnostics.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn absolute_path(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
@@ -0,0 +1,5 @@
+pub mod data_diff;
+pub mod gen_loader;
+pub mod gen_types;
+pub mod import_csv;
+pub mod schema_diff;
@@ -0,0 +1,66 @@
+use std::{path::Path, process::ExitCode};
+
+use emmylua_code_analysis::{EmmyLuaAnalysis, LuaMemberKey};
+use luaconfig::schema::{ConfigSchema, SchemaChange, SchemaChangeKind, diff_config_schema};
+
+use crate::load_analysis;
+
+/// 报告结构变化, 存在不兼容的变化时返回失败
+pub fn run(old_root: &Path, new_root: &Path) -> ExitCode {
+    let old = load_analysis(old_root);
+    let new = load_analysis(new_root);
+    let old_schema = ConfigSchema::collect(old.compilation.get_db());
+    let db = new.compilation.get_db();
+    let diff = diff_config_schema(&old_schema, &ConfigSchema::collect(db), db);
+    if diff.is_empty() {
+        println!("no schema changes");
+        return ExitCode::SUCCESS;
+    }
+
+    for (kind, title) in [
+        (SchemaChangeKind::Breaking, "breaking changes"),
+        (SchemaChangeKind::Compatible, "compatible changes"),
+    ] {
+        let changes = diff
+            .changes
+            .iter()
+            .filter(|change| change.kind == kind)
+            .collect::<Vec<_>>();
+        if changes.is_empty() {
+            continue;
+        }
+
+        println!("{title}:");
+        for change in changes {
+            print_change(&new, change);
+        }
+    }
+
+    if diff.has_breaking_changes() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_change(analysis: &EmmyLuaAnalysis, change: &SchemaChange) {
+    println!("  {}: {}", change.subject, change.message);
+    let vfs = analysis.compilation.get_db().get_vfs();
+    for row in &change.affected_rows {
+        let Some(document) = vfs.get_document(&row.file_id) else {
+            continue;
+        };
+        let line = document.get_line(row.range.start()).unwrap_or(0) + 1;
+        let key = match &row.key {
+            LuaMemberKey::Integer(i) => i.to_string(),
+            key => format!("{:?}", key.to_path()),
+        };
+        println!(
+            "    {}:{} {}[{}]",
+            document.get_file_path().display(),
+            line,
+            row.config_table.get_name(),
+            key
+        );
+    }
+}
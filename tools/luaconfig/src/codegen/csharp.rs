@@ -0,0 +1,216 @@
+use std::fmt::Write;
+
+use emmylua_code_analysis::{DbIndex, attributes::ConfigTableMode};
+
+use crate::schema::{BeanSchema, ConfigSchema, Doc, EnumSchema, EnumValue, FieldType, TableSchema};
+
+use super::{GENERATED_HEADER, to_pascal_case, type_name};
+
+/// 生成 C# 类型定义: 枚举 (`flags` 枚举带 `[Flags]`)、Bean 类与配置表容器类.
+///
+/// 属性名为字段名的 PascalCase, 通过 `[JsonPropertyName]` 对应数据中的字段名.
+pub fn generate_csharp_types(db: &DbIndex, namespace: &str) -> String {
+    let schema = ConfigSchema::collect(db);
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "// <auto-generated>\n// {GENERATED_HEADER}\n// </auto-generated>\n#nullable enable\n\n\
+         using System;\nusing System.Collections.Generic;\nusing System.Text.Json.Serialization;\n\n\
+         namespace {namespace}\n{{\n"
+    );
+
+    let mut items = Vec::new();
+    items.extend(schema.enums.values().map(write_enum));
+    items.extend(schema.beans.values().map(|bean| write_bean(&schema, bean)));
+    items.extend(
+        schema
+            .tables
+            .values()
+            .filter_map(|table| write_table(&schema, table)),
+    );
+    out.push_str(&items.join("\n"));
+    out.push_str("}\n");
+    out
+}
+
+fn write_enum(def: &EnumSchema) -> String {
+    let name = type_name(&def.name);
+    let mut out = String::new();
+    write_doc(&mut out, "    ", &def.doc, &["flags"]);
+    if def.is_string_enum() {
+        // 字符串枚举以常量表示, 字段类型为 `string`
+        let _ = writeln!(out, "    public static class {name}\n    {{");
+        for member in &def.members {
+            write_doc(&mut out, "        ", &member.doc, &[]);
+            let value = match &member.value {
+                EnumValue::Integer(i) => format!("\"{i}\""),
+                EnumValue::String(s) => format!("{s:?}"),
+            };
+            let _ = writeln!(
+                out,
+                "        public const string {} = {value};",
+                member.name
+            );
+        }
+    } else {
+        if def.flags {
+            out.push_str("    [Flags]\n");
+        }
+        let _ = writeln!(out, "    public enum {name}\n    {{");
+        for member in &def.members {
+            write_doc(&mut out, "        ", &member.doc, &[]);
+            if let EnumValue::Integer(i) = member.value {
+                let _ = writeln!(out, "        {} = {i},", member.name);
+            }
+        }
+    }
+    out.push_str("    }\n");
+    out
+}
+
+fn write_bean(schema: &ConfigSchema, def: &BeanSchema) -> String {
+    let name = type_name(&def.name);
+    let mut out = String::new();
+    write_doc(&mut out, "    ", &def.doc, &[]);
+    match &def.parent {
+        Some(parent) => {
+            let _ = writeln!(
+                out,
+                "    public class {name} : {}\n    {{",
+                type_name(parent)
+            );
+        }
+        None => {
+            let _ = writeln!(out, "    public class {name}\n    {{");
+        }
+    }
+
+    let fields = def
+        .declared_fields
+        .iter()
+        .filter_map(|field| def.fields.get(field));
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        write_doc(&mut out, "        ", &field.doc, &[]);
+        let mut property = to_pascal_case(&field.name);
+        if property == name {
+            // 成员名不能与所在类型同名
+            property.push('_');
+        }
+        let typ = csharp_type(schema, &field.field_type);
+        let _ = writeln!(out, "        [JsonPropertyName({:?})]", field.name);
+        if field.nullable {
+            let _ = writeln!(out, "        public {typ}? {property} {{ get; set; }}");
+        } else if is_value_type(schema, &field.field_type) {
+            let _ = writeln!(out, "        public {typ} {property} {{ get; set; }}");
+        } else {
+            let _ = writeln!(
+                out,
+                "        public {typ} {property} {{ get; set; }} = default!;"
+            );
+        }
+    }
+    out.push_str("    }\n");
+    out
+}
+
+fn write_table(schema: &ConfigSchema, def: &TableSchema) -> Option<String> {
+    let bean = type_name(def.bean.as_deref()?);
+    let mut out = String::new();
+    write_doc(&mut out, "    ", &def.doc, &[]);
+    let base = match (def.mode, schema.get_map_key_type(def)) {
+        (ConfigTableMode::List, _) => format!("List<{bean}>"),
+        (_, Some(key)) => format!("Dictionary<{}, {bean}>", csharp_type(schema, key)),
+        (_, None) => format!("Dictionary<string, {bean}>"),
+    };
+    let _ = writeln!(
+        out,
+        "    public class {} : {base}\n    {{\n    }}",
+        type_name(&def.name)
+    );
+    Some(out)
+}
+
+fn csharp_type(schema: &ConfigSchema, typ: &FieldType) -> String {
+    match typ {
+        FieldType::Bool => "bool".to_string(),
+        FieldType::Byte => "byte".to_string(),
+        FieldType::Short => "short".to_string(),
+        FieldType::Int => "int".to_string(),
+        FieldType::Long | FieldType::DateTime => "long".to_string(),
+        FieldType::Float => "float".to_string(),
+        FieldType::Double => "double".to_string(),
+        FieldType::String => "string".to_string(),
+        FieldType::Enum(name) if is_string_enum(schema, name) => "string".to_string(),
+        FieldType::Enum(name) | FieldType::Bean(name) => type_name(name),
+        FieldType::Array(element) => format!("{}[]", csharp_type(schema, element)),
+        FieldType::List(element) => format!("List<{}>", csharp_type(schema, element)),
+        FieldType::Set(element) => format!("HashSet<{}>", csharp_type(schema, element)),
+        FieldType::Map(key, value) => format!(
+            "Dictionary<{}, {}>",
+            csharp_type(schema, key),
+            csharp_type(schema, value)
+        ),
+        FieldType::Unknown(_) => "object".to_string(),
+    }
+}
+
+fn is_value_type(schema: &ConfigSchema, typ: &FieldType) -> bool {
+    match typ {
+        FieldType::Enum(name) => !is_string_enum(schema, name),
+        _ => matches!(
+            typ,
+            FieldType::Bool
+                | FieldType::Byte
+                | FieldType::Short
+                | FieldType::Int
+                | FieldType::Long
+                | FieldType::Float
+                | FieldType::Double
+                | FieldType::DateTime
+        ),
+    }
+}
+
+/// 字符串枚举生成为静态类, 不能作为字段类型
+fn is_string_enum(schema: &ConfigSchema, name: &str) -> bool {
+    schema
+        .enums
+        .get(name)
+        .is_some_and(|def| def.is_string_enum())
+}
+
+/// 描述写入 `<summary>`, 特性写入 `<remarks>`, `skip` 中的特性已由 C# 特性表示
+fn write_doc(out: &mut String, indent: &str, doc: &Doc, skip: &[&str]) {
+    let attributes = doc
+        .attributes
+        .iter()
+        .filter(|attribute| !skip.contains(&attribute.as_str()))
+        .collect::<Vec<_>>();
+    if let Some(description) = &doc.description {
+        let _ = writeln!(out, "{indent}/// <summary>");
+        for line in description.lines().map(str::trim) {
+            if line.is_empty() {
+                let _ = writeln!(out, "{indent}///");
+            } else {
+                let _ = writeln!(out, "{indent}/// {}", escape_xml(line));
+            }
+        }
+        let _ = writeln!(out, "{indent}/// </summary>");
+    }
+    if !attributes.is_empty() {
+        let _ = writeln!(out, "{indent}/// <remarks>");
+        for attribute in attributes {
+            let _ = writeln!(out, "{indent}/// {}", escape_xml(attribute));
+        }
+        let _ = writeln!(out, "{indent}/// </remarks>");
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
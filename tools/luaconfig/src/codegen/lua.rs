@@ -0,0 +1,215 @@
+use std::fmt::Write;
+
+use emmylua_code_analysis::{DbIndex, attributes::ConfigTableMode};
+
+use crate::schema::{ConfigSchema, TableSchema};
+
+use super::{GENERATED_HEADER, collect_data_modules, to_pascal_case};
+
+const HELPERS: &str = r#"--- 将各数据文件中的记录依次收集到一个列表, 数据文件的键不作为主键使用
+---@param modules string[]
+---@param iter function `list` 模式为 `ipairs`, 保持记录顺序
+---@return table
+local function load_rows(modules, iter)
+    local rows = {}
+    for _, name in ipairs(modules) do
+        for _, row in iter(require(name)) do
+            rows[#rows + 1] = row
+        end
+    end
+    return rows
+end
+
+--- 按主键字段建立多层索引, 最后一层的值为记录
+---@param rows table
+---@param keys string[]
+---@return table
+local function build_index(rows, keys)
+    local index = {}
+    for _, row in pairs(rows) do
+        local node = index
+        for i = 1, #keys - 1 do
+            local key = row[keys[i]]
+            local child = node[key]
+            if child == nil then
+                child = {}
+                node[key] = child
+            end
+            node = child
+        end
+        node[row[keys[#keys]]] = row
+    end
+    return index
+end
+"#;
+
+/// 生成按需加载配置数据的 Lua 模块, 为每张配置表生成带类型注解的访问器:
+///
+/// - `all()`: 全部记录的列表, `list` 模式另有 `ipairs()`
+/// - `get(...)`: 按主键字段建立索引后查找, 联合主键按顺序传入各字段
+/// - `getBy<Key>(value)`: 独立索引的每个主键
+/// - `singleton` 模式的 `get()` 返回唯一的记录
+pub fn generate_lua_loader(db: &DbIndex, module_name: &str) -> String {
+    let schema = ConfigSchema::collect(db);
+    let mut out = String::new();
+    let _ = writeln!(out, "-- {GENERATED_HEADER}\n");
+    out.push_str(HELPERS);
+    let _ = writeln!(out, "\n---@class {module_name}\nlocal {module_name} = {{}}");
+
+    for table in schema.tables.values() {
+        let Some(bean) = &table.bean else {
+            continue;
+        };
+        let modules = collect_data_modules(db, &table.name);
+        LuaTableWriter {
+            out: &mut out,
+            schema: &schema,
+            module_name,
+            table,
+            bean,
+        }
+        .write(&modules);
+    }
+
+    let _ = writeln!(out, "\nreturn {module_name}");
+    out
+}
+
+struct LuaTableWriter<'a> {
+    out: &'a mut String,
+    schema: &'a ConfigSchema,
+    module_name: &'a str,
+    table: &'a TableSchema,
+    bean: &'a str,
+}
+
+impl LuaTableWriter<'_> {
+    fn write(&mut self, modules: &[String]) {
+        let name = self.table.name.replace('.', "_");
+        let bean = self.bean;
+        let is_list = self.table.mode == ConfigTableMode::List;
+        let modules = modules
+            .iter()
+            .map(|module| format!("{module:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let _ = write!(
+            self.out,
+            r#"
+---@class {module}.{name}
+local {name} = {{}}
+{module}.{name} = {name}
+
+---@type table?
+local {name}_rows
+
+---@return {bean}[]
+function {name}.all()
+    if {name}_rows == nil then
+        {name}_rows = load_rows({{ {modules} }}, {iter})
+    end
+    return {name}_rows
+end
+"#,
+            module = self.module_name,
+            iter = if is_list { "ipairs" } else { "pairs" },
+        );
+
+        if is_list {
+            let _ = write!(
+                self.out,
+                r#"
+---@return fun(): integer?, {bean}
+function {name}.ipairs()
+    local rows = {name}.all()
+    local i = 0
+    return function()
+        i = i + 1
+        local row = rows[i]
+        if row ~= nil then
+            return i, row
+        end
+    end
+end
+"#
+            );
+        }
+
+        match (self.table.mode, self.table.index.as_slice()) {
+            (ConfigTableMode::Singleton, _) => {
+                let _ = write!(
+                    self.out,
+                    r#"
+---@return {bean}
+function {name}.get()
+    local _, row = next({name}.all())
+    assert(row ~= nil, "{table_name} has no data")
+    return row
+end
+"#,
+                    table_name = self.table.name,
+                );
+            }
+            (_, []) => {}
+            // `map` 模式的单个主键总是生成 `get`, 数据文件可以不以主键为键
+            (ConfigTableMode::Map, keys @ [_]) => {
+                self.write_index_lookup(&name, "get", &format!("{name}_index"), keys)
+            }
+            (_, keys) if self.table.solo => {
+                for key in keys {
+                    self.write_index_lookup(
+                        &name,
+                        &format!("getBy{}", to_pascal_case(key)),
+                        &format!("{name}_{key}_index"),
+                        std::slice::from_ref(key),
+                    );
+                }
+            }
+            (_, keys) => self.write_index_lookup(&name, "get", &format!("{name}_index"), keys),
+        }
+    }
+
+    fn write_index_lookup(&mut self, name: &str, function: &str, index: &str, keys: &[String]) {
+        let Some((last, nested)) = keys.split_last() else {
+            return;
+        };
+        let bean = self.bean;
+        let _ = write!(self.out, "\n---@type table?\nlocal {index}\n\n");
+        for key in keys {
+            let _ = writeln!(self.out, "---@param {key} {}", self.key_type(key));
+        }
+        let _ = write!(
+            self.out,
+            r#"---@return {bean}?
+function {name}.{function}({params})
+    if {index} == nil then
+        {index} = build_index({name}.all(), {{ {names} }})
+    end
+    local node = {index}
+"#,
+            params = keys.join(", "),
+            names = keys
+                .iter()
+                .map(|key| format!("{key:?}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        for key in nested {
+            let _ = write!(
+                self.out,
+                "    node = node[{key}]\n    if node == nil then\n        return nil\n    end\n"
+            );
+        }
+        let _ = write!(self.out, "    return node[{last}]\nend\n");
+    }
+
+    fn key_type(&self, key: &str) -> String {
+        self.schema
+            .beans
+            .get(self.bean)
+            .and_then(|bean| bean.fields.get(key))
+            .map(|field| field.typ.clone())
+            .unwrap_or_else(|| "any".to_string())
+    }
+}
@@ -0,0 +1,47 @@
+mod csharp;
+mod lua;
+mod test;
+mod typescript;
+
+use std::collections::BTreeSet;
+
+pub use csharp::generate_csharp_types;
+pub use lua::generate_lua_loader;
+pub use typescript::generate_typescript_types;
+
+use emmylua_code_analysis::{DbIndex, LuaTypeDeclId};
+
+/// 生成代码的文件头
+const GENERATED_HEADER: &str = "由 `luaconfig` 根据配置表定义生成, 请勿手动修改";
+
+/// 配置表数据所在文件的模块路径, 按路径排序
+fn collect_data_modules(db: &DbIndex, config_table: &str) -> Vec<String> {
+    let id = LuaTypeDeclId::new(config_table);
+    let module_index = db.get_module_index();
+    db.get_config_index()
+        .iter_config_table_rows(&id)
+        .filter_map(|(file_id, _)| module_index.get_module(file_id))
+        .map(|module| module.full_module_name.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// 其他语言中的类型名, 命名空间中的 `.` 替换为 `_`
+fn type_name(name: &str) -> String {
+    name.replace('.', "_")
+}
+
+/// `chapterId` -> `ChapterId`
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
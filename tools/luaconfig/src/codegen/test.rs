@@ -0,0 +1,284 @@
+#[cfg(test)]
+mod tests {
+    use tokio_util::sync::CancellationToken;
+
+    use emmylua_code_analysis::{DiagnosticCode, VirtualWorkspace};
+
+    use crate::codegen::{generate_csharp_types, generate_lua_loader, generate_typescript_types};
+
+    const SCHEMA: &str = r#"
+        ---@class Item: Bean
+        ---@field id int
+        ---@field name string
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+
+        ---@class Stage: Bean
+        ---@field chapterId int
+        ---@field stageId int
+
+        ---@[t.index(["chapterId", "stageId"])]
+        ---@class TbStage: ConfigTable
+        ---@field [int] Stage
+
+        ---@class Npc: Bean
+        ---@field id int
+        ---@field name string
+
+        ---@[t.index(["id", "name"], "solo")]
+        ---@[t.mode("list")]
+        ---@class TbNpc: ConfigTable
+        ---@field [int] Npc
+
+        ---@class Global: Bean
+        ---@field maxLevel int
+
+        ---@[t.mode("singleton")]
+        ---@class TbGlobal: ConfigTable
+        ---@field [int] Global
+    "#;
+
+    fn def_workspace() -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def_file("schema.lua", SCHEMA);
+        ws.def_files(vec![
+            (
+                "data/item.lua",
+                r#"
+                ---@type TbItem
+                local items = {
+                    [1] = { id = 1, name = "a" },
+                }
+                return items
+                "#,
+            ),
+            (
+                "data/item_ext.lua",
+                r#"
+                ---@type TbItem
+                local items = {
+                    { id = 2, name = "b" },
+                }
+                return items
+                "#,
+            ),
+            (
+                "data/stage.lua",
+                r#"
+                ---@type TbStage
+                local stages = {
+                    { chapterId = 1, stageId = 1 },
+                }
+                return stages
+                "#,
+            ),
+            (
+                "data/npc.lua",
+                r#"
+                ---@type TbNpc
+                local npcs = {
+                    { id = 1, name = "a" },
+                }
+                return npcs
+                "#,
+            ),
+            (
+                "data/global.lua",
+                r#"
+                ---@type TbGlobal
+                local global = {
+                    { maxLevel = 60 },
+                }
+                return global
+                "#,
+            ),
+        ]);
+        ws
+    }
+
+    #[test]
+    fn test_generate_lua_loader() {
+        let ws = def_workspace();
+        let source = generate_lua_loader(ws.analysis.compilation.get_db(), "Tables");
+
+        assert!(source.contains(
+            r#"---@param id integer
+---@return Item?
+function TbItem.get(id)
+    if TbItem_index == nil then
+        TbItem_index = build_index(TbItem.all(), { "id" })
+    end
+    local node = TbItem_index
+    return node[id]
+end"#
+        ));
+        assert!(source.contains("---@return Item[]\nfunction TbItem.all()"));
+        assert!(
+            source.contains(r#"TbItem_rows = load_rows({ "data.item", "data.item_ext" }, pairs)"#)
+        );
+        assert!(source.contains(r#"TbNpc_rows = load_rows({ "data.npc" }, ipairs)"#));
+        assert!(source.contains(
+            r#"---@param chapterId integer
+---@param stageId integer
+---@return Stage?
+function TbStage.get(chapterId, stageId)"#
+        ));
+        assert!(source.contains("---@return Stage[]\nfunction TbStage.all()"));
+        assert!(source.contains("function TbStage.ipairs()"));
+        assert!(
+            source.contains("---@param id integer\n---@return Npc?\nfunction TbNpc.getById(id)")
+        );
+        assert!(
+            source
+                .contains("---@param name string\n---@return Npc?\nfunction TbNpc.getByName(name)")
+        );
+        assert!(source.contains("---@return Global\nfunction TbGlobal.get()"));
+    }
+
+    #[test]
+    fn test_generated_loader_is_checked() {
+        let mut ws = def_workspace();
+        let source = generate_lua_loader(ws.analysis.compilation.get_db(), "Tables");
+        let file_id = ws.def_file("Tables.lua", &source);
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeMismatch,
+            r#"
+            local Tables = require("Tables")
+            local item = Tables.TbItem.get(1)
+            local stage = Tables.TbStage.get(1, 2)
+            local npc = Tables.TbNpc.getByName("a")
+            local maxLevel = Tables.TbGlobal.get().maxLevel
+            for _, row in Tables.TbStage.ipairs() do
+                print(row.chapterId)
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeMismatch,
+            r#"
+            local Tables = require("Tables")
+            local item = Tables.TbItem.get("1")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedField,
+            r#"
+            local Tables = require("Tables")
+            local item = Tables.TbItem.get(1)
+            if item then
+                print(item.count)
+            end
+            "#
+        ));
+    }
+
+    const TYPES_SCHEMA: &str = r#"
+        ---@[flags]
+        ---@enum Element
+        Element = {
+            None = 0,
+            --- 火焰
+            Fire = 1,
+            Ice = 2,
+        }
+
+        ---@enum Rarity
+        Rarity = {
+            Common = "common",
+            Rare = "rare",
+        }
+
+        ---@class Reward: Bean
+        ---@field itemId int
+        ---@field count short
+
+        --- 物品
+        ---@class Item: Bean
+        --- 物品编号
+        ---@field id int
+        ---@field name string
+        ---@[v.range("[0,100]")]
+        ---@field level byte
+        ---@field price long?
+        ---@field openTime datetime
+        ---@field element Element
+        ---@field rewards list<Reward>
+        ---@field tags set<string>
+        ---@field weights map<int, float>
+        ---@field rarity Rarity
+        ---@field rarities list<Rarity>
+
+        ---@class Equip: Item
+        ---@field slot int
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+
+        ---@[t.mode("list")]
+        ---@class TbReward: ConfigTable
+        ---@field [int] Reward
+    "#;
+
+    #[test]
+    fn test_generate_csharp_types() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(TYPES_SCHEMA);
+        let code = generate_csharp_types(ws.analysis.compilation.get_db(), "Config");
+        for expected in [
+            "namespace Config\n{",
+            "    [Flags]\n    public enum Element\n    {",
+            "        /// <summary>\n        /// 火焰\n        /// </summary>\n        Fire = 1,",
+            "    /// <summary>\n    /// 物品\n    /// </summary>\n    public class Item\n    {",
+            "        /// <summary>\n        /// 物品编号\n        /// </summary>\n        [JsonPropertyName(\"id\")]\n        public int Id { get; set; }",
+            "        /// <remarks>\n        /// v.range(\"[0,100]\")\n        /// </remarks>\n        [JsonPropertyName(\"level\")]\n        public byte Level { get; set; }",
+            "public long? Price { get; set; }",
+            "public long OpenTime { get; set; }",
+            "public Element Element { get; set; }",
+            "public List<Reward> Rewards { get; set; } = default!;",
+            "public HashSet<string> Tags { get; set; } = default!;",
+            "public Dictionary<int, float> Weights { get; set; } = default!;",
+            "    public static class Rarity\n    {\n        public const string Common = \"common\";",
+            "public string Rarity { get; set; } = default!;",
+            "public List<string> Rarities { get; set; } = default!;",
+            "public short Count { get; set; }",
+            "    public class Equip : Item\n    {",
+            "    public class TbItem : Dictionary<int, Item>",
+            "    public class TbReward : List<Reward>",
+        ] {
+            assert!(code.contains(expected), "`{expected}` not found in\n{code}");
+        }
+    }
+
+    #[test]
+    fn test_generate_typescript_types() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(TYPES_SCHEMA);
+        let code = generate_typescript_types(ws.analysis.compilation.get_db());
+        for expected in [
+            "/**\n * @remarks flags\n */\nexport enum Element {\n    None = 0,",
+            "    /**\n     * 火焰\n     */\n    Fire = 1,",
+            "/**\n * 物品\n */\nexport interface Item {",
+            "    /**\n     * 物品编号\n     */\n    id: number;",
+            "    /**\n     * @remarks v.range(\"[0,100]\")\n     */\n    level: number;",
+            "    price?: number;",
+            "    element: Element;",
+            "    rewards: Reward[];",
+            "    tags: string[];",
+            "    weights: Record<number, number>;",
+            "export enum Rarity {\n    Common = \"common\",",
+            "    rarity: Rarity;",
+            "export interface Equip extends Item {\n",
+            "export type TbItem = Record<number, Item>;",
+            "export type TbReward = Reward[];",
+        ] {
+            assert!(code.contains(expected), "`{expected}` not found in\n{code}");
+        }
+    }
+}
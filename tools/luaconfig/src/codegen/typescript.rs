@@ -0,0 +1,142 @@
+use std::fmt::Write;
+
+use emmylua_code_analysis::{DbIndex, attributes::ConfigTableMode};
+
+use crate::schema::{BeanSchema, ConfigSchema, Doc, EnumSchema, EnumValue, FieldType, TableSchema};
+
+use super::{GENERATED_HEADER, type_name};
+
+/// 生成 TypeScript 类型定义: 枚举、Bean 接口与配置表类型
+pub fn generate_typescript_types(db: &DbIndex) -> String {
+    let schema = ConfigSchema::collect(db);
+    let mut out = format!("// {GENERATED_HEADER}\n");
+    for def in schema.enums.values() {
+        out.push('\n');
+        write_enum(&mut out, def);
+    }
+    for def in schema.beans.values() {
+        out.push('\n');
+        write_bean(&mut out, def);
+    }
+    for def in schema.tables.values() {
+        let Some(bean) = &def.bean else {
+            continue;
+        };
+        out.push('\n');
+        write_table(&mut out, &schema, def, bean);
+    }
+    out
+}
+
+fn write_enum(out: &mut String, def: &EnumSchema) {
+    write_doc(out, "", &def.doc);
+    let _ = writeln!(out, "export enum {} {{", type_name(&def.name));
+    for member in &def.members {
+        write_doc(out, "    ", &member.doc);
+        let value = match &member.value {
+            EnumValue::Integer(i) => i.to_string(),
+            EnumValue::String(s) => format!("{s:?}"),
+        };
+        let _ = writeln!(out, "    {} = {value},", member.name);
+    }
+    out.push_str("}\n");
+}
+
+fn write_bean(out: &mut String, def: &BeanSchema) {
+    let name = type_name(&def.name);
+    write_doc(out, "", &def.doc);
+    match &def.parent {
+        Some(parent) => {
+            let _ = writeln!(
+                out,
+                "export interface {name} extends {} {{",
+                type_name(parent)
+            );
+        }
+        None => {
+            let _ = writeln!(out, "export interface {name} {{");
+        }
+    }
+    let fields = def
+        .declared_fields
+        .iter()
+        .filter_map(|field| def.fields.get(field));
+    for field in fields {
+        write_doc(out, "    ", &field.doc);
+        let _ = writeln!(
+            out,
+            "    {}{}: {};",
+            field.name,
+            if field.nullable { "?" } else { "" },
+            typescript_type(&field.field_type)
+        );
+    }
+    out.push_str("}\n");
+}
+
+fn write_table(out: &mut String, schema: &ConfigSchema, def: &TableSchema, bean: &str) {
+    let bean = type_name(bean);
+    write_doc(out, "", &def.doc);
+    let typ = match (def.mode, schema.get_map_key_type(def)) {
+        (ConfigTableMode::List, _) => format!("{bean}[]"),
+        (_, Some(key)) => map_type(key, &bean),
+        (_, None) => format!("Record<string, {bean}>"),
+    };
+    let _ = writeln!(out, "export type {} = {typ};", type_name(&def.name));
+}
+
+fn typescript_type(typ: &FieldType) -> String {
+    match typ {
+        FieldType::Bool => "boolean".to_string(),
+        FieldType::Byte
+        | FieldType::Short
+        | FieldType::Int
+        | FieldType::Long
+        | FieldType::Float
+        | FieldType::Double
+        | FieldType::DateTime => "number".to_string(),
+        FieldType::String => "string".to_string(),
+        FieldType::Enum(name) | FieldType::Bean(name) => type_name(name),
+        FieldType::Array(element) | FieldType::List(element) | FieldType::Set(element) => {
+            format!("{}[]", typescript_type(element))
+        }
+        FieldType::Map(key, value) => map_type(key, &typescript_type(value)),
+        FieldType::Unknown(_) => "unknown".to_string(),
+    }
+}
+
+/// 枚举为键时不要求包含所有成员
+fn map_type(key: &FieldType, value: &str) -> String {
+    match key {
+        FieldType::Enum(name) => format!("Partial<Record<{}, {value}>>", type_name(name)),
+        FieldType::String | FieldType::Unknown(_) | FieldType::Bool => {
+            format!("Record<string, {value}>")
+        }
+        _ => format!("Record<number, {value}>"),
+    }
+}
+
+fn write_doc(out: &mut String, indent: &str, doc: &Doc) {
+    if doc.is_empty() {
+        return;
+    }
+
+    let _ = writeln!(out, "{indent}/**");
+    if let Some(description) = &doc.description {
+        for line in description.lines().map(str::trim) {
+            if line.is_empty() {
+                let _ = writeln!(out, "{indent} *");
+            } else {
+                let _ = writeln!(out, "{indent} * {}", escape_comment(line));
+            }
+        }
+    }
+    for attribute in &doc.attributes {
+        let _ = writeln!(out, "{indent} * @remarks {}", escape_comment(attribute));
+    }
+    let _ = writeln!(out, "{indent} */");
+}
+
+fn escape_comment(text: &str) -> String {
+    text.replace("*/", "*\\/")
+}
@@ -0,0 +1,380 @@
+mod render;
+mod test;
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+pub use render::{data_diff_to_json, render_data_diff_markdown};
+
+use emmylua_code_analysis::{
+    ConfigTableRow, ConfigValue, ConfigValueNode, DbIndex, FileId, LuaMemberKey, LuaTypeDeclId,
+    shared::luaconfig::{BEAN, CONFIG_TABLE},
+};
+use rowan::TextRange;
+
+/// 两个版本配置数据按主键匹配后的差异
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataDiff {
+    /// 按配置表名排序, 不包含没有变化的配置表
+    pub tables: Vec<TableDataDiff>,
+}
+
+impl DataDiff {
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableDataDiff {
+    pub name: String,
+    /// 按主键排序
+    pub rows: Vec<RowChange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowChange {
+    pub kind: RowChangeKind,
+    /// 主键值, 联合主键以 `, ` 分隔
+    pub key: String,
+    pub old: Option<RowLocation>,
+    pub new: Option<RowLocation>,
+    /// 修改的字段, 只有 `Modified` 有值
+    pub fields: Vec<FieldChange>,
+}
+
+/// 记录所在的文件与行号, 路径相对于工作区根目录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowLocation {
+    pub path: String,
+    /// 从 1 开始
+    pub line: usize,
+}
+
+/// 字段的新旧值, 嵌套字段以路径表示, 如 `rewards[1].count`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub path: String,
+    /// 字段不存在时为 `None`
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// 按主键匹配两个版本中的记录, 忽略记录的顺序、所在文件与书写格式
+pub fn diff_config_data(old: &DbIndex, new: &DbIndex) -> DataDiff {
+    let names = collect_config_tables(old)
+        .into_iter()
+        .chain(collect_config_tables(new))
+        .collect::<BTreeSet<_>>();
+
+    let mut tables = Vec::new();
+    for name in names {
+        let id = LuaTypeDeclId::new(&name);
+        let old_rows = collect_rows(old, &id);
+        let new_rows = collect_rows(new, &id);
+
+        let mut rows = Vec::new();
+        for (key, old_row) in &old_rows {
+            match new_rows.get(key) {
+                Some(new_row) => {
+                    let mut fields = Vec::new();
+                    diff_value(
+                        String::new(),
+                        (old_row.source(old), &old_row.row.value),
+                        (new_row.source(new), &new_row.row.value),
+                        &mut fields,
+                    );
+                    if !fields.is_empty() {
+                        rows.push((
+                            key,
+                            RowChange {
+                                kind: RowChangeKind::Modified,
+                                key: key.join(", "),
+                                old: old_row.location(old),
+                                new: new_row.location(new),
+                                fields,
+                            },
+                        ));
+                    }
+                }
+                None => rows.push((
+                    key,
+                    RowChange {
+                        kind: RowChangeKind::Removed,
+                        key: key.join(", "),
+                        old: old_row.location(old),
+                        new: None,
+                        fields: Vec::new(),
+                    },
+                )),
+            }
+        }
+        for (key, new_row) in &new_rows {
+            if !old_rows.contains_key(key) {
+                rows.push((
+                    key,
+                    RowChange {
+                        kind: RowChangeKind::Added,
+                        key: key.join(", "),
+                        old: None,
+                        new: new_row.location(new),
+                        fields: Vec::new(),
+                    },
+                ));
+            }
+        }
+
+        if !rows.is_empty() {
+            rows.sort_by(|(a, _), (b, _)| compare_keys(a, b));
+            let rows = rows.into_iter().map(|(_, row)| row).collect();
+            tables.push(TableDataDiff { name, rows });
+        }
+    }
+
+    DataDiff { tables }
+}
+
+fn collect_config_tables(db: &DbIndex) -> Vec<String> {
+    db.get_type_index()
+        .get_all_types()
+        .into_iter()
+        .filter(|type_decl| type_decl.is_class())
+        .map(|type_decl| type_decl.get_id())
+        .filter(|id| !CONFIG_TABLE.matches_decl(id) && !BEAN.matches_decl(id))
+        .filter(|id| CONFIG_TABLE.is_config_table(db, id))
+        .map(|id| id.get_name().to_string())
+        .collect()
+}
+
+struct KeyedRow<'a> {
+    file_id: FileId,
+    row: &'a ConfigTableRow,
+}
+
+impl KeyedRow<'_> {
+    fn source<'a>(&self, db: &'a DbIndex) -> RowSource<'a> {
+        RowSource {
+            db,
+            file_id: self.file_id,
+        }
+    }
+
+    fn location(&self, db: &DbIndex) -> Option<RowLocation> {
+        let document = db.get_vfs().get_document(&self.file_id)?;
+        let path = document.get_file_path();
+        let path = db
+            .get_module_index()
+            .get_workspace_relative_path(path)
+            .unwrap_or(path);
+        Some(RowLocation {
+            path: path.to_string_lossy().replace('\\', "/"),
+            line: document.get_line(self.row.range.start())? + 1,
+        })
+    }
+}
+
+/// 按主键收集记录, 主键重复时保留路径与位置靠前的记录
+fn collect_rows<'a>(db: &'a DbIndex, id: &LuaTypeDeclId) -> BTreeMap<Vec<String>, KeyedRow<'a>> {
+    let config_index = db.get_config_index();
+    let pk_fields: &[LuaMemberKey] = match config_index.get_config_table_keys(id) {
+        // 独立索引的每个主键都唯一, 用第一个即可匹配
+        Some(keys) if keys.is_solo() => keys
+            .keys()
+            .first()
+            .map(std::slice::from_ref)
+            .unwrap_or_default(),
+        Some(keys) => keys.keys(),
+        None => &[],
+    };
+
+    let mut rows = config_index
+        .iter_config_table_rows(id)
+        .map(|(file_id, row)| KeyedRow { file_id, row })
+        .collect::<Vec<_>>();
+    rows.sort_by_cached_key(|keyed| {
+        (
+            db.get_vfs().get_file_path(&keyed.file_id).cloned(),
+            keyed.row.range.start(),
+        )
+    });
+
+    let mut result = BTreeMap::new();
+    for keyed in rows {
+        let key = row_primary_key(keyed.source(db), keyed.row, pk_fields);
+        result.entry(key).or_insert(keyed);
+    }
+    result
+}
+
+/// 记录的主键值, 联合主键按字段顺序排列, 没有主键字段时使用记录在数据表中的键
+fn row_primary_key(
+    source: RowSource,
+    row: &ConfigTableRow,
+    pk_fields: &[LuaMemberKey],
+) -> Vec<String> {
+    let values = pk_fields
+        .iter()
+        .map(|field| row.get_field(field).map(|node| source.format(node)))
+        .collect::<Option<Vec<_>>>();
+    match values {
+        Some(values) if !values.is_empty() => values,
+        _ => vec![match &row.key {
+            LuaMemberKey::Integer(i) => i.to_string(),
+            LuaMemberKey::Name(name) => format!("{:?}", name.as_str()),
+            key => key.to_path(),
+        }],
+    }
+}
+
+/// 联合主键逐个字段比较
+fn compare_keys(a: &[String], b: &[String]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| compare_key_part(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+/// 整数主键按数值排序
+fn compare_key_part(a: &str, b: &str) -> Ordering {
+    match (a.parse::<i64>(), b.parse::<i64>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+fn diff_value(
+    path: String,
+    (old_source, old): (RowSource, &ConfigValueNode),
+    (new_source, new): (RowSource, &ConfigValueNode),
+    fields: &mut Vec<FieldChange>,
+) {
+    match (&old.value, &new.value) {
+        (ConfigValue::Table { entries: old, .. }, ConfigValue::Table { entries: new, .. }) => {
+            for entry in old.iter() {
+                let entry_path = join_path(&path, &entry.key);
+                match new.iter().find(|new_entry| new_entry.key == entry.key) {
+                    Some(new_entry) => diff_value(
+                        entry_path,
+                        (old_source, &entry.value),
+                        (new_source, &new_entry.value),
+                        fields,
+                    ),
+                    None => fields.push(FieldChange {
+                        path: entry_path,
+                        old: Some(old_source.format(&entry.value)),
+                        new: None,
+                    }),
+                }
+            }
+            for entry in new.iter() {
+                if !old.iter().any(|old_entry| old_entry.key == entry.key) {
+                    fields.push(FieldChange {
+                        path: join_path(&path, &entry.key),
+                        old: None,
+                        new: Some(new_source.format(&entry.value)),
+                    });
+                }
+            }
+        }
+        _ if !value_eq((old_source, old), (new_source, new)) => fields.push(FieldChange {
+            path,
+            old: Some(old_source.format(old)),
+            new: Some(new_source.format(new)),
+        }),
+        _ => {}
+    }
+}
+
+/// `1` 与 `1.0` 视为相同的值, 无法求值的表达式比较源码
+fn value_eq(
+    (a_source, a): (RowSource, &ConfigValueNode),
+    (b_source, b): (RowSource, &ConfigValueNode),
+) -> bool {
+    match (&a.value, &b.value) {
+        (ConfigValue::Unknown, ConfigValue::Unknown) => {
+            match (a_source.text(a.range), b_source.text(b.range)) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            }
+        }
+        (a, b) => match (a.as_number(), b.as_number()) {
+            (Some(a), Some(b)) => a == b,
+            _ => a == b,
+        },
+    }
+}
+
+fn join_path(path: &str, key: &LuaMemberKey) -> String {
+    let key = key.to_path();
+    if path.is_empty() || key.starts_with('[') {
+        format!("{path}{key}")
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// 记录所在的版本与文件, 用于读取无法求值的表达式的源码
+#[derive(Clone, Copy)]
+struct RowSource<'a> {
+    db: &'a DbIndex,
+    file_id: FileId,
+}
+
+impl RowSource<'_> {
+    /// 合并空白后的源码
+    fn text(&self, range: TextRange) -> Option<String> {
+        let document = self.db.get_vfs().get_document(&self.file_id)?;
+        let text = document
+            .get_text()
+            .get(usize::from(range.start())..usize::from(range.end()))?;
+        Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+
+    /// 以 Lua 字面量的形式输出值, 无法求值的表达式输出源码
+    fn format(&self, node: &ConfigValueNode) -> String {
+        match &node.value {
+            ConfigValue::Nil => "nil".to_string(),
+            ConfigValue::Boolean(b) => b.to_string(),
+            ConfigValue::Integer(i) => i.to_string(),
+            ConfigValue::Float(f) => format!("{f:?}"),
+            ConfigValue::String(s) => format!("{:?}", s.as_str()),
+            ConfigValue::Table { entries, .. } => {
+                let mut next_index = 1;
+                let entries = entries
+                    .iter()
+                    .map(|entry| {
+                        let value = self.format(&entry.value);
+                        match &entry.key {
+                            LuaMemberKey::Integer(i)
+                                if entry.key_range.is_none() && *i == next_index =>
+                            {
+                                next_index += 1;
+                                value
+                            }
+                            LuaMemberKey::Name(name) => format!("{name} = {value}"),
+                            key => format!("{} = {value}", key.to_path()),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                if entries.is_empty() {
+                    "{}".to_string()
+                } else {
+                    format!("{{ {} }}", entries.join(", "))
+                }
+            }
+            ConfigValue::Unknown => self.text(node.range).unwrap_or_else(|| "?".to_string()),
+        }
+    }
+}
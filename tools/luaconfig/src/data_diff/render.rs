@@ -0,0 +1,108 @@
+use std::fmt::Write;
+
+use serde_json::{Value, json};
+
+use super::{DataDiff, RowChange, RowChangeKind, RowLocation};
+
+/// 输出 Markdown, 用于 PR 评论
+pub fn render_data_diff_markdown(diff: &DataDiff) -> String {
+    if diff.is_empty() {
+        return "No config data changes.\n".to_string();
+    }
+
+    let mut out = String::new();
+    for table in &diff.tables {
+        let count = |kind| table.rows.iter().filter(|row| row.kind == kind).count();
+        let _ = writeln!(
+            out,
+            "### {} (+{} -{} ~{})\n",
+            table.name,
+            count(RowChangeKind::Added),
+            count(RowChangeKind::Removed),
+            count(RowChangeKind::Modified)
+        );
+        for row in &table.rows {
+            let location = row
+                .new
+                .as_ref()
+                .or(row.old.as_ref())
+                .map(|location| format!(" ({}:{})", location.path, location.line))
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "- **{}** `{}`{}",
+                kind_name(row.kind),
+                row.key,
+                location
+            );
+            if row.fields.is_empty() {
+                continue;
+            }
+
+            let _ = writeln!(out, "\n  | field | old | new |\n  | --- | --- | --- |");
+            for field in &row.fields {
+                let _ = writeln!(
+                    out,
+                    "  | `{}` | {} | {} |",
+                    field.path,
+                    markdown_cell(field.old.as_deref()),
+                    markdown_cell(field.new.as_deref())
+                );
+            }
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
+
+/// 输出 JSON, 用于其他工具处理
+pub fn data_diff_to_json(diff: &DataDiff) -> Value {
+    Value::Array(
+        diff.tables
+            .iter()
+            .map(|table| {
+                json!({
+                    "table": table.name,
+                    "rows": table.rows.iter().map(row_to_json).collect::<Vec<_>>(),
+                })
+            })
+            .collect(),
+    )
+}
+
+fn row_to_json(row: &RowChange) -> Value {
+    json!({
+        "kind": kind_name(row.kind),
+        "key": row.key,
+        "old": row.old.as_ref().map(location_to_json),
+        "new": row.new.as_ref().map(location_to_json),
+        "fields": row
+            .fields
+            .iter()
+            .map(|field| json!({ "path": field.path, "old": field.old, "new": field.new }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn location_to_json(location: &RowLocation) -> Value {
+    json!({ "path": location.path, "line": location.line })
+}
+
+fn kind_name(kind: RowChangeKind) -> &'static str {
+    match kind {
+        RowChangeKind::Added => "added",
+        RowChangeKind::Removed => "removed",
+        RowChangeKind::Modified => "modified",
+    }
+}
+
+fn markdown_cell(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("`{}`", value.replace('|', "\\|")),
+        None => "-".to_string(),
+    }
+}
@@ -0,0 +1,301 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+
+    use crate::data_diff::{
+        DataDiff, FieldChange, RowChangeKind, data_diff_to_json, diff_config_data,
+        render_data_diff_markdown,
+    };
+
+    const SCHEMA: &str = r#"
+        ---@class Reward: Bean
+        ---@field itemId int
+        ---@field count int
+
+        ---@class Item: Bean
+        ---@field id int
+        ---@field name string
+        ---@field rewards list<Reward>
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+
+        ---@class Drop: Bean
+        ---@field level int
+        ---@field group string
+        ---@field weight int
+
+        ---@[t.index(["level", "group"])]
+        ---@class TbDrop: ConfigTable
+        ---@field [int] Drop
+    "#;
+
+    fn diff(old_files: &[(&str, &str)], new_files: &[(&str, &str)]) -> DataDiff {
+        let mut old_ws = VirtualWorkspace::new_with_init_std_lib();
+        old_ws.def(SCHEMA);
+        for (name, source) in old_files {
+            old_ws.def_file(name, source);
+        }
+
+        let mut new_ws = VirtualWorkspace::new_with_init_std_lib();
+        new_ws.def(SCHEMA);
+        for (name, source) in new_files {
+            new_ws.def_file(name, source);
+        }
+
+        diff_config_data(
+            old_ws.analysis.compilation.get_db(),
+            new_ws.analysis.compilation.get_db(),
+        )
+    }
+
+    #[test]
+    fn test_ignore_order_and_format() {
+        let diff = diff(
+            &[(
+                "item.lua",
+                r#"
+                ---@type TbItem
+                local items = {
+                    [1] = { id = 1, name = "a", rewards = { { itemId = 2, count = 1 } } },
+                    [2] = { id = 2, name = "b", rewards = {} },
+                }
+                "#,
+            )],
+            &[
+                (
+                    "item_b.lua",
+                    r#"
+                    ---@type TbItem
+                    local items = {
+                        [2] = {
+                            name = 'b',
+                            id = 2,
+                            rewards = {},
+                        },
+                    }
+                    "#,
+                ),
+                (
+                    "item_a.lua",
+                    r#"
+                    ---@type TbItem
+                    local items = {
+                        [1] = { rewards = { { count = 1.0, itemId = 1 + 1 } }, name = "a", id = 1 },
+                    }
+                    "#,
+                ),
+            ],
+        );
+        assert!(diff.is_empty(), "{diff:#?}");
+    }
+
+    #[test]
+    fn test_row_changes() {
+        let diff = diff(
+            &[(
+                "item.lua",
+                r#"
+                ---@type TbItem
+                local items = {
+                    [1] = { id = 1, name = "a", rewards = { { itemId = 2, count = 1 } } },
+                    [2] = { id = 2, name = "b", rewards = {} },
+                    [3] = { id = 3, name = "c", rewards = {} },
+                }
+                "#,
+            )],
+            &[(
+                "item.lua",
+                r#"
+                ---@type TbItem
+                local items = {
+                    [4] = { id = 4, name = "d", rewards = {} },
+                    [2] = { id = 2, name = "b", rewards = {} },
+                    [1] = { id = 1, name = "A", rewards = { { itemId = 2, count = 5 }, { itemId = 3, count = 1 } } },
+                }
+                "#,
+            )],
+        );
+
+        assert_eq!(diff.tables.len(), 1);
+        let table = &diff.tables[0];
+        assert_eq!(table.name, "TbItem");
+        let rows = table
+            .rows
+            .iter()
+            .map(|row| (row.kind, row.key.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (RowChangeKind::Modified, "1"),
+                (RowChangeKind::Removed, "3"),
+                (RowChangeKind::Added, "4"),
+            ]
+        );
+
+        let modified = &table.rows[0];
+        assert_eq!(modified.old.as_ref().map(|location| location.line), Some(4));
+        assert_eq!(modified.new.as_ref().map(|location| location.line), Some(6));
+        assert_eq!(
+            modified.fields,
+            vec![
+                FieldChange {
+                    path: "name".to_string(),
+                    old: Some("\"a\"".to_string()),
+                    new: Some("\"A\"".to_string()),
+                },
+                FieldChange {
+                    path: "rewards[1].count".to_string(),
+                    old: Some("1".to_string()),
+                    new: Some("5".to_string()),
+                },
+                FieldChange {
+                    path: "rewards[2]".to_string(),
+                    old: None,
+                    new: Some("{ itemId = 3, count = 1 }".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_union_primary_key() {
+        let diff = diff(
+            &[(
+                "drop.lua",
+                r#"
+                ---@type TbDrop
+                local drops = {
+                    { level = 1, group = "a", weight = 10 },
+                    { level = 1, group = "b", weight = 20 },
+                }
+                "#,
+            )],
+            &[(
+                "drop.lua",
+                r#"
+                ---@type TbDrop
+                local drops = {
+                    { level = 1, group = "b", weight = 30 },
+                    { level = 1, group = "a", weight = 10 },
+                }
+                "#,
+            )],
+        );
+
+        assert_eq!(diff.tables.len(), 1);
+        let rows = &diff.tables[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].kind, RowChangeKind::Modified);
+        assert_eq!(rows[0].key, "1, \"b\"");
+        assert_eq!(rows[0].fields[0].path, "weight");
+    }
+
+    #[test]
+    fn test_union_primary_key_order() {
+        let drops = |weight: i32| {
+            format!(
+                r#"
+                ---@type TbDrop
+                local drops = {{
+                    {{ level = 10, group = "a", weight = {weight} }},
+                    {{ level = 2, group = "a", weight = {weight} }},
+                }}
+                "#
+            )
+        };
+        let diff = diff(&[("drop.lua", &drops(1))], &[("drop.lua", &drops(2))]);
+
+        let keys = diff.tables[0]
+            .rows
+            .iter()
+            .map(|row| row.key.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["2, \"a\"", "10, \"a\""]);
+    }
+
+    #[test]
+    fn test_compare_unknown_value_source() {
+        let items = |name: &str| {
+            format!(
+                r#"
+                ---@type TbItem
+                local items = {{
+                    [1] = {{ id = 1, name = {name}, rewards = {{}} }},
+                }}
+                "#
+            )
+        };
+
+        let unchanged = diff(
+            &[("item.lua", &items("getName(1, 2)"))],
+            &[("item.lua", &items("getName(1,\n 2)"))],
+        );
+        assert!(unchanged.is_empty());
+
+        let changed = diff(
+            &[("item.lua", &items("getName(1)"))],
+            &[("item.lua", &items("getName(2)"))],
+        );
+        assert_eq!(
+            changed.tables[0].rows[0].fields,
+            vec![FieldChange {
+                path: "name".to_string(),
+                old: Some("getName(1)".to_string()),
+                new: Some("getName(2)".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let diff = diff(
+            &[(
+                "item.lua",
+                r#"
+                ---@type TbItem
+                local items = {
+                    [1] = { id = 1, name = "a|b", rewards = {} },
+                }
+                "#,
+            )],
+            &[(
+                "item.lua",
+                r#"
+                ---@type TbItem
+                local items = {
+                    [1] = { id = 1, name = "c", rewards = {} },
+                    [2] = { id = 2, name = "d", rewards = {} },
+                }
+                "#,
+            )],
+        );
+
+        let markdown = render_data_diff_markdown(&diff);
+        assert!(
+            markdown.starts_with("### TbItem (+1 -0 ~1)\n"),
+            "{markdown}"
+        );
+        assert!(
+            markdown.contains("- **modified** `1` (item.lua:4)"),
+            "{markdown}"
+        );
+        assert!(
+            markdown.contains("| `name` | `\"a\\|b\"` | `\"c\"` |"),
+            "{markdown}"
+        );
+        assert!(
+            markdown.contains("- **added** `2` (item.lua:5)"),
+            "{markdown}"
+        );
+
+        let json = data_diff_to_json(&diff);
+        assert_eq!(json[0]["table"], "TbItem");
+        assert_eq!(json[0]["rows"][0]["kind"], "modified");
+        assert_eq!(json[0]["rows"][0]["fields"][0]["new"], "\"c\"");
+        assert_eq!(json[0]["rows"][1]["kind"], "added");
+        assert_eq!(json[0]["rows"][1]["new"]["line"], 5);
+        assert!(json[0]["rows"][1]["old"].is_null());
+    }
+}
@@ -0,0 +1,64 @@
+/// CSV 中的一行记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRecord {
+    /// 记录起始的行号, 从 1 开始
+    pub line: usize,
+    pub cells: Vec<String>,
+}
+
+/// 按 RFC 4180 解析 CSV/TSV, 支持引号内的分隔符、换行与 `""` 转义, 跳过空行
+pub fn parse_csv(text: &str, delimiter: char) -> Vec<CsvRecord> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records = Vec::new();
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    cell.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            '"' if cell.is_empty() => in_quotes = true,
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                cells.push(std::mem::take(&mut cell));
+                push_record(&mut records, record_line, std::mem::take(&mut cells));
+                line += 1;
+                record_line = line;
+            }
+            _ if c == delimiter => cells.push(std::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+
+    if !cell.is_empty() || !cells.is_empty() {
+        cells.push(cell);
+        push_record(&mut records, record_line, cells);
+    }
+    records
+}
+
+fn push_record(records: &mut Vec<CsvRecord>, line: usize, cells: Vec<String>) {
+    if cells.iter().all(|cell| cell.trim().is_empty()) {
+        return;
+    }
+    records.push(CsvRecord { line, cells });
+}
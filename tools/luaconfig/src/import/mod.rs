@@ -0,0 +1,577 @@
+mod csv;
+mod test;
+
+use std::fmt::Write;
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+pub use csv::{CsvRecord, parse_csv};
+
+use emmylua_code_analysis::{
+    DbIndex, LuaMemberOwner, LuaSemanticDeclId, LuaType, LuaTypeDeclId, RenderLevel,
+    attributes::{ConfigTableMode, SepAttribute},
+    get_real_type, humanize_type,
+    shared::luaconfig::{
+        BEAN, CONFIG_TABLE, find_named_enum, find_vector_type, get_vector_components,
+        resolve_enum_name,
+    },
+};
+
+/// 容器字段没有声明 `sep` 时的分隔符
+pub const DEFAULT_SEPARATOR: &str = ",";
+
+/// 导入时发现的错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportError {
+    /// CSV 中的行号, 从 1 开始
+    pub line: usize,
+    /// 单元格所在列的列名, 与整行有关的错误为 `None`
+    pub column: Option<String>,
+    pub message: String,
+}
+
+/// CSV 导入的结果
+#[derive(Debug, Clone, Default)]
+pub struct CsvImport {
+    /// 生成的 Lua 数据文件, 转换失败的单元格不会写入
+    pub source: String,
+    pub rows: usize,
+    pub errors: Vec<ImportError>,
+}
+
+/// 将带表头的 CSV/TSV 按配置表记录的 Bean 字段转换为 Lua 数据文件.
+///
+/// 表头为字段名, 嵌套 Bean 的字段写作 `a.b`. 与 Luban 一致, 首列为 `##var` 等标记时该列不是字段,
+/// 首个单元格以 `##` 开头的数据行视为注释, 列名为空或以 `#` 开头的列被忽略.
+pub fn import_csv(
+    db: &DbIndex,
+    config_table: &str,
+    text: &str,
+    delimiter: char,
+) -> Result<CsvImport, String> {
+    let table_id = LuaTypeDeclId::new(config_table);
+    if CONFIG_TABLE.matches_decl(&table_id) || !CONFIG_TABLE.is_config_table(db, &table_id) {
+        return Err(format!("`{config_table}` is not a config table"));
+    }
+    let bean_id = CONFIG_TABLE
+        .get_bean_id(db, &table_id)
+        .ok_or_else(|| format!("cannot resolve the bean of config table `{config_table}`"))?;
+
+    let mut records = parse_csv(text, delimiter).into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| "missing header row".to_string())?;
+    let has_marker = header
+        .cells
+        .first()
+        .is_some_and(|cell| cell.trim().starts_with("##"));
+
+    let mut errors = Vec::new();
+    let columns = header
+        .cells
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let name = name.trim();
+            if (i == 0 && has_marker) || name.is_empty() || name.starts_with('#') {
+                return None;
+            }
+            match resolve_column(db, &bean_id, name) {
+                Ok(column) => Some(column),
+                Err(message) => {
+                    errors.push(ImportError {
+                        line: header.line,
+                        column: Some(name.to_string()),
+                        message,
+                    });
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let paths = columns
+        .iter()
+        .flatten()
+        .map(|column| column.path.join("."))
+        .collect::<Vec<_>>();
+    check_required_columns(db, &bean_id, "", &paths, header.line, &mut errors);
+
+    let keyed_by = match db.get_config_index().get_config_table_keys(&table_id) {
+        Some(keys)
+            if keys.keys().len() == 1
+                && db.get_config_index().get_config_table_mode(&table_id)
+                    == ConfigTableMode::Map =>
+        {
+            Some(keys.keys()[0].to_path())
+        }
+        _ => None,
+    };
+
+    let mut rows = Vec::new();
+    for record in records {
+        if record
+            .cells
+            .first()
+            .is_some_and(|cell| cell.trim().starts_with("##"))
+        {
+            continue;
+        }
+
+        let mut row = Vec::new();
+        for (i, column) in columns.iter().enumerate() {
+            let Some(column) = column else {
+                continue;
+            };
+            let cell = record.cells.get(i).map(String::as_str).unwrap_or_default();
+            match column.convert(db, cell) {
+                Ok(Some(value)) => insert_value(&mut row, &column.path, value),
+                Ok(None) => {}
+                Err(message) => errors.push(ImportError {
+                    line: record.line,
+                    column: Some(column.name.clone()),
+                    message,
+                }),
+            }
+        }
+
+        let key = match &keyed_by {
+            Some(key_name) => match row.iter().find(|(name, _)| name == key_name) {
+                Some((_, ImportValue::Literal(key))) => Some(key.clone()),
+                _ => {
+                    errors.push(ImportError {
+                        line: record.line,
+                        column: None,
+                        message: format!("primary key `{key_name}` is empty"),
+                    });
+                    continue;
+                }
+            },
+            None => None,
+        };
+        rows.push((key, row));
+    }
+
+    let name = table_id.get_simple_name();
+    let mut source = format!("---@type {config_table}\nlocal {name} = {{\n");
+    for (key, row) in &rows {
+        let value = format_table(row);
+        let _ = match key {
+            Some(key) => writeln!(source, "    [{key}] = {value},"),
+            None => writeln!(source, "    {value},"),
+        };
+    }
+    let _ = write!(source, "}}\n\nreturn {name}\n");
+
+    Ok(CsvImport {
+        source,
+        rows: rows.len(),
+        errors,
+    })
+}
+
+/// 表头中的一列
+struct Column {
+    name: String,
+    /// 字段路径, 嵌套 Bean 的字段有多段
+    path: Vec<String>,
+    field: FieldInfo,
+    /// 路径上存在可以省略的 Bean 字段
+    in_optional_bean: bool,
+}
+
+#[derive(Clone)]
+struct FieldInfo {
+    typ: LuaType,
+    /// 可为空或有默认值
+    optional: bool,
+    separator: String,
+}
+
+impl Column {
+    /// 转换单元格, 省略的字段返回 `None`
+    fn convert(&self, db: &DbIndex, cell: &str) -> Result<Option<String>, String> {
+        if !cell.trim().is_empty() {
+            return convert_value(db, &self.field.typ, &self.field.separator, cell).map(Some);
+        }
+        if self.field.optional || self.in_optional_bean {
+            return Ok(None);
+        }
+
+        let typ = unwrap_optional(&self.field.typ);
+        if find_vector_type(db, &typ).is_none() && get_container_element(&typ).is_some() {
+            return Ok(Some("{}".to_string()));
+        }
+        match get_real_type(db, &typ).unwrap_or(&typ) {
+            LuaType::String => Ok(Some("\"\"".to_string())),
+            _ => Err("required field is empty".to_string()),
+        }
+    }
+}
+
+fn resolve_column(db: &DbIndex, bean_id: &LuaTypeDeclId, name: &str) -> Result<Column, String> {
+    let path = name
+        .split('.')
+        .map(|segment| segment.trim().to_string())
+        .collect::<Vec<_>>();
+    let mut bean_id = bean_id.clone();
+    let mut in_optional_bean = false;
+    for (i, segment) in path.iter().enumerate() {
+        let field = find_field(db, &bean_id, segment)
+            .ok_or_else(|| format!("bean `{}` has no field `{segment}`", bean_id.get_name()))?;
+        if i + 1 == path.len() {
+            return Ok(Column {
+                name: name.to_string(),
+                path,
+                field,
+                in_optional_bean,
+            });
+        }
+
+        in_optional_bean |= field.optional;
+        bean_id = get_bean_type(db, &field.typ)
+            .ok_or_else(|| format!("field `{}` is not a bean", path[..=i].join(".")))?;
+    }
+
+    Err(format!("invalid column name `{name}`"))
+}
+
+/// 检查必填字段都有对应的列, 嵌套 Bean 只在出现其字段的列时检查
+fn check_required_columns(
+    db: &DbIndex,
+    bean_id: &LuaTypeDeclId,
+    prefix: &str,
+    paths: &[String],
+    line: usize,
+    errors: &mut Vec<ImportError>,
+) {
+    for (name, field) in collect_fields(db, bean_id) {
+        let path = format!("{prefix}{name}");
+        if paths.contains(&path) {
+            continue;
+        }
+
+        let nested_prefix = format!("{path}.");
+        if paths
+            .iter()
+            .any(|column| column.starts_with(&nested_prefix))
+        {
+            if let Some(nested_id) = get_bean_type(db, &field.typ) {
+                check_required_columns(db, &nested_id, &nested_prefix, paths, line, errors);
+            }
+        } else if !field.optional {
+            errors.push(ImportError {
+                line,
+                column: None,
+                message: format!("missing column for required field `{path}`"),
+            });
+        }
+    }
+}
+
+/// Bean 的字段, 包括父类的字段
+fn collect_fields(db: &DbIndex, bean_id: &LuaTypeDeclId) -> Vec<(String, FieldInfo)> {
+    let mut fields: Vec<(String, FieldInfo)> = Vec::new();
+    for typ in bean_id.collect_super_types_with_self(db, LuaType::Ref(bean_id.clone())) {
+        let LuaType::Ref(type_id) = typ else {
+            continue;
+        };
+        let Some(mut members) = db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::Type(type_id))
+        else {
+            continue;
+        };
+        members.sort_by_key(|member| member.get_sort_key());
+
+        for member in members {
+            let name = member.get_key().to_path();
+            if name.is_empty() || fields.iter().any(|(field_name, _)| *field_name == name) {
+                continue;
+            }
+
+            let typ = db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())
+                .map(|cache| cache.as_type().clone())
+                .unwrap_or(LuaType::Unknown);
+            let separator = db
+                .get_property_index()
+                .get_property(&LuaSemanticDeclId::Member(member.get_id()))
+                .and_then(|property| {
+                    SepAttribute::find_in(property)?
+                        .get_separator()
+                        .map(str::to_string)
+                })
+                .filter(|separator| !separator.is_empty())
+                .unwrap_or_else(|| DEFAULT_SEPARATOR.to_string());
+            let field = FieldInfo {
+                optional: typ.is_nullable()
+                    || BEAN.get_field_default(db, &member.get_id()).is_some(),
+                typ,
+                separator,
+            };
+            fields.push((name, field));
+        }
+    }
+    fields
+}
+
+fn find_field(db: &DbIndex, bean_id: &LuaTypeDeclId, name: &str) -> Option<FieldInfo> {
+    collect_fields(db, bean_id)
+        .into_iter()
+        .find(|(field_name, _)| field_name == name)
+        .map(|(_, field)| field)
+}
+
+fn get_bean_type(db: &DbIndex, typ: &LuaType) -> Option<LuaTypeDeclId> {
+    match unwrap_optional(typ) {
+        LuaType::Ref(id) | LuaType::Def(id) if BEAN.is_bean(db, &id) => Some(id),
+        _ => None,
+    }
+}
+
+/// 去除特性与 `nil` 后的类型
+fn unwrap_optional(typ: &LuaType) -> LuaType {
+    match typ.strip_attributed() {
+        LuaType::Union(union) => {
+            let types = union
+                .into_vec()
+                .into_iter()
+                .filter(|inner| !inner.is_nil())
+                .collect::<Vec<_>>();
+            match types.as_slice() {
+                [inner] => unwrap_optional(inner),
+                _ => LuaType::from_vec(types),
+            }
+        }
+        typ => typ.clone(),
+    }
+}
+
+fn get_container_element(typ: &LuaType) -> Option<LuaType> {
+    match typ {
+        LuaType::Array(array) => Some(array.get_base().clone()),
+        LuaType::Generic(generic)
+            if matches!(
+                generic.get_base_type_id_ref().get_name(),
+                "array" | "list" | "set"
+            ) =>
+        {
+            generic.get_params().first().cloned()
+        }
+        _ => None,
+    }
+}
+
+/// 将非空单元格转换为 Lua 字面量
+fn convert_value(
+    db: &DbIndex,
+    typ: &LuaType,
+    separator: &str,
+    cell: &str,
+) -> Result<String, String> {
+    let text = cell.trim();
+    let typ = unwrap_optional(typ);
+    if let Some(enum_id) = find_named_enum(db, &typ) {
+        if text.parse::<i64>().is_ok() {
+            return Ok(text.to_string());
+        }
+        return match resolve_enum_name(db, &enum_id, text) {
+            Ok(value) => {
+                format_const(&value).ok_or_else(|| format!("cannot convert enum value `{text}`"))
+            }
+            Err(name) => Err(format!(
+                "`{name}` is not a member of enum `{}`",
+                enum_id.get_name()
+            )),
+        };
+    }
+    if let LuaType::Ref(id) = &typ
+        && id.get_name() == "datetime"
+    {
+        return parse_datetime(text)
+            .map(|seconds| seconds.to_string())
+            .ok_or_else(|| format!("`{text}` is not a datetime"));
+    }
+    if let Some(vector_id) = find_vector_type(db, &typ) {
+        let components = get_vector_components(db, &vector_id).unwrap_or_default();
+        let values = text.split(separator).map(str::trim).collect::<Vec<_>>();
+        if values.len() > components.len() {
+            return Err(format!(
+                "`{}` has at most {} components",
+                vector_id.get_name(),
+                components.len()
+            ));
+        }
+        let values = values
+            .into_iter()
+            .map(parse_number)
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(format!("{{ {} }}", values.join(", ")));
+    }
+    if let Some(element_type) = get_container_element(&typ) {
+        let values = text
+            .split(separator)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| convert_value(db, &element_type, separator, value))
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(if values.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{ {} }}", values.join(", "))
+        });
+    }
+
+    match get_real_type(db, &typ).unwrap_or(&typ) {
+        LuaType::Integer => text
+            .parse::<i64>()
+            .map(|i| i.to_string())
+            .map_err(|_| format!("`{text}` is not an integer")),
+        LuaType::Number => parse_number(text),
+        LuaType::Boolean => match text.to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" => Ok("true".to_string()),
+            "false" | "0" | "no" => Ok("false".to_string()),
+            _ => Err(format!("`{text}` is not a boolean")),
+        },
+        LuaType::String => Ok(format_string(cell)),
+        typ => Err(match get_bean_type(db, typ) {
+            Some(_) => {
+                "bean fields must be imported with nested columns such as `field.x`".to_string()
+            }
+            None => format!(
+                "unsupported field type `{}`",
+                humanize_type(db, typ, RenderLevel::Simple)
+            ),
+        }),
+    }
+}
+
+fn parse_number(text: &str) -> Result<String, String> {
+    if let Ok(i) = text.parse::<i64>() {
+        return Ok(i.to_string());
+    }
+    match text.parse::<f64>() {
+        Ok(f) if f.is_finite() => Ok(f.to_string()),
+        _ => Err(format!("`{text}` is not a number")),
+    }
+}
+
+/// 解析 `datetime`, 返回自 UTC 1970-01-01 00:00:00 以来的秒数
+fn parse_datetime(text: &str) -> Option<i64> {
+    if let Ok(seconds) = text.parse::<i64>() {
+        return Some(seconds);
+    }
+
+    const DATETIME_FORMATS: [&str; 4] = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y/%m/%d %H:%M:%S",
+        "%Y/%m/%d %H:%M",
+    ];
+    const DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%Y/%m/%d"];
+    DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|datetime| datetime.and_utc().timestamp())
+}
+
+fn format_const(value: &LuaType) -> Option<String> {
+    match value {
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => Some(i.to_string()),
+        LuaType::FloatConst(f) => Some(f.to_string()),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => Some(format_string(s)),
+        LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn format_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            _ => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// 记录中的值, 嵌套 Bean 为表
+enum ImportValue {
+    Literal(String),
+    Table(Vec<(String, ImportValue)>),
+}
+
+fn insert_value(entries: &mut Vec<(String, ImportValue)>, path: &[String], value: String) {
+    let [name, rest @ ..] = path else {
+        return;
+    };
+    if rest.is_empty() {
+        entries.push((name.clone(), ImportValue::Literal(value)));
+        return;
+    }
+
+    let index = match entries
+        .iter()
+        .position(|(entry_name, _)| entry_name == name)
+    {
+        Some(index) => index,
+        None => {
+            entries.push((name.clone(), ImportValue::Table(Vec::new())));
+            entries.len() - 1
+        }
+    };
+    if let ImportValue::Table(nested) = &mut entries[index].1 {
+        insert_value(nested, rest, value);
+    }
+}
+
+fn format_table(entries: &[(String, ImportValue)]) -> String {
+    if entries.is_empty() {
+        return "{}".to_string();
+    }
+
+    let entries = entries
+        .iter()
+        .map(|(name, value)| {
+            let value = match value {
+                ImportValue::Literal(literal) => literal.clone(),
+                ImportValue::Table(nested) => format_table(nested),
+            };
+            format!("{} = {value}", format_key(name))
+        })
+        .collect::<Vec<_>>();
+    format!("{{ {} }}", entries.join(", "))
+}
+
+fn format_key(name: &str) -> String {
+    const KEYWORDS: [&str; 22] = [
+        "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "goto", "if",
+        "in", "local", "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+    ];
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&name);
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("[{}]", format_string(name))
+    }
+}
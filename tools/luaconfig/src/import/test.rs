@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use tokio_util::sync::CancellationToken;
+
+    use emmylua_code_analysis::VirtualWorkspace;
+
+    use crate::import::{CsvRecord, ImportError, import_csv, parse_csv};
+
+    const SCHEMA: &str = r#"
+        ---@enum Quality
+        Quality = {
+            White = 1,
+            ---@[alias("绿")]
+            Green = 2,
+        }
+
+        ---@class Reward: Bean
+        ---@field itemId int
+        ---@field count int
+
+        ---@class Item: Bean
+        ---@field id int
+        ---@field name string
+        ---@field quality Quality
+        ---@field price float
+        ---@field tradable boolean
+        ---@field openTime datetime?
+        ---@field pos vector2?
+        ---@field reward Reward?
+        ---@field tags list<string>
+        ---@[sep(";")]
+        ---@field levels list<int>
+        ---@[v.default(1)]
+        ---@field stack int
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+    "#;
+
+    #[test]
+    fn test_parse_csv() {
+        let records = parse_csv("a,\"b,\"\"c\"\"\"\r\n\r\n\"x\ny\",z\n", ',');
+        assert_eq!(
+            records,
+            vec![
+                CsvRecord {
+                    line: 1,
+                    cells: vec!["a".to_string(), "b,\"c\"".to_string()],
+                },
+                CsvRecord {
+                    line: 3,
+                    cells: vec!["x\ny".to_string(), "z".to_string()],
+                },
+            ]
+        );
+        assert_eq!(parse_csv("a\tb,c", '\t')[0].cells, vec!["a", "b,c"]);
+    }
+
+    #[test]
+    fn test_import() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(SCHEMA);
+
+        let csv = "\
+##var,id,name,quality,price,tradable,openTime,pos,reward.itemId,reward.count,tags,levels
+##type,int,string,Quality,float,bool,datetime,vector2,int,int,list,list
+,1,Sword,Green,1.5,true,2024-01-02 03:04:05,\"1,2\",1001,2,\"a,b\",1;2;3
+,2,\"Bow \"\"X\"\"\",White,3,0,,,,,,
+";
+        let result = import_csv(ws.analysis.compilation.get_db(), "TbItem", csv, ',').unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(result.rows, 2);
+        assert_eq!(
+            result.source,
+            r#"---@type TbItem
+local TbItem = {
+    [1] = { id = 1, name = "Sword", quality = 2, price = 1.5, tradable = true, openTime = 1704164645, pos = { 1, 2 }, reward = { itemId = 1001, count = 2 }, tags = { "a", "b" }, levels = { 1, 2, 3 } },
+    [2] = { id = 2, name = "Bow \"X\"", quality = 1, price = 3, tradable = false, tags = {}, levels = {} },
+}
+
+return TbItem
+"#
+        );
+
+        // 生成的数据文件可以通过校验
+        let file_id = ws.def_file("data/item.lua", &result.source);
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_import_errors() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(SCHEMA);
+
+        let csv = "\
+id,name,quality,price,tradable,levels,unknown
+1,Sword,Red,cheap,maybe,1;x
+,Bow,White,1,true,
+";
+        let result = import_csv(ws.analysis.compilation.get_db(), "TbItem", csv, ',').unwrap();
+        let error = |line: usize, column: Option<&str>, message: &str| ImportError {
+            line,
+            column: column.map(str::to_string),
+            message: message.to_string(),
+        };
+        assert_eq!(
+            result.errors,
+            vec![
+                error(1, Some("unknown"), "bean `Item` has no field `unknown`"),
+                error(1, None, "missing column for required field `tags`"),
+                error(
+                    2,
+                    Some("quality"),
+                    "`Red` is not a member of enum `Quality`"
+                ),
+                error(2, Some("price"), "`cheap` is not a number"),
+                error(2, Some("tradable"), "`maybe` is not a boolean"),
+                error(2, Some("levels"), "`x` is not an integer"),
+                error(3, Some("id"), "required field is empty"),
+                error(3, None, "primary key `id` is empty"),
+            ]
+        );
+        assert_eq!(result.rows, 1);
+
+        assert!(import_csv(ws.analysis.compilation.get_db(), "Item", csv, ',').is_err());
+    }
+}
@@ -0,0 +1,6 @@
+//! 配置表工具的实现: CSV 导入、结构与数据比较、代码生成
+
+pub mod codegen;
+pub mod data_diff;
+pub mod import;
+pub mod schema;
@@ -0,0 +1,103 @@
+//! 配置表工具
+//!
+//! - `schema-diff`: 比较两个工作区 (如两个 git worktree) 中配置表与 Bean 的定义, 报告不兼容的变化与受影响的数据,
+//!   存在不兼容的变化时以状态码 1 退出
+//! - `data-diff`: 按主键比较两个工作区中的配置数据, 报告新增、删除与修改的记录, 可输出 Markdown 或 JSON
+//! - `import-csv`: 将 CSV/TSV 按配置表的字段类型导入为 Lua 数据文件, 并校验生成的文件
+//! - `gen-loader`: 生成按需加载配置数据并带有类型注解的 Lua 访问器模块, 模块名为输出文件名
+//! - `gen-types`: 生成 C# (`.cs`) 或 TypeScript (`.ts`) 类型定义, C# 的命名空间为输出文件名
+//!
+//! 运行: `cargo run -p luaconfig -- <command> ...`
+
+mod cmd;
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+};
+
+use cmd::{data_diff, gen_loader, gen_types, import_csv, schema_diff};
+use emmylua_code_analysis::{EmmyLuaAnalysis, load_configs, load_workspace_files};
+
+const USAGE: &str = "usage:
+  luaconfig schema-diff <old_root> <new_root>
+  luaconfig data-diff <old_root> <new_root> [--format markdown|json]
+  luaconfig import-csv <root> <csv> <config_table> [-o <output.lua>]
+  luaconfig gen-loader <root> <output.lua>
+  luaconfig gen-types <root> <output.cs|output.ts>";
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let exit_code = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["schema-diff", old_root, new_root] => {
+            Some(schema_diff::run(Path::new(old_root), Path::new(new_root)))
+        }
+        ["data-diff", old_root, new_root] => Some(data_diff::run(
+            Path::new(old_root),
+            Path::new(new_root),
+            data_diff::Format::Markdown,
+        )),
+        ["data-diff", old_root, new_root, "--format", format] => data_diff::Format::parse(format)
+            .map(|format| data_diff::run(Path::new(old_root), Path::new(new_root), format)),
+        ["import-csv", root, csv, config_table] => Some(import_csv::run(
+            Path::new(root),
+            Path::new(csv),
+            config_table,
+            None,
+        )),
+        ["import-csv", root, csv, config_table, "-o", output] => Some(import_csv::run(
+            Path::new(root),
+            Path::new(csv),
+            config_table,
+            Some(Path::new(output)),
+        )),
+        ["gen-loader", root, output] => Some(gen_loader::run(Path::new(root), Path::new(output))),
+        ["gen-types", root, output] => Some(gen_types::run(Path::new(root), Path::new(output))),
+        _ => None,
+    };
+
+    exit_code.unwrap_or_else(|| {
+        eprintln!("{USAGE}");
+        ExitCode::from(2)
+    })
+}
+
+pub fn load_analysis(root: &Path) -> EmmyLuaAnalysis {
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let config_files = [".luarc.json", ".emmyrc.json"]
+        .into_iter()
+        .map(|name| root.join(name))
+        .filter(|path| path.exists())
+        .collect();
+    let mut emmyrc = load_configs(config_files, None);
+    emmyrc.pre_process_emmyrc(&root);
+
+    let mut analysis = EmmyLuaAnalysis::new();
+    analysis.update_config(Arc::new(emmyrc));
+    analysis.init_std_lib(None);
+    analysis.add_main_workspace(root.clone());
+
+    let emmyrc = analysis.emmyrc.clone();
+    let exclude_dir = emmyrc
+        .workspace
+        .ignore_dir
+        .iter()
+        .map(PathBuf::from)
+        .collect::<Vec<_>>();
+    let files = load_workspace_files(
+        &root,
+        &["**/*.lua".to_string()],
+        &emmyrc.workspace.ignore_globs,
+        &exclude_dir,
+        Some(&emmyrc.workspace.encoding),
+    )
+    .unwrap_or_default();
+    analysis.update_files_by_path(files.into_iter().map(|file| file.into_tuple()).collect());
+    analysis
+}
@@ -0,0 +1,472 @@
+use rowan::TextRange;
+
+use emmylua_code_analysis::{
+    ConfigValue, ConfigValueNode, DbIndex, FileId, LuaMemberKey, LuaTypeDeclId,
+    attributes::{RangeEnd, RangeSpec, SetSpec, SetValue, SizeSpec},
+};
+
+use super::{BeanSchema, ConfigSchema, FieldSchema, TableSchema};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SchemaChangeKind {
+    /// 已有数据可能不再合法
+    Breaking,
+    Compatible,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaChange {
+    pub kind: SchemaChangeKind,
+    /// 发生变化的配置表、Bean 或字段, 如 `TbItem`、`Item.count`
+    pub subject: String,
+    pub message: String,
+    /// 受影响的数据记录, 只有不兼容的变化会收集
+    pub affected_rows: Vec<AffectedRow>,
+}
+
+/// 受结构变化影响的一条记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AffectedRow {
+    pub file_id: FileId,
+    pub config_table: LuaTypeDeclId,
+    pub key: LuaMemberKey,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaDiff {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiff {
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.kind == SchemaChangeKind::Breaking)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// 比较两个版本的结构, `db` 为新版本的分析结果, 用于查找受影响的数据记录
+pub fn diff_config_schema(old: &ConfigSchema, new: &ConfigSchema, db: &DbIndex) -> SchemaDiff {
+    let mut differ = SchemaDiffer {
+        db,
+        new,
+        changes: Vec::new(),
+    };
+
+    for (name, old_table) in &old.tables {
+        match new.tables.get(name) {
+            Some(new_table) => differ.diff_table(old_table, new_table),
+            None => differ.add_table_change(name, "config table removed".to_string()),
+        }
+    }
+    for name in new.tables.keys() {
+        if !old.tables.contains_key(name) {
+            differ.add_compatible(name, "config table added".to_string());
+        }
+    }
+
+    for (name, old_bean) in &old.beans {
+        match new.beans.get(name) {
+            Some(new_bean) => differ.diff_bean(old_bean, new_bean),
+            None => differ.add_removed_bean(old, name),
+        }
+    }
+    for name in new.beans.keys() {
+        if !old.beans.contains_key(name) {
+            differ.add_compatible(name, "bean added".to_string());
+        }
+    }
+
+    let mut changes = differ.changes;
+    changes.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.subject.cmp(&b.subject)));
+    SchemaDiff { changes }
+}
+
+/// 筛选受字段变化影响的记录
+enum RowFilter<'a> {
+    FieldPresent,
+    FieldMissing,
+    OutOfRange(&'a RangeSpec),
+    OutOfSize(&'a SizeSpec),
+    NotInSet(&'a SetSpec),
+}
+
+struct SchemaDiffer<'a> {
+    db: &'a DbIndex,
+    new: &'a ConfigSchema,
+    changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiffer<'_> {
+    fn diff_table(&mut self, old: &TableSchema, new: &TableSchema) {
+        let name = &new.name;
+        if old.bean != new.bean {
+            self.add_table_change(
+                name,
+                format!(
+                    "row bean changed from `{}` to `{}`",
+                    old.bean.as_deref().unwrap_or("?"),
+                    new.bean.as_deref().unwrap_or("?")
+                ),
+            );
+        }
+        if old.index != new.index || (old.index.len() > 1 && old.solo != new.solo) {
+            self.add_table_change(
+                name,
+                format!(
+                    "`t.index` changed from {} to {}",
+                    format_index(old),
+                    format_index(new)
+                ),
+            );
+        }
+        if old.mode != new.mode {
+            self.add_table_change(
+                name,
+                format!("`t.mode` changed from `{:?}` to `{:?}`", old.mode, new.mode),
+            );
+        }
+    }
+
+    fn diff_bean(&mut self, old: &BeanSchema, new: &BeanSchema) {
+        let bean = &new.name;
+        for (name, old_field) in &old.fields {
+            match new.fields.get(name) {
+                Some(new_field) => self.diff_field(bean, old_field, new_field),
+                None => self.add_bean_change(
+                    bean,
+                    name,
+                    RowFilter::FieldPresent,
+                    "field removed".to_string(),
+                ),
+            }
+        }
+
+        for (name, new_field) in &new.fields {
+            if old.fields.contains_key(name) {
+                continue;
+            }
+            if new_field.is_required() {
+                self.add_bean_change(
+                    bean,
+                    name,
+                    RowFilter::FieldMissing,
+                    format!("required field added with type `{}`", new_field.typ),
+                );
+            } else {
+                self.add_compatible(
+                    &format!("{bean}.{name}"),
+                    format!("optional field added with type `{}`", new_field.typ),
+                );
+            }
+        }
+    }
+
+    fn diff_field(&mut self, bean: &str, old: &FieldSchema, new: &FieldSchema) {
+        let name = new.name.as_str();
+        let subject = format!("{bean}.{}", new.name);
+
+        if old.typ != new.typ {
+            let message = format!("type changed from `{}` to `{}`", old.typ, new.typ);
+            if is_widening(&old.typ, &new.typ) {
+                self.add_compatible(&subject, message);
+            } else {
+                self.add_bean_change(bean, name, RowFilter::FieldPresent, message);
+            }
+        }
+
+        if old.is_required() != new.is_required() {
+            if new.is_required() {
+                self.add_bean_change(
+                    bean,
+                    name,
+                    RowFilter::FieldMissing,
+                    "field became required".to_string(),
+                );
+            } else {
+                self.add_compatible(&subject, "field became optional".to_string());
+            }
+        }
+
+        if old.range != new.range {
+            let message = format!(
+                "`v.range` changed from {} to {}",
+                format_spec(old.range.as_ref()),
+                format_spec(new.range.as_ref())
+            );
+            match &new.range {
+                Some(range) if !range_covers(Some(range), old.range.as_ref()) => {
+                    self.add_bean_change(bean, name, RowFilter::OutOfRange(range), message)
+                }
+                _ => self.add_compatible(&subject, message),
+            }
+        }
+
+        if old.size != new.size {
+            let message = format!(
+                "`v.size` changed from {} to {}",
+                format_spec(old.size.as_ref()),
+                format_spec(new.size.as_ref())
+            );
+            match &new.size {
+                Some(size)
+                    if !range_covers(
+                        Some(size.as_range()),
+                        old.size.as_ref().map(SizeSpec::as_range),
+                    ) =>
+                {
+                    self.add_bean_change(bean, name, RowFilter::OutOfSize(size), message)
+                }
+                _ => self.add_compatible(&subject, message),
+            }
+        }
+
+        if old.set != new.set {
+            let message = format!(
+                "`v.set` changed from {} to {}",
+                format_spec(old.set.as_ref()),
+                format_spec(new.set.as_ref())
+            );
+            let is_tightened = match (&old.set, &new.set) {
+                (_, None) => false,
+                (None, Some(_)) => true,
+                (Some(old_set), Some(new_set)) => !old_set.values().is_subset(new_set.values()),
+            };
+            match &new.set {
+                Some(set) if is_tightened => {
+                    self.add_bean_change(bean, name, RowFilter::NotInSet(set), message)
+                }
+                _ => self.add_compatible(&subject, message),
+            }
+        }
+
+        if old.ref_table != new.ref_table {
+            let message = format!(
+                "`v.ref` changed from {} to {}",
+                format_spec(old.ref_table.as_ref()),
+                format_spec(new.ref_table.as_ref())
+            );
+            if new.ref_table.is_some() {
+                self.add_bean_change(bean, name, RowFilter::FieldPresent, message);
+            } else {
+                self.add_compatible(&subject, message);
+            }
+        }
+    }
+
+    fn add_compatible(&mut self, subject: &str, message: String) {
+        self.changes.push(SchemaChange {
+            kind: SchemaChangeKind::Compatible,
+            subject: subject.to_string(),
+            message,
+            affected_rows: Vec::new(),
+        });
+    }
+
+    /// 配置表的变化影响该表的全部记录
+    fn add_table_change(&mut self, table: &str, message: String) {
+        let affected_rows = self.collect_table_rows(table);
+        self.push_breaking(table.to_string(), message, affected_rows);
+    }
+
+    /// 新版本中已经没有该 Bean 的数据, 按旧版本中以它为记录类型的配置表查找记录
+    fn add_removed_bean(&mut self, old: &ConfigSchema, bean: &str) {
+        let affected_rows = old
+            .tables
+            .values()
+            .filter(|table| table.bean.as_deref() == Some(bean))
+            .flat_map(|table| self.collect_table_rows(&table.name))
+            .collect();
+        self.push_breaking(bean.to_string(), "bean removed".to_string(), affected_rows);
+    }
+
+    fn collect_table_rows(&self, table: &str) -> Vec<AffectedRow> {
+        let config_table = LuaTypeDeclId::new(table);
+        self.db
+            .get_config_index()
+            .iter_config_table_rows(&config_table)
+            .map(|(file_id, row)| AffectedRow {
+                file_id,
+                config_table: config_table.clone(),
+                key: row.key.clone(),
+                range: row.range,
+            })
+            .collect()
+    }
+
+    fn add_bean_change(&mut self, bean: &str, field: &str, filter: RowFilter, message: String) {
+        let field_key = LuaMemberKey::Name(field.into());
+        let mut affected_rows = Vec::new();
+        for table in self.new.tables.keys() {
+            let config_table = LuaTypeDeclId::new(table);
+            for (file_id, row) in self
+                .db
+                .get_config_index()
+                .iter_config_table_rows(&config_table)
+            {
+                if contains_affected_bean(&row.value, bean, &field_key, &filter) {
+                    affected_rows.push(AffectedRow {
+                        file_id,
+                        config_table: config_table.clone(),
+                        key: row.key.clone(),
+                        range: row.range,
+                    });
+                }
+            }
+        }
+
+        self.push_breaking(format!("{bean}.{field}"), message, affected_rows);
+    }
+
+    fn push_breaking(&mut self, subject: String, message: String, mut rows: Vec<AffectedRow>) {
+        rows.sort_by_key(|row| (row.file_id, row.range.start()));
+        self.changes.push(SchemaChange {
+            kind: SchemaChangeKind::Breaking,
+            subject,
+            message,
+            affected_rows: rows,
+        });
+    }
+}
+
+/// 值中是否有指定 Bean 的数据满足筛选条件, 包括嵌套的 Bean
+fn contains_affected_bean(
+    node: &ConfigValueNode,
+    bean: &str,
+    field: &LuaMemberKey,
+    filter: &RowFilter,
+) -> bool {
+    if node
+        .value
+        .get_bean()
+        .is_some_and(|id| id.get_name() == bean)
+    {
+        let value = node.value.get(field);
+        let is_affected = match filter {
+            RowFilter::FieldPresent => value.is_some(),
+            RowFilter::FieldMissing => value.is_none(),
+            RowFilter::OutOfRange(range) => value.is_some_and(|value| {
+                collect_leaves(&value.value)
+                    .iter()
+                    .filter_map(|leaf| leaf.as_number())
+                    .any(|number| !range.contains(number))
+            }),
+            RowFilter::OutOfSize(size) => value.is_some_and(|value| {
+                value.value.is_table() && !size.contains_len(value.value.get_entries().len())
+            }),
+            RowFilter::NotInSet(set) => value.is_some_and(|value| {
+                collect_leaves(&value.value)
+                    .iter()
+                    .filter_map(|leaf| to_set_value(leaf))
+                    .any(|set_value| !set.contains(&set_value))
+            }),
+        };
+        if is_affected {
+            return true;
+        }
+    }
+
+    node.value
+        .get_entries()
+        .iter()
+        .any(|entry| contains_affected_bean(&entry.value, bean, field, filter))
+}
+
+/// 值中的全部标量, 容器与向量的约束作用于每个元素
+fn collect_leaves(value: &ConfigValue) -> Vec<&ConfigValue> {
+    match value {
+        ConfigValue::Table { entries, .. } => entries
+            .iter()
+            .flat_map(|entry| collect_leaves(&entry.value.value))
+            .collect(),
+        _ => vec![value],
+    }
+}
+
+fn to_set_value(value: &ConfigValue) -> Option<SetValue> {
+    match value {
+        ConfigValue::Integer(i) => Some(SetValue::Int(*i)),
+        ConfigValue::String(s) => Some(SetValue::String(s.to_string())),
+        _ => None,
+    }
+}
+
+/// `outer` 是否包含 `inner` 的全部取值, `None` 表示不限制
+fn range_covers(outer: Option<&RangeSpec>, inner: Option<&RangeSpec>) -> bool {
+    let Some(outer) = outer else {
+        return true;
+    };
+    let Some(inner) = inner else {
+        return false;
+    };
+
+    let min_covered = match (outer.min, inner.min) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(outer_min), Some(inner_min)) => {
+            outer_min < inner_min
+                || (outer_min == inner_min
+                    && (outer.min_end == RangeEnd::Closed || inner.min_end == RangeEnd::Open))
+        }
+    };
+    let max_covered = match (outer.max, inner.max) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(outer_max), Some(inner_max)) => {
+            outer_max > inner_max
+                || (outer_max == inner_max
+                    && (outer.max_end == RangeEnd::Closed || inner.max_end == RangeEnd::Open))
+        }
+    };
+    min_covered && max_covered
+}
+
+/// 数值类型按取值范围排序
+fn numeric_rank(typ: &str) -> Option<(u8, u8)> {
+    match typ {
+        "byte" => Some((0, 1)),
+        "short" => Some((0, 2)),
+        "int" | "integer" => Some((0, 3)),
+        "long" => Some((0, 4)),
+        "float" => Some((1, 1)),
+        "double" | "number" => Some((1, 2)),
+        _ => None,
+    }
+}
+
+/// 同类数值类型可以放宽; 整数只有不超过 `int` 时才能无损放宽为 `double`
+fn is_widening(old: &str, new: &str) -> bool {
+    match (numeric_rank(old), numeric_rank(new)) {
+        (Some((old_kind, old_rank)), Some((new_kind, new_rank))) if old_kind == new_kind => {
+            old_rank < new_rank
+        }
+        (Some((0, old_rank)), Some((1, 2))) => old_rank <= 3,
+        _ => false,
+    }
+}
+
+fn format_index(table: &TableSchema) -> String {
+    let keys = table
+        .index
+        .iter()
+        .map(|key| format!("`{key}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if table.index.len() > 1 && table.solo {
+        format!("[{keys}] (solo)")
+    } else {
+        format!("[{keys}]")
+    }
+}
+
+fn format_spec<T: std::fmt::Display>(spec: Option<&T>) -> String {
+    match spec {
+        Some(spec) => format!("`{spec}`"),
+        None => "none".to_string(),
+    }
+}
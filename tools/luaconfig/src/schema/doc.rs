@@ -0,0 +1,93 @@
+use emmylua_code_analysis::{LuaAttributeUse, LuaCommonProperty, LuaType};
+
+/// 注释: `---@field`/`---@class` 的描述与声明的特性, 如 `v.range("[1,10]")`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Doc {
+    pub description: Option<String>,
+    pub attributes: Vec<String>,
+}
+
+impl Doc {
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.attributes.is_empty()
+    }
+}
+
+pub(super) fn collect_doc(property: Option<&LuaCommonProperty>) -> Doc {
+    let Some(property) = property else {
+        return Doc::default();
+    };
+    Doc {
+        description: property
+            .description()
+            .map(|description| description.trim().to_string())
+            .filter(|description| !description.is_empty()),
+        attributes: property
+            .attribute_uses()
+            .map(|uses| uses.iter().map(format_attribute).collect())
+            .unwrap_or_default(),
+    }
+}
+
+/// 字段的注释, 包括字段类型上声明的特性, 如 `([v.size(4)] list<int>)`
+pub(super) fn collect_field_doc(property: Option<&LuaCommonProperty>, typ: &LuaType) -> Doc {
+    let mut doc = collect_doc(property);
+    doc.attributes
+        .extend(collect_type_attributes(typ).iter().map(format_attribute));
+    doc
+}
+
+fn collect_type_attributes(typ: &LuaType) -> Vec<LuaAttributeUse> {
+    match typ {
+        LuaType::Attributed(attributed) => {
+            let mut uses = attributed.get_attributes().as_ref().to_vec();
+            uses.extend(collect_type_attributes(attributed.get_base()));
+            uses
+        }
+        LuaType::Union(union) => union
+            .into_vec()
+            .iter()
+            .flat_map(collect_type_attributes)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn format_attribute(attribute_use: &LuaAttributeUse) -> String {
+    let name = attribute_use.id.get_name();
+    if attribute_use.args.is_empty() {
+        return name.to_string();
+    }
+
+    let args = attribute_use
+        .args
+        .iter()
+        .map(|(_, arg)| match arg {
+            Some(LuaType::DocStringConst(s) | LuaType::StringConst(s)) => {
+                format!("{:?}", s.as_str())
+            }
+            Some(LuaType::Tuple(tuple)) => format!(
+                "[{}]",
+                tuple
+                    .get_types()
+                    .iter()
+                    .map(format_attribute_arg)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Some(arg) => format_attribute_arg(arg),
+            None => "nil".to_string(),
+        })
+        .collect::<Vec<_>>();
+    format!("{name}({})", args.join(", "))
+}
+
+fn format_attribute_arg(arg: &LuaType) -> String {
+    match arg {
+        LuaType::DocStringConst(s) | LuaType::StringConst(s) => format!("{:?}", s.as_str()),
+        LuaType::DocIntegerConst(i) | LuaType::IntegerConst(i) => i.to_string(),
+        LuaType::FloatConst(f) => f.to_string(),
+        LuaType::DocBooleanConst(b) | LuaType::BooleanConst(b) => b.to_string(),
+        _ => "?".to_string(),
+    }
+}
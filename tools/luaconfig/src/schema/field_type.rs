@@ -0,0 +1,99 @@
+use emmylua_code_analysis::{
+    DbIndex, LuaType, RenderLevel, humanize_type,
+    shared::luaconfig::{BEAN, find_named_enum},
+};
+
+/// 字段类型, 保留 luaconfig 的标量别名, 枚举与 Bean 为其 Lua 类型名
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    Bool,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    /// 自 UTC 1970-01-01 00:00:00 以来的秒数
+    DateTime,
+    Enum(String),
+    Bean(String),
+    Array(Box<FieldType>),
+    List(Box<FieldType>),
+    Set(Box<FieldType>),
+    Map(Box<FieldType>, Box<FieldType>),
+    /// 无法对应的类型, 保留其 Lua 类型名
+    Unknown(String),
+}
+
+impl FieldType {
+    pub(super) fn from_lua_type(db: &DbIndex, typ: &LuaType) -> Self {
+        let convert = |inner: &LuaType| Box::new(Self::from_lua_type(db, inner));
+        match typ {
+            LuaType::Attributed(attributed) => Self::from_lua_type(db, attributed.get_base()),
+            LuaType::Union(union) => {
+                let types = union
+                    .into_vec()
+                    .into_iter()
+                    .filter(|inner| !inner.is_nil())
+                    .collect::<Vec<_>>();
+                match types.as_slice() {
+                    [inner] => Self::from_lua_type(db, inner),
+                    _ => Self::Unknown(humanize_type(db, typ, RenderLevel::Simple)),
+                }
+            }
+            LuaType::Boolean => Self::Bool,
+            LuaType::Integer => Self::Int,
+            LuaType::Number => Self::Double,
+            LuaType::String => Self::String,
+            LuaType::Array(array) => Self::Array(convert(array.get_base())),
+            LuaType::TableGeneric(params) => match params.as_slice() {
+                [key, value] => Self::Map(convert(key), convert(value)),
+                _ => Self::Unknown(humanize_type(db, typ, RenderLevel::Simple)),
+            },
+            LuaType::Generic(generic) => match (
+                generic.get_base_type_id_ref().get_name(),
+                generic.get_params().as_slice(),
+            ) {
+                ("array", [element]) => Self::Array(convert(element)),
+                ("list", [element]) => Self::List(convert(element)),
+                ("set", [element]) => Self::Set(convert(element)),
+                ("map" | "table", [key, value]) => Self::Map(convert(key), convert(value)),
+                _ => Self::Unknown(humanize_type(db, typ, RenderLevel::Simple)),
+            },
+            LuaType::Ref(id) | LuaType::Def(id) => match id.get_name() {
+                "byte" => Self::Byte,
+                "short" => Self::Short,
+                "long" => Self::Long,
+                "float" => Self::Float,
+                "double" => Self::Double,
+                "datetime" => Self::DateTime,
+                name if find_named_enum(db, typ).is_some() => Self::Enum(name.to_string()),
+                name if BEAN.is_bean(db, id) => Self::Bean(name.to_string()),
+                name => match db.get_type_index().get_type_decl(id) {
+                    Some(type_decl) if type_decl.is_alias() => match type_decl.get_alias_ref() {
+                        Some(origin) => Self::from_lua_type(db, origin),
+                        None => Self::Unknown(name.to_string()),
+                    },
+                    _ => Self::Unknown(name.to_string()),
+                },
+            },
+            _ => Self::Unknown(humanize_type(db, typ, RenderLevel::Simple)),
+        }
+    }
+
+    /// 类型中引用的枚举名
+    pub(super) fn collect_enums<'a>(&'a self, enums: &mut Vec<&'a str>) {
+        match self {
+            Self::Enum(name) => enums.push(name),
+            Self::Array(element) | Self::List(element) | Self::Set(element) => {
+                element.collect_enums(enums)
+            }
+            Self::Map(key, value) => {
+                key.collect_enums(enums);
+                value.collect_enums(enums);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,344 @@
+mod diff;
+mod doc;
+mod field_type;
+mod test;
+
+use std::collections::{BTreeMap, HashSet};
+
+pub use diff::{AffectedRow, SchemaChange, SchemaChangeKind, SchemaDiff, diff_config_schema};
+pub use doc::Doc;
+pub use field_type::FieldType;
+
+use emmylua_code_analysis::{
+    DbIndex, LuaAttributeUse, LuaCommonProperty, LuaMemberOwner, LuaSemanticDeclId, LuaType,
+    LuaTypeDeclId, RenderLevel,
+    attributes::{
+        ConfigTableMode, RangeSpec, SetSpec, SizeSpec, VRangeAttribute, VRefAttribute,
+        VSetAttribute, VSizeAttribute, is_flags_attribute,
+    },
+    humanize_type,
+    shared::luaconfig::{BEAN, CONFIG_TABLE},
+};
+
+use doc::{collect_doc, collect_field_doc};
+
+/// 工作区中全部配置表与 Bean 的结构, 用于比较两个版本的定义与生成其他语言的类型
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigSchema {
+    /// Bean 字段中用到的枚举
+    pub enums: BTreeMap<String, EnumSchema>,
+    pub beans: BTreeMap<String, BeanSchema>,
+    pub tables: BTreeMap<String, TableSchema>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumSchema {
+    pub name: String,
+    pub doc: Doc,
+    /// 带有 `flags`
+    pub flags: bool,
+    /// 按声明顺序
+    pub members: Vec<EnumMemberSchema>,
+}
+
+impl EnumSchema {
+    /// 存在字符串值的成员
+    pub fn is_string_enum(&self) -> bool {
+        self.members
+            .iter()
+            .any(|member| matches!(member.value, EnumValue::String(_)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumMemberSchema {
+    pub name: String,
+    pub value: EnumValue,
+    pub doc: Doc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumValue {
+    Integer(i64),
+    String(String),
+}
+
+/// Bean 的结构, 包括继承的字段
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeanSchema {
+    pub name: String,
+    pub doc: Doc,
+    /// 除 `Bean` 外的父类
+    pub parent: Option<String>,
+    pub fields: BTreeMap<String, FieldSchema>,
+    /// 自身声明的字段名, 按声明顺序
+    pub declared_fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSchema {
+    pub name: String,
+    /// 去除 `nil` 后的字段类型
+    pub typ: String,
+    pub field_type: FieldType,
+    /// 类型可为空
+    pub nullable: bool,
+    /// 带有 `v.default`
+    pub has_default: bool,
+    pub range: Option<RangeSpec>,
+    pub size: Option<SizeSpec>,
+    pub set: Option<SetSpec>,
+    /// `v.ref` 引用的配置表
+    pub ref_table: Option<String>,
+    pub doc: Doc,
+}
+
+impl FieldSchema {
+    /// 数据中必须出现的字段
+    pub fn is_required(&self) -> bool {
+        !self.nullable && !self.has_default
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableSchema {
+    pub name: String,
+    pub bean: Option<String>,
+    /// `t.index` 解析后的主键字段
+    pub index: Vec<String>,
+    /// 多个主键时是否为独立索引
+    pub solo: bool,
+    pub mode: ConfigTableMode,
+    pub doc: Doc,
+}
+
+impl ConfigSchema {
+    /// 从分析结果中收集配置表与 Bean 的结构
+    pub fn collect(db: &DbIndex) -> Self {
+        let mut schema = Self::default();
+        for type_decl in db.get_type_index().get_all_types() {
+            if !type_decl.is_class() {
+                continue;
+            }
+            let id = type_decl.get_id();
+            if BEAN.matches_decl(&id) || CONFIG_TABLE.matches_decl(&id) {
+                continue;
+            }
+
+            if BEAN.is_bean(db, &id) {
+                schema
+                    .beans
+                    .insert(id.get_name().to_string(), collect_bean(db, &id));
+            } else if CONFIG_TABLE.is_config_table(db, &id) {
+                schema
+                    .tables
+                    .insert(id.get_name().to_string(), collect_table(db, &id));
+            }
+        }
+
+        let mut enums = Vec::new();
+        for field in schema.beans.values().flat_map(|bean| bean.fields.values()) {
+            field.field_type.collect_enums(&mut enums);
+        }
+        let enums = enums
+            .into_iter()
+            .map(|name| {
+                (
+                    name.to_string(),
+                    collect_enum(db, &LuaTypeDeclId::new(name)),
+                )
+            })
+            .collect();
+        schema.enums = enums;
+        schema
+    }
+
+    /// `map` 模式以单个主键为键时, 主键字段的类型
+    pub fn get_map_key_type(&self, table: &TableSchema) -> Option<&FieldType> {
+        match table.index.as_slice() {
+            [key] if table.mode != ConfigTableMode::List => self
+                .beans
+                .get(table.bean.as_deref()?)?
+                .fields
+                .get(key)
+                .map(|field| &field.field_type),
+            _ => None,
+        }
+    }
+}
+
+fn collect_table(db: &DbIndex, id: &LuaTypeDeclId) -> TableSchema {
+    let config_index = db.get_config_index();
+    let index_keys = config_index.get_config_table_keys(id);
+    TableSchema {
+        name: id.get_name().to_string(),
+        bean: CONFIG_TABLE
+            .get_bean_id(db, id)
+            .map(|bean_id| bean_id.get_name().to_string()),
+        index: index_keys
+            .map(|keys| keys.keys().iter().map(|key| key.to_path()).collect())
+            .unwrap_or_default(),
+        solo: index_keys.is_some_and(|keys| keys.is_solo()),
+        mode: config_index.get_config_table_mode(id),
+        doc: collect_doc(type_property(db, id)),
+    }
+}
+
+/// 子类中的字段优先于父类中的同名字段
+fn collect_bean(db: &DbIndex, id: &LuaTypeDeclId) -> BeanSchema {
+    let mut fields = BTreeMap::new();
+    let mut visited = HashSet::new();
+    for typ in id.collect_super_types_with_self(db, LuaType::Ref(id.clone())) {
+        let LuaType::Ref(type_id) = typ else {
+            continue;
+        };
+        let Some(members) = db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::Type(type_id))
+        else {
+            continue;
+        };
+
+        for member in members {
+            let name = member.get_key().to_path();
+            if name.is_empty() || !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let typ = db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())
+                .map(|cache| cache.as_type().clone())
+                .unwrap_or(LuaType::Unknown);
+            let property = db
+                .get_property_index()
+                .get_property(&LuaSemanticDeclId::Member(member.get_id()));
+            let field = FieldSchema {
+                name: name.clone(),
+                typ: humanize_type(db, &strip_nil(typ.strip_attributed()), RenderLevel::Simple),
+                field_type: FieldType::from_lua_type(db, &typ),
+                nullable: typ.is_nullable(),
+                has_default: BEAN.get_field_default(db, &member.get_id()).is_some(),
+                range: find_type_spec(&typ, &|uses| {
+                    VRangeAttribute::find_in_uses(uses)?.parse().ok()
+                })
+                .or_else(|| VRangeAttribute::find_in(property?)?.parse().ok()),
+                size: find_type_spec(&typ, &|uses| {
+                    VSizeAttribute::find_in_uses(uses)?.parse().ok()
+                }),
+                set: find_type_spec(&typ, &|uses| {
+                    VSetAttribute::find_in_uses(uses)?.parse().ok()
+                }),
+                ref_table: find_type_spec(&typ, &|uses| {
+                    VRefAttribute::find_in_uses(uses)?
+                        .get_table_name()
+                        .map(str::to_string)
+                })
+                .or_else(|| {
+                    VRefAttribute::find_in(property?)?
+                        .get_table_name()
+                        .map(str::to_string)
+                }),
+                doc: collect_field_doc(property, &typ),
+            };
+            fields.insert(name, field);
+        }
+    }
+
+    let parent = db
+        .get_type_index()
+        .get_super_types(id)
+        .unwrap_or_default()
+        .into_iter()
+        .find_map(|super_type| match super_type {
+            LuaType::Ref(super_id) if !BEAN.matches_decl(&super_id) => {
+                Some(super_id.get_name().to_string())
+            }
+            _ => None,
+        });
+    let declared_fields = db
+        .get_member_index()
+        .get_sorted_members(&LuaMemberOwner::Type(id.clone()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|member| Some(member.get_key().get_name()?.to_string()))
+        .collect();
+
+    BeanSchema {
+        name: id.get_name().to_string(),
+        doc: collect_doc(type_property(db, id)),
+        parent,
+        fields,
+        declared_fields,
+    }
+}
+
+fn collect_enum(db: &DbIndex, id: &LuaTypeDeclId) -> EnumSchema {
+    let members = db
+        .get_member_index()
+        .get_sorted_members(&LuaMemberOwner::Type(id.clone()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|member| {
+            let name = member.get_key().get_name()?.to_string();
+            let value = match db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())?
+                .as_type()
+            {
+                LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => EnumValue::Integer(*i),
+                LuaType::StringConst(s) | LuaType::DocStringConst(s) => {
+                    EnumValue::String(s.to_string())
+                }
+                _ => return None,
+            };
+            let property = db
+                .get_property_index()
+                .get_property(&LuaSemanticDeclId::Member(member.get_id()));
+            Some(EnumMemberSchema {
+                name,
+                value,
+                doc: collect_doc(property),
+            })
+        })
+        .collect();
+
+    let property = type_property(db, id);
+    EnumSchema {
+        name: id.get_name().to_string(),
+        doc: collect_doc(property),
+        flags: property.is_some_and(is_flags_attribute),
+        members,
+    }
+}
+
+fn type_property<'a>(db: &'a DbIndex, id: &LuaTypeDeclId) -> Option<&'a LuaCommonProperty> {
+    db.get_property_index()
+        .get_property(&LuaSemanticDeclId::TypeDecl(id.clone()))
+}
+
+/// 字段类型上通过特性声明的约束, 如 `([v.set([1, 2])] int)`
+fn find_type_spec<T>(typ: &LuaType, parse: &impl Fn(&[LuaAttributeUse]) -> Option<T>) -> Option<T> {
+    match typ {
+        LuaType::Attributed(attributed) => parse(attributed.get_attributes().as_ref())
+            .or_else(|| find_type_spec(attributed.get_base(), parse)),
+        LuaType::Union(union) => union
+            .into_vec()
+            .iter()
+            .find_map(|inner| find_type_spec(inner, parse)),
+        _ => None,
+    }
+}
+
+fn strip_nil(typ: &LuaType) -> LuaType {
+    match typ {
+        LuaType::Union(union) => LuaType::from_vec(
+            union
+                .into_vec()
+                .into_iter()
+                .filter(|inner| !inner.is_nil())
+                .collect(),
+        ),
+        _ => typ.clone(),
+    }
+}
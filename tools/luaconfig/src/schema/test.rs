@@ -0,0 +1,235 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::{LuaMemberKey, VirtualWorkspace};
+
+    use crate::schema::{ConfigSchema, SchemaChangeKind, SchemaDiff, diff_config_schema};
+
+    const DATA: &str = r#"
+        ---@type TbItem
+        local items = {
+            [1] = { id = 1, kind = 1, count = 5, price = 10, tags = { "a" } },
+            [2] = { id = 2, kind = 2, count = 500, tags = { "a", "b", "c" } },
+        }
+    "#;
+
+    fn diff(old_schema: &str, new_schema: &str) -> SchemaDiff {
+        let mut old_ws = VirtualWorkspace::new_with_init_std_lib();
+        old_ws.def(old_schema);
+        old_ws.def_file("item.lua", DATA);
+        let old = ConfigSchema::collect(old_ws.analysis.compilation.get_db());
+
+        let mut new_ws = VirtualWorkspace::new_with_init_std_lib();
+        new_ws.def(new_schema);
+        new_ws.def_file("item.lua", DATA);
+        let db = new_ws.analysis.compilation.get_db();
+        let new = ConfigSchema::collect(db);
+        diff_config_schema(&old, &new, db)
+    }
+
+    fn find_change<'a>(
+        diff: &'a SchemaDiff,
+        subject: &str,
+        message: &str,
+    ) -> &'a crate::schema::SchemaChange {
+        diff.changes
+            .iter()
+            .find(|change| change.subject == subject && change.message.contains(message))
+            .unwrap_or_else(|| panic!("{subject}: {message} not found in {:#?}", diff.changes))
+    }
+
+    fn affected_keys(change: &crate::schema::SchemaChange) -> Vec<i64> {
+        change
+            .affected_rows
+            .iter()
+            .filter_map(|row| match row.key {
+                LuaMemberKey::Integer(i) => Some(i),
+                _ => None,
+            })
+            .collect()
+    }
+
+    const OLD_SCHEMA: &str = r#"
+        ---@class Item: Bean
+        ---@field id int
+        ---@field kind ([v.set([1, 2, 3])] int)
+        ---@[v.range("[0,1000]")]
+        ---@field count int
+        ---@field price int?
+        ---@field tags ([v.size("[0,5]")] list<string>)
+
+        ---@class TbItem: ConfigTable
+        ---@field [int] Item
+    "#;
+
+    #[test]
+    fn test_no_changes() {
+        let diff = diff(OLD_SCHEMA, OLD_SCHEMA);
+        assert!(diff.is_empty(), "{:#?}", diff.changes);
+    }
+
+    #[test]
+    fn test_breaking_changes() {
+        let diff = diff(
+            OLD_SCHEMA,
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+            ---@field kind ([v.set([1, 3])] int)
+            ---@[v.range("[0,100]")]
+            ---@field count short
+            ---@field tags ([v.size("[0,2]")] list<string>)
+            ---@field quality int
+
+            ---@[t.index("kind")]
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        assert!(diff.has_breaking_changes());
+
+        let removed = find_change(&diff, "Item.price", "field removed");
+        assert_eq!(removed.kind, SchemaChangeKind::Breaking);
+        assert_eq!(affected_keys(removed), vec![1]);
+
+        let narrowed = find_change(
+            &diff,
+            "Item.count",
+            "type changed from `integer` to `short`",
+        );
+        assert_eq!(narrowed.kind, SchemaChangeKind::Breaking);
+
+        let range = find_change(&diff, "Item.count", "`v.range`");
+        assert_eq!(range.kind, SchemaChangeKind::Breaking);
+        assert_eq!(affected_keys(range), vec![2]);
+
+        let set = find_change(&diff, "Item.kind", "`v.set`");
+        assert_eq!(affected_keys(set), vec![2]);
+
+        let size = find_change(&diff, "Item.tags", "`v.size`");
+        assert_eq!(affected_keys(size), vec![2]);
+
+        let required = find_change(&diff, "Item.quality", "required field added");
+        assert_eq!(required.kind, SchemaChangeKind::Breaking);
+        assert_eq!(affected_keys(required), vec![1, 2]);
+
+        let index = find_change(&diff, "TbItem", "`t.index` changed");
+        assert_eq!(affected_keys(index), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_compatible_changes() {
+        let diff = diff(
+            OLD_SCHEMA,
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+            ---@field kind ([v.set([1, 2, 3, 4])] int)
+            ---@[v.range("[0,)")]
+            ---@field count long
+            ---@field price double?
+            ---@field tags list<string>
+            ---@field icon string?
+            ---@[v.default(1)]
+            ---@field level int
+
+            ---@class Reward: Bean
+            ---@field itemId int
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        assert!(!diff.has_breaking_changes(), "{:#?}", diff.changes);
+        for (subject, message) in [
+            ("Item.kind", "`v.set`"),
+            ("Item.count", "`v.range`"),
+            ("Item.count", "type changed from `integer` to `long`"),
+            ("Item.price", "type changed from `integer` to `double`"),
+            ("Item.tags", "`v.size`"),
+            ("Item.icon", "optional field added"),
+            ("Item.level", "optional field added"),
+            ("Reward", "bean added"),
+        ] {
+            assert_eq!(
+                find_change(&diff, subject, message).kind,
+                SchemaChangeKind::Compatible
+            );
+        }
+    }
+
+    #[test]
+    fn test_lossy_numeric_changes() {
+        let int_to_float = diff(
+            OLD_SCHEMA,
+            r#"
+            ---@class Item: Bean
+            ---@field id int
+            ---@field kind ([v.set([1, 2, 3])] int)
+            ---@[v.range("[0,1000]")]
+            ---@field count float
+            ---@field price int?
+            ---@field tags ([v.size("[0,5]")] list<string>)
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        assert_eq!(
+            find_change(
+                &int_to_float,
+                "Item.count",
+                "type changed from `integer` to `float`"
+            )
+            .kind,
+            SchemaChangeKind::Breaking
+        );
+
+        let long_to_double = diff(
+            r#"
+            ---@class Item: Bean
+            ---@field id long
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+            r#"
+            ---@class Item: Bean
+            ---@field id double
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Item
+            "#,
+        );
+        assert_eq!(
+            find_change(
+                &long_to_double,
+                "Item.id",
+                "type changed from `long` to `double`"
+            )
+            .kind,
+            SchemaChangeKind::Breaking
+        );
+    }
+
+    #[test]
+    fn test_bean_removed() {
+        let diff = diff(
+            OLD_SCHEMA,
+            r#"
+            ---@class Goods: Bean
+            ---@field id int
+            ---@field kind ([v.set([1, 2, 3])] int)
+            ---@[v.range("[0,1000]")]
+            ---@field count int
+            ---@field price int?
+            ---@field tags ([v.size("[0,5]")] list<string>)
+
+            ---@class TbItem: ConfigTable
+            ---@field [int] Goods
+            "#,
+        );
+        let removed = find_change(&diff, "Item", "bean removed");
+        assert_eq!(removed.kind, SchemaChangeKind::Breaking);
+        assert_eq!(affected_keys(removed), vec![1, 2]);
+    }
+}